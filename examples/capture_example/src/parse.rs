@@ -1,23 +1,10 @@
 use anyhow::Result;
 use pcap::Packet;
-use radiotap::Radiotap;
 
 pub fn handle_packet(packet: Packet) -> Result<()> {
-    // At first, we look at the
-    let radiotap = match Radiotap::from_bytes(packet.data) {
-        Ok(radiotap) => radiotap,
-        Err(error) => {
-            println!(
-                "Couldn't read packet data with Radiotap: {:?}, error {:?}",
-                &packet.data, error
-            );
-            return Ok(());
-        }
-    };
-
-    let payload = &packet.data[radiotap.header.length..];
-    match libwifi::parse(&payload) {
-        Ok(frame) => {
+    // The frame is prepended by a radiotap header, which also tells whether it ends with an FCS.
+    match libwifi::parse_radiotap_frame(packet.data) {
+        Ok((_radiotap, frame)) => {
             println!("Got frame: {:?}", frame);
         }
         Err(err) => {
@@ -9,18 +9,18 @@ mod inner;
 /// This macro is only designed for internal usage in the [libwifi](https://docs.rs/libwifi/latest/libwifi/) crate.
 ///
 /// How to use:
-/// ```
+/// ```ignore
 /// #[derive(Clone, Debug, AddressHeader)]
 /// pub struct AssociationRequest {
 ///     pub header: ManagementHeader,
-///     pub beacon_interval: u16,
 ///     pub capability_info: u16,
+///     pub listen_interval: u16,
 ///     pub station_info: StationInfo,
 /// }
 /// ```
 ///
 /// The new generated code will look like this:
-/// ```
+/// ```ignore
 /// impl crate::Addresses for AssociationRequest {
 ///     fn src(&self) -> Option<&MacAddress> {
 ///         self.header.src()
@@ -1,6 +1,6 @@
 use nom::Needed;
 
-use crate::frame::components::FrameControl;
+use crate::frame::components::{FrameControl, ManagementInfoId};
 use crate::generators::SerializationError;
 
 #[derive(thiserror::Error, Debug)]
@@ -18,9 +18,19 @@ pub enum Error {
     SerializeFailure(String),
     #[error("There wasn't enough data. {}", .0)]
     Incomplete(String),
+    /// An element of a management frame is longer than the remaining data.
+    /// The number of missing bytes is passed as second parameter.
+    #[error("The element {:?} is truncated, {} bytes are missing", .0, .1)]
+    TruncatedElement(ManagementInfoId, usize),
 
     #[error("Libwifi cannot handle this specific protocol yet: {}", .0)]
     UnhandledProtocol(String),
+
+    /// A capture file isn't a valid pcap or pcapng file.
+    #[error("Invalid capture file: {}", .0)]
+    InvalidCapture(String),
+    #[error("An IO error occurred: {}", .0)]
+    Io(#[from] std::io::Error),
 }
 
 impl From<nom::Err<nom::error::Error<&[u8]>>> for Error {
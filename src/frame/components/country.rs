@@ -0,0 +1,109 @@
+/// The Country element, which announces the regulatory domain of the BSS.
+///
+/// Structure:
+/// - 2 bytes: Country code (ISO 3166-1 alpha-2)
+/// - 1 byte: Environment
+/// - 3 bytes each: [CountryTriplet]s
+/// - 1 byte: Padding, if the length of the element would be odd
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Country {
+    pub country_code: [u8; 2],
+    /// The environment in which the BSS operates, as ASCII character.
+    /// - `' '`: Any environment
+    /// - `'O'`: Outdoor
+    /// - `'I'`: Indoor
+    /// - `'X'`: Non-country entity
+    ///
+    /// Newer versions of the spec use values `1` to `4` to refer to the global operating
+    /// class table (Table E-4).
+    pub environment: u8,
+    pub triplets: Vec<CountryTriplet>,
+}
+
+impl Country {
+    /// The country code as string, e.g. `"DE"`.
+    pub fn country_code(&self) -> String {
+        String::from_utf8_lossy(&self.country_code).to_string()
+    }
+
+    /// All subband triplets, which define the maximum transmit power for a range of channels.
+    pub fn subbands(&self) -> impl Iterator<Item = &SubbandTriplet> {
+        self.triplets.iter().filter_map(|triplet| match triplet {
+            CountryTriplet::Subband(subband) => Some(subband),
+            CountryTriplet::Operating(_) => None,
+        })
+    }
+
+    /// All operating triplets, which select an operating class.
+    pub fn operating_classes(&self) -> impl Iterator<Item = &OperatingTriplet> {
+        self.triplets.iter().filter_map(|triplet| match triplet {
+            CountryTriplet::Operating(operating) => Some(operating),
+            CountryTriplet::Subband(_) => None,
+        })
+    }
+}
+
+/// A triplet of the [Country] element.
+///
+/// The first byte decides about the type: Values of 201 and above mark an operating triplet.
+/// Subband triplets that follow an operating triplet belong to its operating class.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CountryTriplet {
+    Subband(SubbandTriplet),
+    Operating(OperatingTriplet),
+}
+
+/// A subband triplet of the [Country] element.
+///
+/// Structure:
+/// - 1 byte: First channel number
+/// - 1 byte: Number of channels
+/// - 1 byte: Maximum transmit power level in dBm
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SubbandTriplet {
+    pub first_channel: u8,
+    pub number_of_channels: u8,
+    pub max_transmit_power: i8,
+}
+
+impl SubbandTriplet {
+    /// The channel numbers of this subband.
+    ///
+    /// The channels are spaced by one in the 2.4 GHz band and by four in the 5 GHz band.
+    pub fn channels(&self) -> impl Iterator<Item = u8> {
+        let spacing = if self.first_channel <= 14 { 1 } else { 4 };
+        let first_channel = self.first_channel;
+
+        (0..self.number_of_channels)
+            .map(move |index| first_channel.saturating_add(index.saturating_mul(spacing)))
+    }
+}
+
+/// An operating triplet of the [Country] element.
+///
+/// Structure:
+/// - 1 byte: Operating extension identifier (201 or greater)
+/// - 1 byte: Operating class
+/// - 1 byte: Coverage class
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperatingTriplet {
+    pub operating_extension_id: u8,
+    pub operating_class: u8,
+    pub coverage_class: u8,
+}
+
+impl OperatingTriplet {
+    /// The lowest operating extension identifier.
+    /// All lower values are the first channel of a [SubbandTriplet].
+    pub(crate) const MIN_EXTENSION_ID: u8 = 201;
+}
+
+impl Default for OperatingTriplet {
+    fn default() -> Self {
+        OperatingTriplet {
+            operating_extension_id: Self::MIN_EXTENSION_ID,
+            operating_class: 0,
+            coverage_class: 0,
+        }
+    }
+}
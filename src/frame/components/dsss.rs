@@ -0,0 +1,11 @@
+/// The DS Parameter Set element, which contains the channel of the BSS.
+///
+/// It's mandatory in the 2.4 GHz band, as the channels overlap and a frame might be
+/// received on a neighbouring channel. Many access points send it in the 5 GHz band as well.
+///
+/// Structure:
+/// - 1 byte: Current channel
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DsParameterSet {
+    pub current_channel: u8,
+}
@@ -0,0 +1,93 @@
+/// The EHT Capabilities element, which announces the 802.11be capabilities of a station.
+///
+/// Structure:
+/// - 2 bytes: EHT MAC capabilities information
+/// - 9 bytes: EHT PHY capabilities information
+/// - Supported EHT-MCS and NSS set (variable length)
+/// - EHT PPE thresholds (optional, variable length)
+///
+/// The length of the supported EHT-MCS and NSS set depends on the channel widths of the
+/// [HeCapabilities](super::HeCapabilities) element in the same frame.
+/// Hence, the remaining bytes are kept together.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EhtCapabilities {
+    pub mac_capabilities: [u8; 2],
+    pub phy_capabilities: [u8; 9],
+    /// The raw supported EHT-MCS and NSS set, followed by the PPE thresholds (if present).
+    pub mcs_nss_and_ppe_thresholds: Vec<u8>,
+}
+
+impl EhtCapabilities {
+    /// EPCS priority access support.
+    pub fn epcs_priority_access_support(&self) -> bool {
+        self.mac_capabilities[0] & 0x01 > 0
+    }
+
+    /// EHT OM control support.
+    pub fn om_control_support(&self) -> bool {
+        self.mac_capabilities[0] & 0x02 > 0
+    }
+
+    /// 320 MHz channels are supported in the 6 GHz band.
+    pub fn supports_320mhz(&self) -> bool {
+        self.phy_capabilities[0] & 0x02 > 0
+    }
+
+    /// Whether the element contains PPE thresholds.
+    pub fn ppe_thresholds_present(&self) -> bool {
+        self.phy_capabilities[5] & 0x08 > 0
+    }
+}
+
+/// The EHT Operation Information field of the [EhtOperation] element.
+///
+/// Structure:
+/// - 1 byte: Control (channel width in bits 0-2)
+/// - 1 byte: Channel center frequency segment 0
+/// - 1 byte: Channel center frequency segment 1
+/// - 2 bytes: Disabled subchannel bitmap (optional)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EhtOperationInfo {
+    pub channel_width: u8,
+    pub channel_center_frequency_segment_0: u8,
+    pub channel_center_frequency_segment_1: u8,
+    /// Each bit represents a disabled 20 MHz subchannel (puncturing).
+    pub disabled_subchannel_bitmap: Option<u16>,
+}
+
+impl EhtOperationInfo {
+    /// The channel width in MHz.
+    pub fn channel_width_mhz(&self) -> Option<u16> {
+        match self.channel_width & 0b111 {
+            0 => Some(20),
+            1 => Some(40),
+            2 => Some(80),
+            3 => Some(160),
+            4 => Some(320),
+            _ => None,
+        }
+    }
+}
+
+/// The EHT Operation element, which is sent by access points to describe the operation of
+/// an 802.11be BSS.
+///
+/// Structure:
+/// - 1 byte: EHT operation parameters
+///   - **bit_0**: EHT operation information present
+///   - **bit_1**: Disabled subchannel bitmap present
+///   - **bit_2**: EHT default PE duration
+///   - **bit_3**: Group addressed BU indication limit
+///   - **bit_4-5**: Group addressed BU indication exponent
+/// - 4 bytes: Basic EHT-MCS and NSS set
+/// - EHT operation information (optional)
+///
+/// The presence flags of the optional fields are derived from the respective `Option`s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EhtOperation {
+    pub default_pe_duration: bool,
+    pub group_addressed_bu_indication_limit: bool,
+    pub group_addressed_bu_indication_exponent: u8,
+    pub basic_mcs_nss_set: [u8; 4],
+    pub operation_info: Option<EhtOperationInfo>,
+}
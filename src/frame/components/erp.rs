@@ -0,0 +1,18 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// The ERP element, which is sent by 802.11g access points in the 2.4 GHz band to
+    /// control the protection of OFDM frames against legacy 802.11b stations.
+    ///
+    /// Structure:
+    /// - 1 byte: ERP parameters (3 bits flags, 5 bits reserved)
+    #[derive(Default)]
+    pub struct ErpInformation: u8 {
+        /// Non-ERP (802.11b only) stations are associated with the BSS or were detected.
+        const NON_ERP_PRESENT       = 0x01;
+        /// Stations have to use protection mechanisms, such as RTS/CTS, for OFDM frames.
+        const USE_PROTECTION        = 0x02;
+        /// Stations have to use long preambles, as some stations don't support short ones.
+        const BARKER_PREAMBLE_MODE  = 0x04;
+    }
+}
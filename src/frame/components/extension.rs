@@ -0,0 +1,29 @@
+use num_enum::{FromPrimitive, IntoPrimitive};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+/// The extension ID of an element with the [ManagementInfoId::InfoIdExtension](super::ManagementInfoId::InfoIdExtension)
+/// element ID. It's the first byte of the element body.
+///
+/// Reference: IEEE 802.11-2020, Table 9-92 and IEEE 802.11be.
+///
+/// This table only contains the extension IDs which are known to this library.
+/// All others are preserved via [ExtensionId::Unknown].
+pub enum ExtensionId {
+    PasswordIdentifier = 33,
+    HeCapabilities = 35,
+    HeOperation = 36,
+    UoraParameterSet = 37,
+    MuEdcaParameterSet = 38,
+    SpatialReuseParameterSet = 39,
+    BssColorChangeAnnouncement = 42,
+    NonInheritance = 56,
+    He6GhzBandCapabilities = 59,
+    RejectedGroups = 92,
+    AntiCloggingTokenContainer = 93,
+    EhtOperation = 106,
+    MultiLink = 107,
+    EhtCapabilities = 108,
+    #[num_enum(catch_all)]
+    Unknown(u8),
+}
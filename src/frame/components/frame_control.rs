@@ -13,6 +13,11 @@ fn flag_is_set(data: u8, bit: u8) -> bool {
 }
 
 #[inline]
+#[allow(clippy::too_many_arguments)]
+/// Build the flags byte of the [FrameControl] header.
+///
+/// The bit order is the same as the one used by the flag accessors on [FrameControl],
+/// i.e. `to_ds` is the least significant bit and `order` the most significant one.
 pub fn build_flags(
     to_ds: bool,
     from_ds: bool,
@@ -23,14 +28,14 @@ pub fn build_flags(
     protected: bool,
     order: bool,
 ) -> u8 {
-    ((to_ds as u8) << 7)
-        | ((from_ds as u8) << 6)
-        | ((more_frags as u8) << 5)
-        | ((retry as u8) << 4)
-        | ((pwr_mgmt as u8) << 3)
-        | ((more_data as u8) << 2)
-        | ((protected as u8) << 1)
-        | (order as u8)
+    (to_ds as u8)
+        | ((from_ds as u8) << 1)
+        | ((more_frags as u8) << 2)
+        | ((retry as u8) << 3)
+        | ((pwr_mgmt as u8) << 4)
+        | ((more_data as u8) << 5)
+        | ((protected as u8) << 6)
+        | ((order as u8) << 7)
 }
 
 /// The very first two bytes of every frame contain the FrameControl header.
@@ -39,7 +44,7 @@ pub fn build_flags(
 /// First byte:
 ///
 /// - **bit_0-1**: Protocol version.
-///     Until now, this has always been 0 and is expected to be 0.
+///   Until now, this has always been 0 and is expected to be 0.
 /// - **bit_2-3**: [FrameType]
 /// - **bit_4-7**: [FrameSubType]
 ///
@@ -50,7 +55,7 @@ pub fn build_flags(
 /// - **bit_3** `retry`: Set if this frame is a retransmission, maybe through the loss of an ACK.
 /// - **bit_4** `power_mgmt`: Indicates what power mode (`save` or `active`) the station will be in, once the frame has been sent.
 /// - **bit_5** `more_data`: Set by the AP to indicate that more frames are destined to a particular station that may be in power save mode.
-///                     These frames will be buffered at the AP, so it can be sent once the station decides to become `active`.
+///   These frames will be buffered at the AP, so it can be sent once the station decides to become `active`.
 /// - **bit_6** `wep`: Set if WEP is being used to encrypt the body of the frame.
 /// - **bit_7** `order`: Set if the frame is being sent according to the _Strictly Ordered Class_.
 #[derive(Clone, Debug)]
@@ -134,6 +139,23 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Ensure that [build_flags] sets the same bits that are read by the flag accessors.
+    fn test_build_flags() {
+        for bit in 0..8 {
+            let mut flags = [false; 8];
+            flags[bit as usize] = true;
+            let second_byte = build_flags(
+                flags[0], flags[1], flags[2], flags[3], flags[4], flags[5], flags[6], flags[7],
+            );
+            let frame_control = parse_frame_control(&[0b0000_0000, second_byte]).unwrap().1;
+
+            for check_bit in 0..8 {
+                assert_eq!(bit == check_bit, flag_for_bit(check_bit, &frame_control));
+            }
+        }
+    }
+
     #[test]
     /// Create a Management-Beacon FrameControl header
     /// FrameType should be `00` and SubType `1000`
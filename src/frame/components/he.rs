@@ -0,0 +1,179 @@
+use super::VhtChannelWidth;
+
+/// A HE-MCS map, which contains the maximum supported MCS for 1 to 8 spatial streams.
+///
+/// Each spatial stream uses two bits:
+/// - `0`: HE-MCS 0-7
+/// - `1`: HE-MCS 0-9
+/// - `2`: HE-MCS 0-11
+/// - `3`: Not supported
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HeMcsMap(pub u16);
+
+impl HeMcsMap {
+    /// The highest supported MCS index for the given number of spatial streams (1-8).
+    /// Returns `None`, if the number of spatial streams isn't supported.
+    pub fn max_mcs(&self, spatial_streams: u8) -> Option<u8> {
+        if !(1..=8).contains(&spatial_streams) {
+            return None;
+        }
+
+        match (self.0 >> ((spatial_streams - 1) * 2)) & 0b11 {
+            0 => Some(7),
+            1 => Some(9),
+            2 => Some(11),
+            _ => None,
+        }
+    }
+
+    /// The highest number of spatial streams that's supported.
+    pub fn max_spatial_streams(&self) -> u8 {
+        (1..=8)
+            .rev()
+            .find(|streams| self.max_mcs(*streams).is_some())
+            .unwrap_or(0)
+    }
+}
+
+/// The Rx and Tx HE-MCS maps for a specific channel width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeMcsNssSet {
+    pub rx_mcs_map: HeMcsMap,
+    pub tx_mcs_map: HeMcsMap,
+}
+
+/// The HE Capabilities element, which announces the 802.11ax capabilities of a station.
+///
+/// Structure:
+/// - 6 bytes: HE MAC capabilities information
+/// - 11 bytes: HE PHY capabilities information
+/// - 4 bytes: Supported HE-MCS and NSS set for channel widths up to 80 MHz
+/// - 4 bytes: Supported HE-MCS and NSS set for 160 MHz (optional)
+/// - 4 bytes: Supported HE-MCS and NSS set for 80+80 MHz (optional)
+/// - PPE thresholds (optional, variable length)
+///
+/// The presence of the optional fields is signaled in the PHY capabilities.
+/// The capability fields have a lot of sub-fields; only the most important ones have accessors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeCapabilities {
+    pub mac_capabilities: [u8; 6],
+    pub phy_capabilities: [u8; 11],
+    pub mcs_nss_80mhz: HeMcsNssSet,
+    pub mcs_nss_160mhz: Option<HeMcsNssSet>,
+    pub mcs_nss_80_plus_80mhz: Option<HeMcsNssSet>,
+    /// The raw PPE thresholds field.
+    /// Only present if [HeCapabilities::ppe_thresholds_present] is set.
+    pub ppe_thresholds: Vec<u8>,
+}
+
+impl HeCapabilities {
+    /// +HTC-HE support.
+    pub fn htc_he_support(&self) -> bool {
+        self.mac_capabilities[0] & 0x01 > 0
+    }
+
+    /// TWT requester support.
+    pub fn twt_requester_support(&self) -> bool {
+        self.mac_capabilities[0] & 0x02 > 0
+    }
+
+    /// TWT responder support.
+    pub fn twt_responder_support(&self) -> bool {
+        self.mac_capabilities[0] & 0x04 > 0
+    }
+
+    /// 40 MHz channels are supported in the 2.4 GHz band.
+    pub fn supports_40mhz_2ghz(&self) -> bool {
+        self.phy_capabilities[0] & 0x02 > 0
+    }
+
+    /// 40 MHz and 80 MHz channels are supported in the 5 GHz and 6 GHz bands.
+    pub fn supports_80mhz(&self) -> bool {
+        self.phy_capabilities[0] & 0x04 > 0
+    }
+
+    /// 160 MHz channels are supported in the 5 GHz and 6 GHz bands.
+    pub fn supports_160mhz(&self) -> bool {
+        self.phy_capabilities[0] & 0x08 > 0
+    }
+
+    /// 80+80 MHz channels are supported in the 5 GHz and 6 GHz bands.
+    pub fn supports_80_plus_80mhz(&self) -> bool {
+        self.phy_capabilities[0] & 0x10 > 0
+    }
+
+    /// Whether the element contains PPE thresholds.
+    pub fn ppe_thresholds_present(&self) -> bool {
+        self.phy_capabilities[6] & 0x80 > 0
+    }
+}
+
+/// The VHT Operation Information field of the [HeOperation] element.
+/// It's used by 802.11ax access points in the 5 GHz band that don't send a VHT Operation
+/// element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeVhtOperationInfo {
+    pub channel_width: VhtChannelWidth,
+    pub channel_center_frequency_segment_0: u8,
+    pub channel_center_frequency_segment_1: u8,
+}
+
+/// The 6 GHz Operation Information field of the [HeOperation] element.
+///
+/// Structure:
+/// - 1 byte: Primary channel
+/// - 1 byte: Control
+///   - **bit_0-1**: Channel width (20, 40, 80, 160/80+80 MHz)
+///   - **bit_2**: Duplicate beacon
+///   - **bit_3-5**: Regulatory info
+/// - 1 byte: Channel center frequency segment 0
+/// - 1 byte: Channel center frequency segment 1
+/// - 1 byte: Minimum rate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct He6GhzOperationInfo {
+    pub primary_channel: u8,
+    pub channel_width: u8,
+    pub duplicate_beacon: bool,
+    pub regulatory_info: u8,
+    pub channel_center_frequency_segment_0: u8,
+    pub channel_center_frequency_segment_1: u8,
+    pub minimum_rate: u8,
+}
+
+/// The HE Operation element, which is sent by access points to describe the operation of
+/// an 802.11ax BSS.
+///
+/// Structure:
+/// - 3 bytes: HE operation parameters
+///   - **bit_0-2**: Default PE duration
+///   - **bit_3**: TWT required
+///   - **bit_4-13**: TXOP duration RTS threshold
+///   - **bit_14**: VHT operation information present
+///   - **bit_15**: Co-hosted BSS
+///   - **bit_16**: ER SU disable
+///   - **bit_17**: 6 GHz operation information present
+/// - 1 byte: BSS color information
+///   - **bit_0-5**: BSS color
+///   - **bit_6**: Partial BSS color
+///   - **bit_7**: BSS color disabled
+/// - 2 bytes: Basic HE-MCS and NSS set
+/// - 3 bytes: VHT operation information (optional)
+/// - 1 byte: Max co-hosted BSSID indicator (optional)
+/// - 5 bytes: 6 GHz operation information (optional)
+///
+/// The presence flags of the optional fields are derived from the respective `Option`s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeOperation {
+    pub default_pe_duration: u8,
+    pub twt_required: bool,
+    pub txop_duration_rts_threshold: u16,
+    pub er_su_disable: bool,
+    pub bss_color: u8,
+    pub partial_bss_color: bool,
+    pub bss_color_disabled: bool,
+    pub basic_mcs_set: HeMcsMap,
+    pub vht_operation_info: Option<HeVhtOperationInfo>,
+    /// Only present, if this is a co-hosted BSS.
+    pub max_cohosted_bssid_indicator: Option<u8>,
+    pub six_ghz_operation_info: Option<He6GhzOperationInfo>,
+}
@@ -178,7 +178,7 @@ impl Addresses for DataHeader {
         } else if self.frame_control.from_ds() {
             Some(&self.address_2)
         } else {
-            self.address_4.as_ref()
+            Some(&self.address_3)
         }
     }
 }
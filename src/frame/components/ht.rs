@@ -0,0 +1,250 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// The HT capability information field of the [HtCapabilities] element.
+    ///
+    /// Multi-bit fields are represented by their mask.
+    /// Use the accessor methods to get their values.
+    pub struct HtCapabilityInfo: u16 {
+        const LDPC_CODING               = 0x0001;
+        /// 20 MHz and 40 MHz operation is supported.
+        const CHANNEL_WIDTH_40MHZ       = 0x0002;
+        const SM_POWER_SAVE             = 0x000c;
+        const GREENFIELD                = 0x0010;
+        const SHORT_GI_20MHZ            = 0x0020;
+        const SHORT_GI_40MHZ            = 0x0040;
+        const TX_STBC                   = 0x0080;
+        const RX_STBC                   = 0x0300;
+        const DELAYED_BLOCK_ACK         = 0x0400;
+        /// The maximum A-MSDU length is 7935 instead of 3839 octets.
+        const MAX_AMSDU_7935            = 0x0800;
+        const DSSS_CCK_40MHZ            = 0x1000;
+        const FORTY_MHZ_INTOLERANT      = 0x4000;
+        const LSIG_TXOP_PROTECTION      = 0x8000;
+    }
+}
+
+impl HtCapabilityInfo {
+    /// The spatial multiplexing power save mode.
+    /// `0` is static, `1` is dynamic and `3` means that it's disabled.
+    pub fn sm_power_save(&self) -> u8 {
+        ((self.bits() & Self::SM_POWER_SAVE.bits()) >> 2) as u8
+    }
+
+    /// The number of spatial streams that can be received with STBC (0-3).
+    pub fn rx_stbc_streams(&self) -> u8 {
+        ((self.bits() & Self::RX_STBC.bits()) >> 8) as u8
+    }
+}
+
+bitflags! {
+    /// The extended HT capabilities field of the [HtCapabilities] element.
+    pub struct HtExtendedCapabilities: u16 {
+        const PCO                       = 0x0001;
+        const PCO_TRANSITION_TIME       = 0x0006;
+        const MCS_FEEDBACK              = 0x0300;
+        const HTC_HT_SUPPORT            = 0x0400;
+        const RD_RESPONDER              = 0x0800;
+    }
+}
+
+bitflags! {
+    /// The transmit beamforming capabilities field of the [HtCapabilities] element.
+    pub struct TxBeamformingCapabilities: u32 {
+        const IMPLICIT_TXBF_RECEIVING               = 0x0000_0001;
+        const RECEIVE_STAGGERED_SOUNDING            = 0x0000_0002;
+        const TRANSMIT_STAGGERED_SOUNDING           = 0x0000_0004;
+        const RECEIVE_NDP                           = 0x0000_0008;
+        const TRANSMIT_NDP                          = 0x0000_0010;
+        const IMPLICIT_TXBF                         = 0x0000_0020;
+        const CALIBRATION                           = 0x0000_00c0;
+        const EXPLICIT_CSI_TXBF                     = 0x0000_0100;
+        const EXPLICIT_NONCOMPRESSED_STEERING       = 0x0000_0200;
+        const EXPLICIT_COMPRESSED_STEERING          = 0x0000_0400;
+        const EXPLICIT_TXBF_CSI_FEEDBACK            = 0x0000_1800;
+        const EXPLICIT_NONCOMPRESSED_FEEDBACK       = 0x0000_6000;
+        const EXPLICIT_COMPRESSED_FEEDBACK          = 0x0001_8000;
+        const MINIMAL_GROUPING                      = 0x0006_0000;
+        const CSI_BEAMFORMER_ANTENNAS               = 0x0018_0000;
+        const NONCOMPRESSED_STEERING_ANTENNAS       = 0x0060_0000;
+        const COMPRESSED_STEERING_ANTENNAS          = 0x0180_0000;
+        const CSI_MAX_ROWS_BEAMFORMER               = 0x0600_0000;
+        const CHANNEL_ESTIMATION                    = 0x1800_0000;
+    }
+}
+
+bitflags! {
+    /// The antenna selection (ASEL) capabilities field of the [HtCapabilities] element.
+    pub struct AselCapabilities: u8 {
+        const ASEL                                  = 0x01;
+        const EXPLICIT_CSI_FEEDBACK_TX_ASEL         = 0x02;
+        const ANTENNA_INDICES_FEEDBACK_TX_ASEL      = 0x04;
+        const EXPLICIT_CSI_FEEDBACK                 = 0x08;
+        const ANTENNA_INDICES_FEEDBACK              = 0x10;
+        const RECEIVE_ASEL                          = 0x20;
+        const TRANSMIT_SOUNDING_PPDUS               = 0x40;
+    }
+}
+
+/// The A-MPDU parameters field of the [HtCapabilities] element.
+///
+/// - **bit_0-1**: Maximum A-MPDU length exponent
+/// - **bit_2-4**: Minimum MPDU start spacing
+/// - **bit_5-7**: Reserved
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AmpduParameters {
+    pub max_ampdu_length_exponent: u8,
+    pub min_mpdu_start_spacing: u8,
+}
+
+impl AmpduParameters {
+    /// The maximum A-MPDU length in octets (`2^(13 + exponent) - 1`).
+    pub fn max_ampdu_length(&self) -> u32 {
+        (1 << (13 + self.max_ampdu_length_exponent as u32)) - 1
+    }
+}
+
+/// The (basic) supported HT-MCS set.
+///
+/// Structure:
+/// - 10 bytes: Rx MCS bitmask (MCS 0-76)
+/// - 2 bytes: Rx highest supported data rate in Mb/s (lower 10 bits)
+/// - 1 byte: Tx MCS set
+///   - **bit_0**: Tx MCS set defined
+///   - **bit_1**: Tx Rx MCS set not equal
+///   - **bit_2-3**: Tx maximum number of spatial streams **- 1**
+///   - **bit_4**: Tx unequal modulation supported
+/// - 3 bytes: Reserved
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SupportedMcsSet {
+    pub rx_mcs_bitmask: [u8; 10],
+    pub rx_highest_data_rate: u16,
+    pub tx_mcs_set_defined: bool,
+    pub tx_rx_mcs_set_not_equal: bool,
+    /// The maximum number of spatial streams **- 1**.
+    pub tx_max_spatial_streams: u8,
+    pub tx_unequal_modulation: bool,
+}
+
+impl SupportedMcsSet {
+    /// Check whether a given MCS index (0-76) can be received.
+    pub fn supports_mcs(&self, index: u8) -> bool {
+        if index > 76 {
+            return false;
+        }
+
+        self.rx_mcs_bitmask[(index / 8) as usize] & (1 << (index % 8)) > 0
+    }
+}
+
+/// The HT Capabilities element, which announces the 802.11n capabilities of a station.
+///
+/// Structure:
+/// - 2 bytes: HT capability information
+/// - 1 byte: A-MPDU parameters
+/// - 16 bytes: Supported MCS set
+/// - 2 bytes: Extended HT capabilities
+/// - 4 bytes: Transmit beamforming capabilities
+/// - 1 byte: ASEL capabilities
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HtCapabilities {
+    pub ht_capability_info: HtCapabilityInfo,
+    pub ampdu_parameters: AmpduParameters,
+    pub supported_mcs_set: SupportedMcsSet,
+    pub extended_capabilities: HtExtendedCapabilities,
+    pub txbf_capabilities: TxBeamformingCapabilities,
+    pub asel_capabilities: AselCapabilities,
+}
+
+/// The position of the secondary 20 MHz channel relative to the primary channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SecondaryChannelOffset {
+    NoSecondaryChannel,
+    Above,
+    Reserved,
+    Below,
+}
+
+impl SecondaryChannelOffset {
+    /// Create the offset from its 2 bit representation.
+    pub fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0 => SecondaryChannelOffset::NoSecondaryChannel,
+            1 => SecondaryChannelOffset::Above,
+            2 => SecondaryChannelOffset::Reserved,
+            _ => SecondaryChannelOffset::Below,
+        }
+    }
+
+    /// The 2 bit representation of this offset.
+    pub fn bits(&self) -> u8 {
+        match self {
+            SecondaryChannelOffset::NoSecondaryChannel => 0,
+            SecondaryChannelOffset::Above => 1,
+            SecondaryChannelOffset::Reserved => 2,
+            SecondaryChannelOffset::Below => 3,
+        }
+    }
+}
+
+/// The HT protection mode of a BSS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HtProtection {
+    /// All stations in the BSS are HT stations.
+    NoProtection,
+    /// There are non-HT stations in an overlapping BSS.
+    NonmemberProtection,
+    /// There are 20 MHz-only HT stations in a 20/40 MHz BSS.
+    TwentyMhzProtection,
+    /// There are non-HT stations in the BSS.
+    NonHtMixed,
+}
+
+impl HtProtection {
+    /// Create the protection mode from its 2 bit representation.
+    pub fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0 => HtProtection::NoProtection,
+            1 => HtProtection::NonmemberProtection,
+            2 => HtProtection::TwentyMhzProtection,
+            _ => HtProtection::NonHtMixed,
+        }
+    }
+
+    /// The 2 bit representation of this protection mode.
+    pub fn bits(&self) -> u8 {
+        match self {
+            HtProtection::NoProtection => 0,
+            HtProtection::NonmemberProtection => 1,
+            HtProtection::TwentyMhzProtection => 2,
+            HtProtection::NonHtMixed => 3,
+        }
+    }
+}
+
+/// The HT Operation element, which is sent by access points to describe the operation of
+/// an 802.11n BSS.
+///
+/// Structure:
+/// - 1 byte: Primary channel
+/// - 5 bytes: HT operation information
+/// - 16 bytes: Basic HT-MCS set
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HtOperation {
+    pub primary_channel: u8,
+    pub secondary_channel_offset: SecondaryChannelOffset,
+    /// If set, any channel width of the supported channel width set may be used.
+    /// Otherwise, only 20 MHz may be used.
+    pub sta_channel_width: bool,
+    pub rifs_mode: bool,
+    pub ht_protection: HtProtection,
+    pub nongreenfield_stas_present: bool,
+    pub obss_non_ht_stas_present: bool,
+    /// Only used by VHT access points for 80+80 MHz and 160 MHz operation.
+    pub channel_center_frequency_segment_2: u8,
+    pub dual_beacon: bool,
+    pub dual_cts_protection: bool,
+    pub stbc_beacon: bool,
+    /// The MCS values that are supported by all stations in the BSS.
+    pub basic_mcs_set: SupportedMcsSet,
+}
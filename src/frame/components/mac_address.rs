@@ -10,7 +10,7 @@ use std::fmt;
 /// // -> true
 /// ```
 ///
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct MacAddress(pub [u8; 6]);
 
 impl MacAddress {
@@ -88,7 +88,7 @@ impl std::str::FromStr for MacAddress {
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut array = [0u8; 6];
 
-        let bytes: Vec<&str> = input.split(|c| c == ':').collect();
+        let bytes: Vec<&str> = input.split(':').collect();
         if bytes.len() != 6 {
             return Err(MacParseError::InvalidLength);
         }
@@ -1,13 +1,47 @@
 mod cap_info;
+mod country;
+mod dsss;
+mod eht;
+mod erp;
+mod extension;
 mod frame_control;
+mod he;
 mod header;
+mod ht;
 mod mac_address;
+mod multi_link;
+mod rates;
+mod reason_code;
+mod rsn;
 mod sequence_control;
 mod station_info;
+mod status_code;
+mod tim;
+mod vendor;
+mod vht;
+mod wmm;
+mod wpa;
 
 pub use cap_info::*;
+pub use country::*;
+pub use dsss::*;
+pub use eht::*;
+pub use erp::*;
+pub use extension::*;
 pub use frame_control::{build_flags, FrameControl};
+pub use he::*;
 pub use header::*;
+pub use ht::*;
 pub use mac_address::*;
+pub use multi_link::*;
+pub use rates::*;
+pub use reason_code::ReasonCode;
+pub use rsn::*;
 pub use sequence_control::SequenceControl;
 pub use station_info::*;
+pub use status_code::StatusCode;
+pub use tim::*;
+pub use vendor::*;
+pub use vht::*;
+pub use wmm::*;
+pub use wpa::*;
@@ -0,0 +1,283 @@
+use super::{ExtensionId, MacAddress, ManagementInfoId, StationInfo};
+use crate::error::Error;
+use crate::parsers::{parse_non_inheritance, parse_station_info};
+
+/// The Multi-Link element, which is used by 802.11be multi-link devices (MLD) to announce
+/// their affiliated stations (links).
+///
+/// Structure:
+/// - 2 bytes: Multi-Link control (3 bits type, 1 bit reserved, 12 bits presence bitmap)
+/// - Common info (variable, depends on the type and presence bitmap)
+/// - Link info (variable): A list of subelements, usually [PerStaProfile]s
+///
+/// Only the Basic and Probe Request variants are parsed.
+/// All other variants are kept as raw extension elements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MultiLink {
+    /// Sent by AP MLDs in beacons, probe responses and (re)association frames and by non-AP
+    /// MLDs in (re)association requests.
+    Basic(BasicMultiLink),
+    /// Sent by non-AP MLDs in probe requests to request information about other links of an
+    /// AP MLD.
+    ProbeRequest(ProbeRequestMultiLink),
+}
+
+impl MultiLink {
+    /// All per-STA profiles of this element.
+    pub fn per_sta_profiles(&self) -> &[PerStaProfile] {
+        match self {
+            MultiLink::Basic(multi_link) => &multi_link.per_sta_profiles,
+            MultiLink::ProbeRequest(multi_link) => &multi_link.per_sta_profiles,
+        }
+    }
+}
+
+/// The Basic variant of the [MultiLink] element.
+///
+/// Common info structure:
+/// - 1 byte: Common info length (including this byte)
+/// - 6 bytes: MLD MAC address
+/// - 1 byte: Link ID info (optional)
+/// - 1 byte: BSS parameters change count (optional)
+/// - 2 bytes: Medium synchronization delay information (optional)
+/// - 2 bytes: EML capabilities (optional)
+/// - 2 bytes: MLD capabilities and operations (optional)
+/// - 1 byte: AP MLD ID (optional)
+/// - 2 bytes: Extended MLD capabilities and operations (optional)
+///
+/// The presence of the optional fields is signaled in the presence bitmap of the
+/// Multi-Link control field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BasicMultiLink {
+    pub mld_mac_address: MacAddress,
+    /// The ID of the link that transmits this element.
+    pub link_id: Option<u8>,
+    pub bss_parameters_change_count: Option<u8>,
+    pub medium_synchronization_delay: Option<u16>,
+    pub eml_capabilities: Option<EmlCapabilities>,
+    pub mld_capabilities: Option<MldCapabilities>,
+    pub ap_mld_id: Option<u8>,
+    pub extended_mld_capabilities: Option<u16>,
+    pub per_sta_profiles: Vec<PerStaProfile>,
+}
+
+impl BasicMultiLink {
+    /// The MAC addresses of all other links of this MLD, together with their link ID.
+    ///
+    /// For AP MLDs, these are the BSSIDs of the affiliated APs.
+    /// The link that transmits the element isn't included, as its address is the transmitter
+    /// address of the frame.
+    pub fn link_addresses(&self) -> impl Iterator<Item = (u8, MacAddress)> + '_ {
+        self.per_sta_profiles.iter().filter_map(|profile| {
+            profile
+                .sta_mac_address
+                .map(|address| (profile.link_id, address))
+        })
+    }
+}
+
+/// The Probe Request variant of the [MultiLink] element.
+///
+/// Common info structure:
+/// - 1 byte: Common info length (including this byte)
+/// - 1 byte: AP MLD ID (optional)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProbeRequestMultiLink {
+    /// The AP MLD that's targeted by this request.
+    /// If it's not present, the AP MLD of the addressed AP is targeted.
+    pub ap_mld_id: Option<u8>,
+    /// The links for which information is requested.
+    pub per_sta_profiles: Vec<PerStaProfile>,
+}
+
+/// The EML capabilities of a MLD.
+///
+/// Only the most important sub-fields have accessors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EmlCapabilities(pub u16);
+
+impl EmlCapabilities {
+    /// Enhanced multi-link single-radio (EMLSR) mode is supported.
+    pub fn emlsr_support(&self) -> bool {
+        self.0 & 0x0001 > 0
+    }
+
+    /// Enhanced multi-link multi-radio (EMLMR) mode is supported.
+    pub fn emlmr_support(&self) -> bool {
+        self.0 & 0x0080 > 0
+    }
+}
+
+/// The MLD capabilities and operations of a MLD.
+///
+/// Only the most important sub-fields have accessors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MldCapabilities(pub u16);
+
+impl MldCapabilities {
+    /// The maximum number of links that can be used at the same time, minus one.
+    pub fn max_simultaneous_links(&self) -> u8 {
+        (self.0 & 0x000f) as u8
+    }
+
+    /// The TID-to-link mapping negotiation support (0-3).
+    pub fn tid_to_link_mapping_negotiation_support(&self) -> u8 {
+        ((self.0 >> 5) & 0b11) as u8
+    }
+}
+
+/// The DTIM info of a [PerStaProfile].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DtimInfo {
+    pub dtim_count: u8,
+    pub dtim_period: u8,
+}
+
+/// The NSTR indication bitmap of a [PerStaProfile].
+/// Each bit indicates, whether the link is a non-simultaneous transmit and receive (NSTR)
+/// link pair with the link of the respective link ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NstrIndicationBitmap {
+    OneOctet(u8),
+    TwoOctets(u16),
+}
+
+/// The Per-STA Profile subelement of a [MultiLink] element.
+/// It contains the information of a single other link of the MLD.
+///
+/// Structure:
+/// - 1 byte: Subelement ID (always `0`)
+/// - 1 byte: Subelement length
+/// - 2 bytes: STA control
+/// - STA info (variable, only in the Basic variant):
+///   - 1 byte: STA info length (including this byte)
+///   - 6 bytes: STA MAC address (optional)
+///   - 2 bytes: Beacon interval (optional)
+///   - 8 bytes: TSF offset (optional)
+///   - 2 bytes: DTIM info (optional)
+///   - 1 or 2 bytes: NSTR indication bitmap (optional)
+///   - 1 byte: BSS parameters change count (optional)
+/// - STA profile (variable)
+///
+/// The STA profile contains the fixed fields and elements of the frame that would be sent
+/// by the other link. Elements that aren't in the profile are inherited from the reporting
+/// frame, see [PerStaProfile::station_info].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PerStaProfile {
+    pub link_id: u8,
+    /// The profile contains all elements of the link.
+    /// In the Probe Request variant, this means that a complete profile is requested.
+    pub complete_profile: bool,
+    /// The address of the link. For APs, this is their BSSID.
+    pub sta_mac_address: Option<MacAddress>,
+    pub beacon_interval: Option<u16>,
+    /// The offset of the TSF of this link to the TSF of the reporting link in microseconds.
+    pub tsf_offset: Option<i64>,
+    pub dtim_info: Option<DtimInfo>,
+    pub nstr_indication_bitmap: Option<NstrIndicationBitmap>,
+    pub bss_parameters_change_count: Option<u8>,
+    /// The raw STA profile, which consists of the fixed fields of the frame followed by
+    /// its elements.
+    pub sta_profile: Vec<u8>,
+}
+
+impl PerStaProfile {
+    /// Get all elements of this link, including the ones inherited from the reporting frame.
+    ///
+    /// `fixed_fields_len` is the length of the fixed fields at the start of the STA profile,
+    /// which depends on the type of the reporting frame. For example, beacons and probe
+    /// responses only contain the 2 byte capability info, probe requests contain no fixed
+    /// fields at all.
+    ///
+    /// Elements of the reporting frame are inherited, unless the profile contains an element
+    /// with the same ID or lists them in its Non-Inheritance element.
+    /// The Multi-Link element itself is never inherited.
+    pub fn station_info(
+        &self,
+        reporting: &StationInfo,
+        fixed_fields_len: usize,
+    ) -> Result<StationInfo, Error> {
+        let Some(profile) = self.sta_profile.get(fixed_fields_len..) else {
+            return Err(Error::Incomplete(format!(
+                "The STA profile is shorter than the fixed fields ({} bytes)",
+                fixed_fields_len
+            )));
+        };
+        let mut station_info = parse_station_info(profile)?;
+
+        let mut non_inheritance = None;
+        if let Some(position) = station_info
+            .elements
+            .iter()
+            .position(|element| element_key(element) == NON_INHERITANCE_KEY)
+        {
+            let (_, data) = station_info.elements.remove(position);
+            let (_, value) = parse_non_inheritance(&data[1..])?;
+            non_inheritance = Some(value);
+        }
+
+        let mut inherited = Vec::new();
+        for element in &reporting.elements {
+            let key = element_key(element);
+            let overridden = station_info
+                .elements
+                .iter()
+                .any(|profile_element| element_key(profile_element) == key);
+            let excluded = non_inheritance.as_ref().is_some_and(|non_inheritance| {
+                !non_inheritance.inherits(key.0.into(), key.1.map(Into::into))
+            });
+
+            if !overridden && !excluded && key != MULTI_LINK_KEY && key != NON_INHERITANCE_KEY {
+                inherited.push(element.clone());
+            }
+        }
+        station_info.elements.extend(inherited);
+
+        Ok(station_info)
+    }
+}
+
+/// The Non-Inheritance element, which lists all elements of the reporting frame that
+/// shouldn't be inherited by a [PerStaProfile].
+///
+/// Structure:
+/// - 1 byte: Length of the element ID list + element IDs
+/// - 1 byte: Length of the extension ID list + extension IDs
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NonInheritance {
+    pub element_ids: Vec<u8>,
+    pub extension_ids: Vec<u8>,
+}
+
+impl NonInheritance {
+    /// Whether the element with the given ID (and extension ID) may be inherited.
+    pub fn inherits(&self, id: u8, extension_id: Option<u8>) -> bool {
+        match extension_id {
+            Some(extension_id) => !self.extension_ids.contains(&extension_id),
+            None => !self.element_ids.contains(&id),
+        }
+    }
+}
+
+/// The key of the Multi-Link element.
+const MULTI_LINK_KEY: (ManagementInfoId, Option<ExtensionId>) = (
+    ManagementInfoId::InfoIdExtension,
+    Some(ExtensionId::MultiLink),
+);
+/// The key of the [NonInheritance] element.
+const NON_INHERITANCE_KEY: (ManagementInfoId, Option<ExtensionId>) = (
+    ManagementInfoId::InfoIdExtension,
+    Some(ExtensionId::NonInheritance),
+);
+
+/// The element ID and, for extension elements, the extension ID of an element.
+fn element_key(
+    (id, data): &(ManagementInfoId, Vec<u8>),
+) -> (ManagementInfoId, Option<ExtensionId>) {
+    match (id, data.first()) {
+        (ManagementInfoId::InfoIdExtension, Some(extension_id)) => {
+            (*id, Some(ExtensionId::from(*extension_id)))
+        }
+        _ => (*id, None),
+    }
+}
@@ -0,0 +1,150 @@
+use crate::error::Error;
+
+/// A single entry of the Supported Rates and Extended Supported Rates elements.
+///
+/// Each entry is either a data rate or a BSS membership selector:
+/// - Bit 7: The rate is part of the basic rate set, which must be supported by all stations
+///   of the BSS. Always set for BSS membership selectors.
+/// - Bit 0-6: The rate in units of 500 kbps, or the value of the BSS membership selector.
+///
+/// ```
+/// use libwifi::frame::components::{BssMembershipSelector, SupportedRate};
+///
+/// let rate = SupportedRate(0x8c);
+/// assert_eq!(rate.kbps(), Some(6000));
+/// assert!(rate.is_basic());
+///
+/// let selector = SupportedRate(0xff);
+/// assert_eq!(selector.selector(), Some(BssMembershipSelector::HtPhy));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SupportedRate(pub u8);
+
+impl SupportedRate {
+    /// Create a data rate from its speed in kbps.
+    ///
+    /// The rate must be a multiple of 500 kbps and mustn't collide with a
+    /// [BssMembershipSelector].
+    pub fn new(kbps: u32, is_basic: bool) -> Result<Self, Error> {
+        let units = kbps / 500;
+        if !kbps.is_multiple_of(500)
+            || units == 0
+            || units >= u32::from(BssMembershipSelector::MIN_VALUE)
+        {
+            return Err(Error::SerializeFailure(format!(
+                "Can't be an 802.11 supported rate: {} kbps!",
+                kbps
+            )));
+        }
+
+        Ok(SupportedRate(units as u8 | ((is_basic as u8) << 7)))
+    }
+
+    /// Create an entry for a BSS membership selector.
+    pub fn from_selector(selector: BssMembershipSelector) -> Self {
+        SupportedRate(0x80 | selector.value())
+    }
+
+    /// The rate is part of the basic rate set of the BSS.
+    /// Always `false` for BSS membership selectors.
+    pub fn is_basic(&self) -> bool {
+        self.0 & 0x80 > 0 && self.selector().is_none()
+    }
+
+    /// The data rate in kbps.
+    /// Returns `None` for BSS membership selectors.
+    pub fn kbps(&self) -> Option<u32> {
+        if self.selector().is_some() {
+            return None;
+        }
+
+        Some(u32::from(self.0 & 0x7f) * 500)
+    }
+
+    /// The BSS membership selector of this entry, if it isn't a data rate.
+    pub fn selector(&self) -> Option<BssMembershipSelector> {
+        if self.0 & 0x80 == 0 {
+            return None;
+        }
+
+        BssMembershipSelector::from_value(self.0 & 0x7f)
+    }
+}
+
+impl TryFrom<SupportedRate> for u32 {
+    type Error = Error;
+
+    /// Supported rate in Kbps.
+    fn try_from(value: SupportedRate) -> Result<Self, Self::Error> {
+        value.kbps().ok_or_else(|| {
+            Error::ParseFailure(
+                "BSS membership selectors don't have a rate!".to_string(),
+                vec![value.0],
+            )
+        })
+    }
+}
+
+impl TryFrom<u32> for SupportedRate {
+    type Error = Error;
+
+    /// Create a rate, which isn't part of the basic rate set, from its speed in kbps.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        SupportedRate::new(value, false)
+    }
+}
+
+/// A BSS membership selector, which is sent in the basic rate set to require features of
+/// all stations that want to join the BSS.
+///
+/// Reference: IEEE 802.11-2020, Table 9-78 and IEEE 802.11be.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BssMembershipSelector {
+    /// 802.11be is required.
+    EhtPhy,
+    /// 802.11ax is required.
+    HePhy,
+    /// Only SAE with the hash-to-element method for the password element is supported.
+    SaeHashToElementOnly,
+    /// Support for the EPD (EtherType Protocol Discrimination) is required.
+    Epd,
+    /// Support for the GLK (General Link) is required.
+    GlkPhy,
+    /// 802.11ac is required.
+    VhtPhy,
+    /// 802.11n is required.
+    HtPhy,
+}
+
+impl BssMembershipSelector {
+    /// The lowest value of a BSS membership selector.
+    /// All lower values are data rates.
+    const MIN_VALUE: u8 = 121;
+
+    /// Create a selector from its 7 bit value, without the basic rate bit.
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value {
+            121 => Some(BssMembershipSelector::EhtPhy),
+            122 => Some(BssMembershipSelector::HePhy),
+            123 => Some(BssMembershipSelector::SaeHashToElementOnly),
+            124 => Some(BssMembershipSelector::Epd),
+            125 => Some(BssMembershipSelector::GlkPhy),
+            126 => Some(BssMembershipSelector::VhtPhy),
+            127 => Some(BssMembershipSelector::HtPhy),
+            _ => None,
+        }
+    }
+
+    /// The 7 bit value of this selector, without the basic rate bit.
+    pub fn value(&self) -> u8 {
+        match self {
+            BssMembershipSelector::EhtPhy => 121,
+            BssMembershipSelector::HePhy => 122,
+            BssMembershipSelector::SaeHashToElementOnly => 123,
+            BssMembershipSelector::Epd => 124,
+            BssMembershipSelector::GlkPhy => 125,
+            BssMembershipSelector::VhtPhy => 126,
+            BssMembershipSelector::HtPhy => 127,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use num_enum::{FromPrimitive, IntoPrimitive};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, IntoPrimitive)]
+#[repr(u16)]
+/// The reason code used in Deauthentication, Disassociation and DELBA frames to indicate
+/// why a connection or session has been torn down.
+///
+/// Reference: IEEE 802.11-2020, Table 9-49.
+///
+/// Codes that aren't explicitly listed are preserved via [ReasonCode::Unknown].
+pub enum ReasonCode {
+    Reserved = 0,
+    Unspecified = 1,
+    InvalidAuthentication = 2,
+    LeavingNetworkDeauth = 3,
+    ReasonInactivity = 4,
+    NoMoreStas = 5,
+    InvalidClass2Frame = 6,
+    InvalidClass3Frame = 7,
+    LeavingNetworkDisassoc = 8,
+    NotAuthenticated = 9,
+    UnacceptablePowerCapability = 10,
+    UnacceptableSupportedChannels = 11,
+    BssTransitionDisassoc = 12,
+    ReasonInvalidElement = 13,
+    MicFailure = 14,
+    FourWayHandshakeTimeout = 15,
+    GkHandshakeTimeout = 16,
+    HandshakeElementMismatch = 17,
+    ReasonInvalidGroupCipher = 18,
+    ReasonInvalidPairwiseCipher = 19,
+    ReasonInvalidAkmp = 20,
+    UnsupportedRsneVersion = 21,
+    InvalidRsneCapabilities = 22,
+    Ieee8021xAuthFailed = 23,
+    ReasonCipherOutOfPolicy = 24,
+    TdlsDirectLinkUnreachable = 25,
+    TdlsDirectLinkUnspecified = 26,
+    SspRequestedDisassoc = 27,
+    NoSspRoamingAgreement = 28,
+    BadCipherOrAkm = 29,
+    NotAuthorizedThisLocation = 30,
+    ServiceChangePrecludesTs = 31,
+    UnspecifiedQosReason = 32,
+    NotEnoughBandwidth = 33,
+    MissingAcks = 34,
+    ExceededTxop = 35,
+    StaLeaving = 36,
+    EndTsBaDls = 37,
+    UnknownTsBa = 38,
+    Timeout = 39,
+    PeerkeyMismatch = 45,
+    PeerInitiated = 46,
+    ApInitiated = 47,
+    ReasonInvalidFtActionFrameCount = 48,
+    ReasonInvalidPmkid = 49,
+    ReasonInvalidMde = 50,
+    ReasonInvalidFte = 51,
+    MeshPeeringCanceled = 52,
+    MeshMaxPeers = 53,
+    MeshConfigurationPolicyViolation = 54,
+    MeshCloseRcvd = 55,
+    MeshMaxRetries = 56,
+    MeshConfirmTimeout = 57,
+    MeshInvalidGtk = 58,
+    MeshInconsistentParameters = 59,
+    MeshInvalidSecurityCapability = 60,
+    MeshPathErrorNoProxyInformation = 61,
+    MeshPathErrorNoForwardingInformation = 62,
+    MeshPathErrorDestinationUnreachable = 63,
+    MacAddressAlreadyExistsInMbss = 64,
+    MeshChannelSwitchRegulatoryRequirements = 65,
+    MeshChannelSwitchUnspecified = 66,
+    TransmissionLinkEstablishmentFailed = 67,
+    AlternativeChannelOccupied = 68,
+    PoorRssiConditions = 71,
+    #[num_enum(catch_all)]
+    Unknown(u16),
+}
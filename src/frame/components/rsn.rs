@@ -0,0 +1,289 @@
+use bitflags::bitflags;
+
+/// The IEEE 802.11 organization identifier that's used by all standard suite selectors.
+pub const IEEE_OUI: [u8; 3] = [0x00, 0x0f, 0xac];
+
+/// A cipher suite selector as used in the RSN element.
+///
+/// Each selector consists of a 3 byte organization identifier and a 1 byte suite type.
+/// Standard suites use the [IEEE_OUI], all others are preserved in [CipherSuite::Other].
+///
+/// Reference: IEEE 802.11-2020, Table 9-149.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CipherSuite {
+    /// Use the group cipher suite. Only valid as pairwise cipher.
+    UseGroupCipher,
+    Wep40,
+    Tkip,
+    Ccmp128,
+    Wep104,
+    BipCmac128,
+    /// Only valid as group cipher.
+    GroupAddressedTrafficNotAllowed,
+    Gcmp128,
+    Gcmp256,
+    Ccmp256,
+    BipGmac128,
+    BipGmac256,
+    BipCmac256,
+    Other {
+        oui: [u8; 3],
+        suite_type: u8,
+    },
+}
+
+impl CipherSuite {
+    /// Create a cipher suite from its organization identifier and suite type.
+    pub fn from_selector(oui: [u8; 3], suite_type: u8) -> Self {
+        if oui != IEEE_OUI {
+            return CipherSuite::Other { oui, suite_type };
+        }
+
+        match suite_type {
+            0 => CipherSuite::UseGroupCipher,
+            1 => CipherSuite::Wep40,
+            2 => CipherSuite::Tkip,
+            4 => CipherSuite::Ccmp128,
+            5 => CipherSuite::Wep104,
+            6 => CipherSuite::BipCmac128,
+            7 => CipherSuite::GroupAddressedTrafficNotAllowed,
+            8 => CipherSuite::Gcmp128,
+            9 => CipherSuite::Gcmp256,
+            10 => CipherSuite::Ccmp256,
+            11 => CipherSuite::BipGmac128,
+            12 => CipherSuite::BipGmac256,
+            13 => CipherSuite::BipCmac256,
+            _ => CipherSuite::Other { oui, suite_type },
+        }
+    }
+
+    /// Get the organization identifier and suite type of this cipher suite.
+    pub fn selector(&self) -> ([u8; 3], u8) {
+        let suite_type = match self {
+            CipherSuite::UseGroupCipher => 0,
+            CipherSuite::Wep40 => 1,
+            CipherSuite::Tkip => 2,
+            CipherSuite::Ccmp128 => 4,
+            CipherSuite::Wep104 => 5,
+            CipherSuite::BipCmac128 => 6,
+            CipherSuite::GroupAddressedTrafficNotAllowed => 7,
+            CipherSuite::Gcmp128 => 8,
+            CipherSuite::Gcmp256 => 9,
+            CipherSuite::Ccmp256 => 10,
+            CipherSuite::BipGmac128 => 11,
+            CipherSuite::BipGmac256 => 12,
+            CipherSuite::BipCmac256 => 13,
+            CipherSuite::Other { oui, suite_type } => return (*oui, *suite_type),
+        };
+
+        (IEEE_OUI, suite_type)
+    }
+}
+
+/// An authentication and key management (AKM) suite selector as used in the RSN element.
+///
+/// Standard suites use the [IEEE_OUI], all others are preserved in [AkmSuite::Other].
+///
+/// Reference: IEEE 802.11-2020, Table 9-151.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AkmSuite {
+    /// WPA-Enterprise
+    Ieee8021x,
+    /// WPA-Personal
+    Psk,
+    FtIeee8021x,
+    FtPsk,
+    Ieee8021xSha256,
+    PskSha256,
+    Tdls,
+    /// WPA3-Personal
+    Sae,
+    FtSae,
+    ApPeerKey,
+    /// WPA3-Enterprise Suite-B
+    Ieee8021xSuiteB,
+    /// WPA3-Enterprise 192-bit
+    Ieee8021xSuiteB192,
+    FtIeee8021xSha384,
+    FilsSha256,
+    FilsSha384,
+    FtFilsSha256,
+    FtFilsSha384,
+    /// Opportunistic Wireless Encryption (Enhanced Open)
+    Owe,
+    FtPskSha384,
+    PskSha384,
+    Pasn,
+    Ieee8021xSha384,
+    SaeExtKey,
+    FtSaeExtKey,
+    Other {
+        oui: [u8; 3],
+        suite_type: u8,
+    },
+}
+
+impl AkmSuite {
+    /// Create an AKM suite from its organization identifier and suite type.
+    pub fn from_selector(oui: [u8; 3], suite_type: u8) -> Self {
+        if oui != IEEE_OUI {
+            return AkmSuite::Other { oui, suite_type };
+        }
+
+        match suite_type {
+            1 => AkmSuite::Ieee8021x,
+            2 => AkmSuite::Psk,
+            3 => AkmSuite::FtIeee8021x,
+            4 => AkmSuite::FtPsk,
+            5 => AkmSuite::Ieee8021xSha256,
+            6 => AkmSuite::PskSha256,
+            7 => AkmSuite::Tdls,
+            8 => AkmSuite::Sae,
+            9 => AkmSuite::FtSae,
+            10 => AkmSuite::ApPeerKey,
+            11 => AkmSuite::Ieee8021xSuiteB,
+            12 => AkmSuite::Ieee8021xSuiteB192,
+            13 => AkmSuite::FtIeee8021xSha384,
+            14 => AkmSuite::FilsSha256,
+            15 => AkmSuite::FilsSha384,
+            16 => AkmSuite::FtFilsSha256,
+            17 => AkmSuite::FtFilsSha384,
+            18 => AkmSuite::Owe,
+            19 => AkmSuite::FtPskSha384,
+            20 => AkmSuite::PskSha384,
+            21 => AkmSuite::Pasn,
+            23 => AkmSuite::Ieee8021xSha384,
+            24 => AkmSuite::SaeExtKey,
+            25 => AkmSuite::FtSaeExtKey,
+            _ => AkmSuite::Other { oui, suite_type },
+        }
+    }
+
+    /// Get the organization identifier and suite type of this AKM suite.
+    pub fn selector(&self) -> ([u8; 3], u8) {
+        let suite_type = match self {
+            AkmSuite::Ieee8021x => 1,
+            AkmSuite::Psk => 2,
+            AkmSuite::FtIeee8021x => 3,
+            AkmSuite::FtPsk => 4,
+            AkmSuite::Ieee8021xSha256 => 5,
+            AkmSuite::PskSha256 => 6,
+            AkmSuite::Tdls => 7,
+            AkmSuite::Sae => 8,
+            AkmSuite::FtSae => 9,
+            AkmSuite::ApPeerKey => 10,
+            AkmSuite::Ieee8021xSuiteB => 11,
+            AkmSuite::Ieee8021xSuiteB192 => 12,
+            AkmSuite::FtIeee8021xSha384 => 13,
+            AkmSuite::FilsSha256 => 14,
+            AkmSuite::FilsSha384 => 15,
+            AkmSuite::FtFilsSha256 => 16,
+            AkmSuite::FtFilsSha384 => 17,
+            AkmSuite::Owe => 18,
+            AkmSuite::FtPskSha384 => 19,
+            AkmSuite::PskSha384 => 20,
+            AkmSuite::Pasn => 21,
+            AkmSuite::Ieee8021xSha384 => 23,
+            AkmSuite::SaeExtKey => 24,
+            AkmSuite::FtSaeExtKey => 25,
+            AkmSuite::Other { oui, suite_type } => return (*oui, *suite_type),
+        };
+
+        (IEEE_OUI, suite_type)
+    }
+
+    /// Whether this AKM uses Fast BSS Transition (802.11r).
+    pub fn is_fast_transition(&self) -> bool {
+        matches!(
+            self,
+            AkmSuite::FtIeee8021x
+                | AkmSuite::FtPsk
+                | AkmSuite::FtSae
+                | AkmSuite::FtIeee8021xSha384
+                | AkmSuite::FtFilsSha256
+                | AkmSuite::FtFilsSha384
+                | AkmSuite::FtPskSha384
+                | AkmSuite::FtSaeExtKey
+        )
+    }
+}
+
+bitflags! {
+    /// The RSN capabilities field.
+    ///
+    /// The replay counter masks span two bits each.
+    /// Their value is the exponent of the number of replay counters (`2^n`).
+    pub struct RsnCapabilities: u16 {
+        const PREAUTH                   = 0x0001;
+        const NO_PAIRWISE               = 0x0002;
+        const PTKSA_REPLAY_COUNTER      = 0x000c;
+        const GTKSA_REPLAY_COUNTER      = 0x0030;
+        const MFP_REQUIRED              = 0x0040;
+        const MFP_CAPABLE               = 0x0080;
+        const JOINT_MULTIBAND_RSNA      = 0x0100;
+        const PEERKEY_ENABLED           = 0x0200;
+        const SPP_AMSDU_CAPABLE         = 0x0400;
+        const SPP_AMSDU_REQUIRED        = 0x0800;
+        const PBAC                      = 0x1000;
+        const EXTENDED_KEY_ID           = 0x2000;
+        const OCVC                      = 0x4000;
+    }
+}
+
+impl Default for RsnCapabilities {
+    fn default() -> Self {
+        RsnCapabilities::empty()
+    }
+}
+
+/// The Robust Security Network (RSN) element, which advertises the supported ciphers and
+/// authentication methods of WPA2/WPA3 networks.
+///
+/// Structure:
+/// - 2 bytes: Version (always `1`)
+/// - 4 bytes: Group data cipher suite
+/// - 2 bytes: Pairwise cipher suite count + `4 * n` bytes pairwise cipher suites
+/// - 2 bytes: AKM suite count + `4 * n` bytes AKM suites
+/// - 2 bytes: RSN capabilities
+/// - 2 bytes: PMKID count + `16 * n` bytes PMKIDs (optional)
+/// - 4 bytes: Group management cipher suite (optional)
+///
+/// All fields after the version are optional and may be omitted from the end.
+/// In that case, the defaults from the spec are used (CCMP-128 and 802.1X).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RsnInformation {
+    pub version: u16,
+    pub group_cipher: CipherSuite,
+    pub pairwise_ciphers: Vec<CipherSuite>,
+    pub akm_suites: Vec<AkmSuite>,
+    pub capabilities: RsnCapabilities,
+    pub pmkids: Vec<[u8; 16]>,
+    /// Only present if management frame protection is in use.
+    pub group_management_cipher: Option<CipherSuite>,
+}
+
+impl Default for RsnInformation {
+    fn default() -> Self {
+        RsnInformation {
+            version: 1,
+            group_cipher: CipherSuite::Ccmp128,
+            pairwise_ciphers: vec![CipherSuite::Ccmp128],
+            akm_suites: vec![AkmSuite::Ieee8021x],
+            capabilities: RsnCapabilities::empty(),
+            pmkids: Vec::new(),
+            group_management_cipher: None,
+        }
+    }
+}
+
+impl RsnInformation {
+    /// Management frame protection is required by this network.
+    pub fn mfp_required(&self) -> bool {
+        self.capabilities.contains(RsnCapabilities::MFP_REQUIRED)
+    }
+
+    /// Management frame protection is supported by this network.
+    pub fn mfp_capable(&self) -> bool {
+        self.capabilities.contains(RsnCapabilities::MFP_CAPABLE)
+    }
+}
@@ -1,8 +1,17 @@
-use num_enum::{IntoPrimitive, TryFromPrimitive};
+use nom::IResult;
+use num_enum::{FromPrimitive, IntoPrimitive};
 
+use super::{
+    Country, DsParameterSet, EhtCapabilities, EhtOperation, ErpInformation, ExtensionId,
+    HeCapabilities, HeOperation, HtCapabilities, HtOperation, MultiLink, RsnInformation,
+    SupportedRate, Tim, VendorElement, VendorId, VendorRegistry, VhtCapabilities, VhtOperation,
+    Wmm, WpaInformation,
+};
 use crate::error::Error;
+use crate::generators::*;
+use crate::parsers::*;
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 /// StationInfo is used to parse and store variable length fields that are often sent
 /// with management frames.
 ///
@@ -11,89 +20,573 @@ use crate::error::Error;
 /// of these fields, this generic solution is used to capture all of them.
 ///
 /// It is also important to note that most of these fields won't be sent most of the time. \
-/// All fields that are already handled by this library get their own field in the StationInfo
-/// struct.
+/// All elements are stored in the `elements` field in the order in which they appear in the
+/// frame. This list is the source of truth, which makes parsing and serializing lossless.
+/// Elements that are longer than 255 bytes are sent as multiple fragments, which are stored
+/// as a single element.
 ///
-/// Since we cannot handle all all those elements, the bytes of all unhandled elements will
-/// be saved in the `data` field under the respectiv element id.
+/// Elements that are already handled by this library can be accessed with typed getters,
+/// such as [StationInfo::ssid] or [StationInfo::rsn_information], which parse the respective
+/// element on demand. \
+/// The typed setters, such as [StationInfo::set_rsn_information], replace the existing element
+/// or insert a new one at its position in the element order of the spec.
 pub struct StationInfo {
-    /// The transmission rates that are supported by the station.
-    /// Empty if no rates were transmitted.
-    pub supported_rates: Vec<SupportedRate>,
-    /// If the sender included a SSID, it will be in here.
-    pub ssid: Option<String>,
-    /// This map contains all fields that aren't explicitly parsed by us.
-    /// The format is Vec<(FieldId, PayloadBytes)>.
+    /// All elements in the order in which they're sent.
+    /// The format is Vec<(ElementId, PayloadBytes)>.
     ///
-    /// Please consider to create a PR, if you write a parser for a new field :).
-    pub data: Vec<(ManagementInfoId, Vec<u8>)>,
+    /// The payload of extension elements starts with their [ExtensionId].
+    pub elements: Vec<(ManagementInfoId, Vec<u8>)>,
 }
 
-#[derive(Clone, Debug, Default)]
-pub struct SupportedRate(pub u8);
-
-impl TryFrom<SupportedRate> for u32 {
-    type Error = Error;
-
-    /// Supported rate in Kbps.
-    fn try_from(value: SupportedRate) -> Result<Self, Self::Error> {
-        match value.0 {
-            0x82 => Ok(1000),
-            0x84 => Ok(2000),
-            0x8b => Ok(5500),
-            0x0c => Ok(6000),
-            0x12 => Ok(9000),
-            0x96 => Ok(11000),
-            0x18 => Ok(12000),
-            0x24 => Ok(18000),
-            0x2c => Ok(22000),
-            0x30 => Ok(24000),
-            0x42 => Ok(33000),
-            0x48 => Ok(36000),
-            0x60 => Ok(48000),
-            0x6c => Ok(54000),
-            _ => Err(Error::ParseFailure(
-                "Unknown supported rate value!".to_string(),
-                vec![value.0],
-            )),
-        }
+impl StationInfo {
+    /// The SSID of the network as string, if the sender included one.
+    ///
+    /// This is a lossy view on [StationInfo::ssid_bytes]. Invalid UTF-8 sequences are replaced
+    /// and null characters are replaced by spaces, as some APs seem to enjoy sending those.
+    pub fn ssid(&self) -> Option<String> {
+        self.ssid_bytes()
+            .map(|ssid| String::from_utf8_lossy(ssid).replace('\0', " "))
+    }
+
+    pub fn set_ssid(&mut self, ssid: &str) {
+        self.set_ssid_bytes(ssid.as_bytes());
+    }
+
+    /// The raw SSID, exactly as it was sent.
+    /// The spec doesn't require SSIDs to be valid UTF-8.
+    pub fn ssid_bytes(&self) -> Option<&[u8]> {
+        self.element(ManagementInfoId::SSID)
+    }
+
+    pub fn set_ssid_bytes(&mut self, ssid: &[u8]) {
+        self.set_element(ManagementInfoId::SSID, &[], ssid.to_vec());
     }
-}
 
-impl TryFrom<u32> for SupportedRate {
-    type Error = Error;
-
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        let rate = match value {
-            1000 => Some(0x82),
-            2000 => Some(0x84),
-            5500 => Some(0x8b),
-            6000 => Some(0x0c),
-            9000 => Some(0x12),
-            11000 => Some(0x96),
-            12000 => Some(0x18),
-            18000 => Some(0x24),
-            22000 => Some(0x2c),
-            24000 => Some(0x30),
-            33000 => Some(0x42),
-            36000 => Some(0x48),
-            48000 => Some(0x60),
-            54000 => Some(0x6c),
+    /// Check whether the SSID is hidden, and how it's hidden.
+    ///
+    /// Returns `None` if the SSID is visible or if there's no SSID element.
+    /// Note that an empty SSID in a probe request isn't a hidden SSID,
+    /// but the wildcard SSID, see [StationInfo::is_wildcard_ssid].
+    pub fn hidden_ssid(&self) -> Option<HiddenSsid> {
+        match self.ssid_bytes()? {
+            [] => Some(HiddenSsid::Empty),
+            ssid if ssid.iter().all(|byte| *byte == 0) => Some(HiddenSsid::NulPadded(ssid.len())),
             _ => None,
-        };
-        rate.map_or_else(
-            || {
-                Err(Error::SerializeFailure(format!(
-                    "Can't be an 802.11 supported rate: {} kbps!",
-                    value
-                )))
-            },
-            |x| Ok(SupportedRate(x)),
+        }
+    }
+
+    /// Check whether the SSID is the broadcast wildcard SSID.
+    ///
+    /// Probe requests use an empty SSID to ask all networks in range for a response.
+    /// In beacons and probe responses, the same empty SSID signals a [HiddenSsid::Empty].
+    pub fn is_wildcard_ssid(&self) -> bool {
+        self.ssid_bytes().is_some_and(|ssid| ssid.is_empty())
+    }
+
+    /// The transmission rates and BSS membership selectors that are supported by the station.
+    /// Empty if no rates were transmitted.
+    ///
+    /// The rates of the Supported Rates and Extended Supported Rates elements are merged.
+    pub fn supported_rates(&self) -> Vec<SupportedRate> {
+        [
+            ManagementInfoId::SupportedRates,
+            ManagementInfoId::ExtSupportedRates,
+        ]
+        .into_iter()
+        .filter_map(|id| self.element(id))
+        .flatten()
+        .map(|rate| SupportedRate(*rate))
+        .collect()
+    }
+
+    /// Set the supported rates and BSS membership selectors.
+    ///
+    /// The Supported Rates element can only hold 8 rates.
+    /// All other rates are put into the Extended Supported Rates element, which is removed
+    /// if it isn't needed. Both elements are removed, if no rates are given.
+    pub fn set_supported_rates(&mut self, rates: &[SupportedRate]) {
+        let rates: Vec<u8> = rates.iter().map(|rate| rate.0).collect();
+        let (rates, extended_rates) = rates.split_at(rates.len().min(MAX_SUPPORTED_RATES));
+
+        if rates.is_empty() {
+            self.elements
+                .retain(|(id, _)| *id != ManagementInfoId::SupportedRates);
+        } else {
+            self.set_element(ManagementInfoId::SupportedRates, &[], rates.to_vec());
+        }
+        if extended_rates.is_empty() {
+            self.elements
+                .retain(|(id, _)| *id != ManagementInfoId::ExtSupportedRates);
+        } else {
+            self.set_element(
+                ManagementInfoId::ExtSupportedRates,
+                &[],
+                extended_rates.to_vec(),
+            );
+        }
+    }
+
+    /// The channel of the BSS.
+    pub fn ds_parameter_set(&self) -> Option<DsParameterSet> {
+        self.typed_element(
+            ManagementInfoId::DsParameterSet,
+            &[],
+            parse_ds_parameter_set,
+        )
+    }
+
+    pub fn set_ds_parameter_set(&mut self, ds: &DsParameterSet) -> Result<(), Error> {
+        self.set_typed_element(
+            ManagementInfoId::DsParameterSet,
+            ds,
+            &[],
+            gen_ds_parameter_set,
+        )
+    }
+
+    /// The traffic indication map, which announces buffered frames for stations in power
+    /// save mode.
+    pub fn tim(&self) -> Option<Tim> {
+        self.typed_element(ManagementInfoId::TIM, &[], parse_tim)
+    }
+
+    pub fn set_tim(&mut self, tim: &Tim) -> Result<(), Error> {
+        self.set_typed_element(ManagementInfoId::TIM, tim, &[], gen_tim)
+    }
+
+    /// The regulatory domain of the BSS.
+    pub fn country(&self) -> Option<Country> {
+        self.typed_element(ManagementInfoId::Country, &[], parse_country)
+    }
+
+    pub fn set_country(&mut self, country: &Country) -> Result<(), Error> {
+        self.set_typed_element(ManagementInfoId::Country, country, &[], gen_country)
+    }
+
+    /// The 802.11g protection parameters of the BSS.
+    pub fn erp_information(&self) -> Option<ErpInformation> {
+        self.typed_element(ManagementInfoId::ErpInfo, &[], parse_erp_information)
+    }
+
+    pub fn set_erp_information(&mut self, erp: &ErpInformation) -> Result<(), Error> {
+        self.set_typed_element(ManagementInfoId::ErpInfo, erp, &[], gen_erp_information)
+    }
+
+    /// The RSN element, which contains the security configuration of WPA2/WPA3 networks.
+    pub fn rsn_information(&self) -> Option<RsnInformation> {
+        self.typed_element(
+            ManagementInfoId::RobustSecurityNetwork,
+            &[],
+            parse_rsn_information,
+        )
+    }
+
+    pub fn set_rsn_information(&mut self, rsn: &RsnInformation) -> Result<(), Error> {
+        self.set_typed_element(
+            ManagementInfoId::RobustSecurityNetwork,
+            rsn,
+            &[],
+            gen_rsn_information,
+        )
+    }
+
+    /// The 802.11n capabilities of the station.
+    pub fn ht_capabilities(&self) -> Option<HtCapabilities> {
+        self.typed_element(ManagementInfoId::HtCapability, &[], parse_ht_capabilities)
+    }
+
+    pub fn set_ht_capabilities(&mut self, ht: &HtCapabilities) -> Result<(), Error> {
+        self.set_typed_element(ManagementInfoId::HtCapability, ht, &[], gen_ht_capabilities)
+    }
+
+    /// The 802.11n operation parameters of the BSS.
+    pub fn ht_operation(&self) -> Option<HtOperation> {
+        self.typed_element(ManagementInfoId::HtInfo, &[], parse_ht_operation)
+    }
+
+    pub fn set_ht_operation(&mut self, ht: &HtOperation) -> Result<(), Error> {
+        self.set_typed_element(ManagementInfoId::HtInfo, ht, &[], gen_ht_operation)
+    }
+
+    /// The 802.11ac capabilities of the station.
+    pub fn vht_capabilities(&self) -> Option<VhtCapabilities> {
+        self.typed_element(
+            ManagementInfoId::VhtCapabilities,
+            &[],
+            parse_vht_capabilities,
+        )
+    }
+
+    pub fn set_vht_capabilities(&mut self, vht: &VhtCapabilities) -> Result<(), Error> {
+        self.set_typed_element(
+            ManagementInfoId::VhtCapabilities,
+            vht,
+            &[],
+            gen_vht_capabilities,
+        )
+    }
+
+    /// The 802.11ac operation parameters of the BSS.
+    pub fn vht_operation(&self) -> Option<VhtOperation> {
+        self.typed_element(ManagementInfoId::VhtOperation, &[], parse_vht_operation)
+    }
+
+    pub fn set_vht_operation(&mut self, vht: &VhtOperation) -> Result<(), Error> {
+        self.set_typed_element(ManagementInfoId::VhtOperation, vht, &[], gen_vht_operation)
+    }
+
+    /// The 802.11ax capabilities of the station.
+    pub fn he_capabilities(&self) -> Option<HeCapabilities> {
+        self.typed_extension(ExtensionId::HeCapabilities, parse_he_capabilities)
+    }
+
+    pub fn set_he_capabilities(&mut self, he: &HeCapabilities) -> Result<(), Error> {
+        self.set_typed_element(
+            ManagementInfoId::InfoIdExtension,
+            he,
+            &[ExtensionId::HeCapabilities.into()],
+            gen_he_capabilities,
+        )
+    }
+
+    /// The 802.11ax operation parameters of the BSS.
+    pub fn he_operation(&self) -> Option<HeOperation> {
+        self.typed_extension(ExtensionId::HeOperation, parse_he_operation)
+    }
+
+    pub fn set_he_operation(&mut self, he: &HeOperation) -> Result<(), Error> {
+        self.set_typed_element(
+            ManagementInfoId::InfoIdExtension,
+            he,
+            &[ExtensionId::HeOperation.into()],
+            gen_he_operation,
+        )
+    }
+
+    /// The 802.11be capabilities of the station.
+    pub fn eht_capabilities(&self) -> Option<EhtCapabilities> {
+        self.typed_extension(ExtensionId::EhtCapabilities, parse_eht_capabilities)
+    }
+
+    pub fn set_eht_capabilities(&mut self, eht: &EhtCapabilities) -> Result<(), Error> {
+        self.set_typed_element(
+            ManagementInfoId::InfoIdExtension,
+            eht,
+            &[ExtensionId::EhtCapabilities.into()],
+            gen_eht_capabilities,
+        )
+    }
+
+    /// The 802.11be operation parameters of the BSS.
+    pub fn eht_operation(&self) -> Option<EhtOperation> {
+        self.typed_extension(ExtensionId::EhtOperation, parse_eht_operation)
+    }
+
+    pub fn set_eht_operation(&mut self, eht: &EhtOperation) -> Result<(), Error> {
+        self.set_typed_element(
+            ManagementInfoId::InfoIdExtension,
+            eht,
+            &[ExtensionId::EhtOperation.into()],
+            gen_eht_operation,
+        )
+    }
+
+    /// The 802.11be Multi-Link element, which describes the other links of a multi-link device.
+    pub fn multi_link(&self) -> Option<MultiLink> {
+        self.typed_extension(ExtensionId::MultiLink, parse_multi_link)
+    }
+
+    pub fn set_multi_link(&mut self, multi_link: &MultiLink) -> Result<(), Error> {
+        self.set_typed_element(
+            ManagementInfoId::InfoIdExtension,
+            multi_link,
+            &[ExtensionId::MultiLink.into()],
+            gen_multi_link,
+        )
+    }
+
+    /// The legacy WPA vendor element.
+    pub fn wpa_information(&self) -> Option<WpaInformation> {
+        self.typed_element(
+            ManagementInfoId::VendorSpecific,
+            &WPA_PREFIX,
+            parse_wpa_information,
+        )
+    }
+
+    pub fn set_wpa_information(&mut self, wpa: &WpaInformation) -> Result<(), Error> {
+        self.set_typed_element(
+            ManagementInfoId::VendorSpecific,
+            wpa,
+            &WPA_PREFIX,
+            gen_wpa_information,
+        )
+    }
+
+    /// The WMM Information or Parameter vendor element.
+    pub fn wmm(&self) -> Option<Wmm> {
+        self.typed_element(ManagementInfoId::VendorSpecific, &WMM_PREFIX, parse_wmm)
+    }
+
+    pub fn set_wmm(&mut self, wmm: &Wmm) -> Result<(), Error> {
+        self.set_typed_element(ManagementInfoId::VendorSpecific, wmm, &WMM_PREFIX, gen_wmm)
+    }
+
+    /// All vendor specific elements with their [VendorId] and the data after the vendor type.
+    /// Elements that are too short to contain an OUI and a vendor type are skipped.
+    pub fn raw_vendor_elements(&self) -> impl Iterator<Item = (VendorId, &[u8])> {
+        self.elements
+            .iter()
+            .filter(|(id, _)| *id == ManagementInfoId::VendorSpecific)
+            .filter_map(|(_, data)| match data.as_slice() {
+                [oui_0, oui_1, oui_2, vendor_type, body @ ..] => {
+                    Some((VendorId::new([*oui_0, *oui_1, *oui_2], *vendor_type), body))
+                }
+                _ => None,
+            })
+    }
+
+    /// All vendor specific elements that can be decoded by the given registry.
+    ///
+    /// Use [VendorRegistry::default] for the built-in decoders.
+    /// Elements without a decoder or with invalid data are skipped, they're still available
+    /// via [StationInfo::raw_vendor_elements].
+    pub fn vendor_elements<'a>(
+        &'a self,
+        registry: &'a VendorRegistry,
+    ) -> impl Iterator<Item = VendorElement> + 'a {
+        self.elements
+            .iter()
+            .filter(|(id, _)| *id == ManagementInfoId::VendorSpecific)
+            .filter_map(|(_, data)| registry.decode(data)?.ok())
+    }
+
+    /// Encode a vendor specific element with the given registry.
+    /// The first element with the same [VendorId] is replaced.
+    pub fn set_vendor_element(
+        &mut self,
+        registry: &VendorRegistry,
+        element: &VendorElement,
+    ) -> Result<(), Error> {
+        let payload = registry.encode(element)?;
+        self.set_element(
+            ManagementInfoId::VendorSpecific,
+            &element.vendor_id().prefix(),
+            payload,
+        );
+
+        Ok(())
+    }
+
+    /// All extension elements.
+    ///
+    /// They're stored in [StationInfo::elements] with the [ManagementInfoId::InfoIdExtension] id.
+    /// The first byte of their payload is the [ExtensionId], which is split off here.
+    pub fn raw_extensions(&self) -> impl Iterator<Item = (ExtensionId, &[u8])> {
+        self.elements
+            .iter()
+            .filter(|(id, data)| *id == ManagementInfoId::InfoIdExtension && !data.is_empty())
+            .map(|(_, data)| (ExtensionId::from(data[0]), &data[1..]))
+    }
+
+    /// The payload of the first element with the given id.
+    pub fn element(&self, id: ManagementInfoId) -> Option<&[u8]> {
+        self.elements
+            .iter()
+            .find(|(element_id, _)| *element_id == id)
+            .map(|(_, data)| data.as_slice())
+    }
+
+    /// Insert an element at its position in the element order of the spec.
+    ///
+    /// The element is inserted in front of the first element that comes after it.
+    /// Elements whose position isn't known are appended at the end.
+    pub fn insert_element(&mut self, id: ManagementInfoId, data: Vec<u8>) {
+        let position = element_order(id, data.first().copied()).and_then(|order| {
+            self.elements.iter().position(|(element_id, element_data)| {
+                element_order(*element_id, element_data.first().copied())
+                    .is_some_and(|element_order| element_order > order)
+            })
+        });
+
+        match position {
+            Some(position) => self.elements.insert(position, (id, data)),
+            None => self.elements.push((id, data)),
+        }
+    }
+
+    /// Replace the payload of the first element with the given id, whose payload starts with
+    /// `prefix`. If there's no such element, a new one is inserted.
+    fn set_element(&mut self, id: ManagementInfoId, prefix: &[u8], data: Vec<u8>) {
+        let existing = self.elements.iter_mut().find(|(element_id, element_data)| {
+            *element_id == id && element_data.starts_with(prefix)
+        });
+
+        match existing {
+            Some(element) => element.1 = data,
+            None => self.insert_element(id, data),
+        }
+    }
+
+    /// Parse the first element with the given id and payload prefix, that's valid.
+    /// The prefix isn't passed to the parser.
+    fn typed_element<T>(
+        &self,
+        id: ManagementInfoId,
+        prefix: &[u8],
+        parser: fn(&[u8]) -> IResult<&[u8], T>,
+    ) -> Option<T> {
+        self.elements
+            .iter()
+            .filter(|(element_id, _)| *element_id == id)
+            .filter_map(|(_, data)| data.strip_prefix(prefix))
+            .find_map(|body| match parser(body) {
+                Ok(([], value)) => Some(value),
+                _ => None,
+            })
+    }
+
+    fn typed_extension<T>(
+        &self,
+        extension_id: ExtensionId,
+        parser: fn(&[u8]) -> IResult<&[u8], T>,
+    ) -> Option<T> {
+        self.typed_element(
+            ManagementInfoId::InfoIdExtension,
+            &[extension_id.into()],
+            parser,
         )
     }
+
+    /// Serialize a typed element and store it via [StationInfo::set_element].
+    fn set_typed_element<T>(
+        &mut self,
+        id: ManagementInfoId,
+        value: &T,
+        prefix: &[u8],
+        generator: for<'a> fn(GenCursor<'a>, &T) -> GenResult<'a>,
+    ) -> Result<(), Error> {
+        self.set_element(id, prefix, element_body(value, generator)?);
+
+        Ok(())
+    }
+}
+
+/// The maximum length of the body of an element.
+/// Elements can't be longer than the largest possible frame body (11454 bytes).
+pub(crate) const MAX_ELEMENT_LENGTH: usize = 11454;
+
+/// Serialize the body of a typed element with its generator.
+pub(crate) fn element_body<T>(
+    value: &T,
+    generator: for<'a> fn(GenCursor<'a>, &T) -> GenResult<'a>,
+) -> Result<Vec<u8>, Error> {
+    let mut buffer = vec![0; MAX_ELEMENT_LENGTH];
+    let (_, length) = generator((buffer.as_mut_slice(), 0), value)?;
+    buffer.truncate(length);
+
+    Ok(buffer)
+}
+
+/// The maximum number of rates in the Supported Rates element.
+const MAX_SUPPORTED_RATES: usize = 8;
+
+/// The payload prefix of the WPA vendor element: The Microsoft OUI and type `1`.
+const WPA_PREFIX: [u8; 4] = VendorId::WPA.prefix();
+/// The payload prefix of the WMM vendor element: The Microsoft OUI and type `2`.
+const WMM_PREFIX: [u8; 4] = VendorId::WMM.prefix();
+
+/// The order in which elements appear in beacon frames.
+///
+/// Reference: IEEE 802.11-2020, Table 9-32.
+const ELEMENT_ORDER: &[u8] = &[
+    0,   // SSID
+    1,   // Supported rates
+    3,   // DS parameter set
+    4,   // CF parameter set
+    6,   // IBSS parameter set
+    5,   // TIM
+    7,   // Country
+    32,  // Power constraint
+    37,  // Channel switch announcement
+    40,  // Quiet
+    41,  // IBSS DFS
+    35,  // TPC report
+    42,  // ERP
+    50,  // Extended supported rates
+    48,  // RSN
+    11,  // BSS load
+    12,  // EDCA parameter set
+    46,  // QoS capability
+    51,  // AP channel report
+    63,  // BSS average access delay
+    64,  // Antenna
+    67,  // BSS available admission capacity
+    68,  // BSS AC access delay
+    66,  // Measurement pilot transmission
+    71,  // Multiple BSSID
+    70,  // RM enabled capabilities
+    54,  // Mobility domain
+    58,  // DSE registered location
+    60,  // Extended channel switch announcement
+    59,  // Supported operating classes
+    45,  // HT capabilities
+    61,  // HT operation
+    72,  // 20/40 BSS coexistence
+    74,  // Overlapping BSS scan parameters
+    127, // Extended capabilities
+    191, // VHT capabilities
+    192, // VHT operation
+    195, // Transmit power envelope
+    255, // Element ID extension
+    221, // Vendor specific
+];
+
+/// The order in which extension elements appear in beacon frames.
+/// They're all located at the position of the [ManagementInfoId::InfoIdExtension] in
+/// [ELEMENT_ORDER].
+///
+/// Reference: IEEE 802.11ax-2021 and IEEE 802.11be, Table 9-32.
+const EXTENSION_ORDER: &[u8] = &[
+    35,  // HE capabilities
+    36,  // HE operation
+    42,  // BSS color change announcement
+    39,  // Spatial reuse parameter set
+    37,  // UORA parameter set
+    38,  // MU EDCA parameter set
+    59,  // HE 6 GHz band capabilities
+    107, // Multi-Link
+    108, // EHT capabilities
+    106, // EHT operation
+];
+
+/// The position of an element in [ELEMENT_ORDER].
+/// Extension elements are additionally ordered by their position in [EXTENSION_ORDER].
+///
+/// The extension ID is the first byte of the payload and ignored for all other elements.
+fn element_order(id: ManagementInfoId, extension_id: Option<u8>) -> Option<(usize, usize)> {
+    let id: u8 = id.into();
+    let order = ELEMENT_ORDER.iter().position(|order_id| *order_id == id)?;
+    let extension_order = match extension_id {
+        Some(extension_id) if id == u8::from(ManagementInfoId::InfoIdExtension) => EXTENSION_ORDER
+            .iter()
+            .position(|order_id| *order_id == extension_id)?,
+        _ => 0,
+    };
+
+    Some((order, extension_order))
+}
+
+/// The ways in which access points hide their SSID in beacons and probe responses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HiddenSsid {
+    /// The SSID element is empty.
+    Empty,
+    /// The SSID is replaced by null bytes. Its length is usually the length of the real SSID.
+    NulPadded(usize),
 }
 
-#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 /// Management Frame Information Element IDs for tagged data in management frame
 /// headers.
@@ -109,6 +602,8 @@ impl TryFrom<u32> for SupportedRate {
 ///
 /// Please update this table with new info element id types which you find in
 /// the wild.
+///
+/// All element IDs that aren't in this table are preserved via [ManagementInfoId::Unknown].
 pub enum ManagementInfoId {
     SSID = 0,
     SupportedRates,
@@ -148,8 +643,20 @@ pub enum ManagementInfoId {
     RmEnabledCapability = 70,
     // 71-73 unknown
     BssOverlapParams = 74,
-    // 75-220 unknown
+    // 75-190 unknown
+    VhtCapabilities = 191,
+    VhtOperation = 192,
+    // 193-220 unknown
     VendorSpecific = 221,
-    // 222-254 unknown
+    // 222-241 unknown
+    /// Carries the remaining data of the preceding element, if it's longer than 255 bytes.
+    /// Fragments are reassembled by the parser and created by the generator, so this ID only
+    /// shows up for fragments without a preceding element.
+    Fragment = 242,
+    // 243-254 unknown
     InfoIdExtension = 255,
+    /// Any element ID that isn't known to this library.
+    /// The discriminant is only required by the compiler and has no meaning.
+    #[num_enum(catch_all)]
+    Unknown(u8) = 254,
 }
@@ -0,0 +1,125 @@
+use num_enum::{FromPrimitive, IntoPrimitive};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, IntoPrimitive)]
+#[repr(u16)]
+/// The status code used in response frames to indicate success or failure of a request.
+///
+/// Reference: IEEE 802.11-2020, Table 9-50.
+///
+/// Codes that aren't explicitly listed are preserved via [StatusCode::Unknown].
+pub enum StatusCode {
+    Success = 0,
+    UnspecifiedFailure = 1,
+    TdlsWakeupAlternate = 2,
+    TdlsWakeupReject = 3,
+    SecurityDisabled = 5,
+    UnacceptableLifetime = 6,
+    NotInSameBss = 7,
+    RefusedCapabilitiesMismatch = 10,
+    DeniedNoAssociationExists = 11,
+    DeniedOtherReason = 12,
+    UnsupportedAuthAlgorithm = 13,
+    TransactionSequenceError = 14,
+    ChallengeFailure = 15,
+    RejectedSequenceTimeout = 16,
+    DeniedNoMoreStas = 17,
+    RefusedBasicRatesMismatch = 18,
+    DeniedNoShortPreambleSupport = 19,
+    RejectedSpectrumManagementRequired = 22,
+    RejectedBadPowerCapability = 23,
+    RejectedBadSupportedChannels = 24,
+    DeniedNoShortSlotTimeSupport = 25,
+    DeniedNoHtSupport = 27,
+    R0khUnreachable = 28,
+    DeniedPcoTimeNotSupported = 29,
+    RefusedTemporarily = 30,
+    RobustManagementPolicyViolation = 31,
+    UnspecifiedQosFailure = 32,
+    DeniedInsufficientBandwidth = 33,
+    DeniedPoorChannelConditions = 34,
+    DeniedQosNotSupported = 35,
+    RequestDeclined = 37,
+    InvalidParameters = 38,
+    RejectedWithSuggestedChanges = 39,
+    StatusInvalidElement = 40,
+    StatusInvalidGroupCipher = 41,
+    StatusInvalidPairwiseCipher = 42,
+    StatusInvalidAkmp = 43,
+    UnsupportedRsneVersion = 44,
+    InvalidRsneCapabilities = 45,
+    StatusCipherOutOfPolicy = 46,
+    RejectedForDelayPeriod = 47,
+    DlsNotAllowed = 48,
+    NotPresent = 49,
+    NotQosSta = 50,
+    DeniedListenIntervalTooLarge = 51,
+    StatusInvalidFtActionFrameCount = 52,
+    StatusInvalidPmkid = 53,
+    StatusInvalidMde = 54,
+    StatusInvalidFte = 55,
+    RequestedTclasNotSupported = 56,
+    InsufficientTclasProcessingResources = 57,
+    TryAnotherBss = 58,
+    GasAdvertisementProtocolNotSupported = 59,
+    NoOutstandingGasRequest = 60,
+    GasResponseNotReceivedFromServer = 61,
+    GasQueryTimeout = 62,
+    GasQueryResponseTooLarge = 63,
+    RejectedHomeWithSuggestedChanges = 64,
+    ServerUnreachable = 65,
+    RejectedForSspPermissions = 67,
+    RefusedUnauthenticatedAccessNotSupported = 68,
+    InvalidRsne = 72,
+    UApsdCoexistanceNotSupported = 73,
+    UApsdCoexModeNotSupported = 74,
+    BadIntervalWithUApsdCoex = 75,
+    AntiCloggingTokenRequired = 76,
+    UnsupportedFiniteCyclicGroup = 77,
+    CannotFindAlternativeTbtt = 78,
+    TransmissionFailure = 79,
+    TclasProcessingNotSupported = 80,
+    TclasResourcesExhausted = 81,
+    RejectedWithSuggestedBssTransition = 82,
+    RejectWithSchedule = 83,
+    RejectNoWakeupSpecified = 84,
+    SuccessPowerSaveMode = 85,
+    PendingAdmittingFstSession = 86,
+    PerformingFstNow = 87,
+    PendingGapInBaWindow = 88,
+    RejectUPidSetting = 89,
+    RefusedExternalReason = 92,
+    RefusedApOutOfMemory = 93,
+    RejectedEmergencyServicesNotSupported = 94,
+    QueryResponseOutstanding = 95,
+    RejectDseBand = 96,
+    TclasProcessingTerminated = 97,
+    TsScheduleConflict = 98,
+    DeniedWithSuggestedBandAndChannel = 99,
+    MccaopReservationConflict = 100,
+    MafLimitExceeded = 101,
+    MccaTrackLimitExceeded = 102,
+    DeniedDueToSpectrumManagement = 103,
+    DeniedVhtNotSupported = 104,
+    EnablementDenied = 105,
+    RestrictionFromAuthorizedGdb = 106,
+    AuthorizationDeenabled = 107,
+    EnergyLimitedOperationNotSupported = 108,
+    RejectedNdpBlockAckSuggested = 109,
+    RejectedMaxAwayDurationUnacceptable = 110,
+    FlowControlOperationSupported = 111,
+    FilsAuthenticationFailure = 112,
+    UnknownAuthenticationServer = 113,
+    UnknownPasswordIdentifier = 123,
+    DeniedHeNotSupported = 124,
+    SaeHashToElement = 126,
+    SaePk = 127,
+    #[num_enum(catch_all)]
+    Unknown(u16),
+}
+
+impl StatusCode {
+    /// Whether the status code indicates a successful request.
+    pub fn is_success(&self) -> bool {
+        matches!(self, StatusCode::Success | StatusCode::SuccessPowerSaveMode)
+    }
+}
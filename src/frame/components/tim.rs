@@ -0,0 +1,113 @@
+/// The highest association ID (AID) that can be represented in the [Tim] element.
+const MAX_AID: u16 = 2007;
+
+/// The Traffic Indication Map (TIM) element, which is sent by access points in beacons to
+/// inform stations in power save mode about buffered frames.
+///
+/// Structure:
+/// - 1 byte: DTIM count
+/// - 1 byte: DTIM period
+/// - 1 byte: Bitmap control (1 bit group addressed traffic, 7 bits bitmap offset)
+/// - 1-251 bytes: Partial virtual bitmap
+///
+/// The full virtual bitmap has one bit per AID (0-2007). Only the octets between the bitmap
+/// offset and the last octet with a set bit are sent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tim {
+    /// The number of beacons until the next DTIM beacon. `0` means that this is a DTIM.
+    pub dtim_count: u8,
+    /// The number of beacon intervals between two DTIMs.
+    pub dtim_period: u8,
+    /// Group addressed frames are buffered and will be sent after this DTIM.
+    pub group_addressed_traffic: bool,
+    /// The offset of the partial virtual bitmap in the full bitmap in units of 2 octets.
+    pub bitmap_offset: u8,
+    pub partial_virtual_bitmap: Vec<u8>,
+}
+
+impl Default for Tim {
+    fn default() -> Self {
+        Tim {
+            dtim_count: 0,
+            dtim_period: 1,
+            group_addressed_traffic: false,
+            bitmap_offset: 0,
+            partial_virtual_bitmap: vec![0],
+        }
+    }
+}
+
+impl Tim {
+    /// Create a TIM with the smallest partial virtual bitmap that contains all given AIDs.
+    ///
+    /// AIDs above 2007 are ignored.
+    pub fn new(
+        dtim_count: u8,
+        dtim_period: u8,
+        group_addressed_traffic: bool,
+        buffered_aids: &[u16],
+    ) -> Self {
+        let aids: Vec<u16> = buffered_aids
+            .iter()
+            .copied()
+            .filter(|aid| *aid <= MAX_AID)
+            .collect();
+        let mut tim = Tim {
+            dtim_count,
+            dtim_period,
+            group_addressed_traffic,
+            ..Default::default()
+        };
+
+        let (Some(first), Some(last)) = (aids.iter().min(), aids.iter().max()) else {
+            return tim;
+        };
+        // The offset has to be even, as it's sent in units of 2 octets.
+        let first_octet = (first / 8) & !1;
+        let last_octet = last / 8;
+
+        tim.bitmap_offset = (first_octet / 2) as u8;
+        tim.partial_virtual_bitmap = vec![0; usize::from(last_octet - first_octet + 1)];
+        for aid in aids {
+            tim.partial_virtual_bitmap[usize::from(aid / 8 - first_octet)] |= 1 << (aid % 8);
+        }
+
+        tim
+    }
+
+    /// This is a DTIM beacon, after which buffered group addressed frames are sent.
+    pub fn is_dtim(&self) -> bool {
+        self.dtim_count == 0
+    }
+
+    /// Check whether frames for the station with the given association ID (AID) are buffered.
+    pub fn is_aid_buffered(&self, aid: u16) -> bool {
+        let Some(octet) = usize::from(aid / 8).checked_sub(self.first_octet()) else {
+            return false;
+        };
+
+        self.partial_virtual_bitmap
+            .get(octet)
+            .is_some_and(|bits| bits & (1 << (aid % 8)) > 0)
+    }
+
+    /// The association IDs (AIDs) of all stations for which frames are buffered.
+    pub fn buffered_aids(&self) -> Vec<u16> {
+        let first_octet = self.first_octet();
+
+        self.partial_virtual_bitmap
+            .iter()
+            .enumerate()
+            .flat_map(|(index, bits)| {
+                (0..8)
+                    .filter(move |bit| bits & (1 << bit) > 0)
+                    .map(move |bit| ((first_octet + index) * 8 + bit) as u16)
+            })
+            .collect()
+    }
+
+    /// The index of the first octet of the partial virtual bitmap in the full bitmap.
+    fn first_octet(&self) -> usize {
+        usize::from(self.bitmap_offset) * 2
+    }
+}
@@ -0,0 +1,443 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use nom::combinator::all_consuming;
+use nom::IResult;
+
+use super::{element_body, MacAddress, Wmm, WpaInformation, MICROSOFT_OUI};
+use crate::error::Error;
+use crate::generators::*;
+use crate::parsers::*;
+
+/// The organization identifier of the Wi-Fi Alliance, which is used for the P2P and
+/// OWE Transition Mode vendor elements.
+pub const WIFI_ALLIANCE_OUI: [u8; 3] = [0x50, 0x6f, 0x9a];
+
+/// The organization identifier of Broadcom.
+pub const BROADCOM_OUI: [u8; 3] = [0x00, 0x10, 0x18];
+
+/// The identifier of a vendor specific element.
+///
+/// The body of a vendor specific element starts with the organization identifier (OUI) of the
+/// vendor, followed by a vendor defined type. Together they decide about the format of the rest
+/// of the element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VendorId {
+    pub oui: [u8; 3],
+    pub vendor_type: u8,
+}
+
+impl VendorId {
+    /// The legacy WPA element, see [WpaInformation].
+    pub const WPA: VendorId = VendorId::new(MICROSOFT_OUI, 1);
+    /// The WMM Information and Parameter elements, see [Wmm].
+    pub const WMM: VendorId = VendorId::new(MICROSOFT_OUI, 2);
+    /// The Wi-Fi Protected Setup element, see [Wps].
+    pub const WPS: VendorId = VendorId::new(MICROSOFT_OUI, 4);
+    /// The Wi-Fi Direct element, see [P2p].
+    pub const P2P: VendorId = VendorId::new(WIFI_ALLIANCE_OUI, 9);
+    /// The OWE Transition Mode element, see [OweTransitionMode].
+    pub const OWE_TRANSITION_MODE: VendorId = VendorId::new(WIFI_ALLIANCE_OUI, 28);
+    /// The Broadcom element that's sent by most Broadcom chipsets, see [BroadcomElement].
+    pub const BROADCOM: VendorId = VendorId::new(BROADCOM_OUI, 2);
+
+    pub const fn new(oui: [u8; 3], vendor_type: u8) -> Self {
+        VendorId { oui, vendor_type }
+    }
+
+    /// The first four bytes of the element body.
+    pub const fn prefix(&self) -> [u8; 4] {
+        [self.oui[0], self.oui[1], self.oui[2], self.vendor_type]
+    }
+}
+
+/// A decoded vendor specific element.
+///
+/// The built-in variants are decoded by [VendorRegistry::default].
+/// Elements of decoders that are registered by the application end up in
+/// [VendorElement::Custom].
+#[derive(Clone, Debug)]
+pub enum VendorElement {
+    Wpa(WpaInformation),
+    Wmm(Wmm),
+    Wps(Wps),
+    P2p(P2p),
+    OweTransitionMode(OweTransitionMode),
+    Broadcom(BroadcomElement),
+    /// An element of an application specific decoder.
+    /// Use [VendorElement::downcast_ref] to get the decoded value.
+    Custom {
+        vendor_id: VendorId,
+        value: Arc<dyn Any + Send + Sync>,
+    },
+}
+
+impl VendorElement {
+    /// Wrap the value of an application specific decoder.
+    pub fn custom<T: Any + Send + Sync>(vendor_id: VendorId, value: T) -> Self {
+        VendorElement::Custom {
+            vendor_id,
+            value: Arc::new(value),
+        }
+    }
+
+    /// The OUI and vendor type of this element.
+    pub fn vendor_id(&self) -> VendorId {
+        match self {
+            VendorElement::Wpa(_) => VendorId::WPA,
+            VendorElement::Wmm(_) => VendorId::WMM,
+            VendorElement::Wps(_) => VendorId::WPS,
+            VendorElement::P2p(_) => VendorId::P2P,
+            VendorElement::OweTransitionMode(_) => VendorId::OWE_TRANSITION_MODE,
+            VendorElement::Broadcom(_) => VendorId::BROADCOM,
+            VendorElement::Custom { vendor_id, .. } => *vendor_id,
+        }
+    }
+
+    /// Get the value of a [VendorElement::Custom] element, if it has the given type.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        match self {
+            VendorElement::Custom { value, .. } => value.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes the body of a vendor specific element, which starts right after the OUI and
+/// vendor type.
+pub type VendorDecoder = Box<dyn Fn(&[u8]) -> Result<VendorElement, Error> + Send + Sync>;
+
+/// Encodes a vendor specific element into its body, without the OUI and vendor type.
+pub type VendorEncoder = Box<dyn Fn(&VendorElement) -> Result<Vec<u8>, Error> + Send + Sync>;
+
+/// A collection of decoders and encoders for vendor specific elements, keyed by their
+/// [VendorId].
+///
+/// [VendorRegistry::default] contains the built-in decoders and encoders for the Microsoft
+/// (WPA, WMM, WPS), Wi-Fi Alliance (P2P, OWE Transition Mode) and Broadcom elements.
+/// Applications can register their own ones for proprietary elements, which replace
+/// built-in ones with the same [VendorId].
+///
+/// ```
+/// use libwifi::frame::components::{StationInfo, VendorElement, VendorId, VendorRegistry};
+///
+/// struct Beacon {
+///     interval: u8,
+/// }
+///
+/// let vendor_id = VendorId::new([0x02, 0x00, 0x00], 1);
+/// let mut registry = VendorRegistry::default();
+/// registry.register(
+///     vendor_id,
+///     move |body| match body {
+///         [interval] => Ok(VendorElement::custom(vendor_id, Beacon { interval: *interval })),
+///         _ => Err(libwifi::error::Error::Incomplete("One byte expected".to_string())),
+///     },
+///     |element| match element.downcast_ref::<Beacon>() {
+///         Some(beacon) => Ok(vec![beacon.interval]),
+///         None => Err(libwifi::error::Error::SerializeFailure("Wrong type".to_string())),
+///     },
+/// );
+///
+/// let mut station_info = StationInfo::default();
+/// station_info
+///     .set_vendor_element(&registry, &VendorElement::custom(vendor_id, Beacon { interval: 5 }))
+///     .unwrap();
+///
+/// let elements: Vec<_> = station_info.vendor_elements(&registry).collect();
+/// assert_eq!(elements[0].downcast_ref::<Beacon>().unwrap().interval, 5);
+/// ```
+pub struct VendorRegistry {
+    decoders: HashMap<VendorId, VendorDecoder>,
+    encoders: HashMap<VendorId, VendorEncoder>,
+}
+
+impl VendorRegistry {
+    /// A registry without any decoders or encoders, not even the built-in ones.
+    pub fn empty() -> Self {
+        VendorRegistry {
+            decoders: HashMap::new(),
+            encoders: HashMap::new(),
+        }
+    }
+
+    /// Register a decoder and an encoder for the given [VendorId].
+    pub fn register(
+        &mut self,
+        vendor_id: VendorId,
+        decoder: impl Fn(&[u8]) -> Result<VendorElement, Error> + Send + Sync + 'static,
+        encoder: impl Fn(&VendorElement) -> Result<Vec<u8>, Error> + Send + Sync + 'static,
+    ) {
+        self.register_decoder(vendor_id, decoder);
+        self.register_encoder(vendor_id, encoder);
+    }
+
+    /// Register a decoder for the given [VendorId].
+    pub fn register_decoder(
+        &mut self,
+        vendor_id: VendorId,
+        decoder: impl Fn(&[u8]) -> Result<VendorElement, Error> + Send + Sync + 'static,
+    ) {
+        self.decoders.insert(vendor_id, Box::new(decoder));
+    }
+
+    /// Register an encoder for the given [VendorId].
+    pub fn register_encoder(
+        &mut self,
+        vendor_id: VendorId,
+        encoder: impl Fn(&VendorElement) -> Result<Vec<u8>, Error> + Send + Sync + 'static,
+    ) {
+        self.encoders.insert(vendor_id, Box::new(encoder));
+    }
+
+    /// Decode the payload of a vendor specific element, which starts with the OUI.
+    ///
+    /// Returns `None`, if there's no decoder for the element.
+    pub fn decode(&self, payload: &[u8]) -> Option<Result<VendorElement, Error>> {
+        let [oui_0, oui_1, oui_2, vendor_type, body @ ..] = payload else {
+            return None;
+        };
+        let decoder = self
+            .decoders
+            .get(&VendorId::new([*oui_0, *oui_1, *oui_2], *vendor_type))?;
+
+        Some(decoder(body))
+    }
+
+    /// Encode an element into the payload of a vendor specific element, including the OUI.
+    pub fn encode(&self, element: &VendorElement) -> Result<Vec<u8>, Error> {
+        let vendor_id = element.vendor_id();
+        let Some(encoder) = self.encoders.get(&vendor_id) else {
+            return Err(Error::SerializeFailure(format!(
+                "There's no encoder for the vendor element {:?}",
+                vendor_id
+            )));
+        };
+
+        let mut payload = vendor_id.prefix().to_vec();
+        payload.extend(encoder(element)?);
+
+        Ok(payload)
+    }
+}
+
+impl Default for VendorRegistry {
+    /// A registry with the built-in decoders and encoders.
+    fn default() -> Self {
+        let mut registry = VendorRegistry::empty();
+        registry.register(
+            VendorId::WPA,
+            |body| decode(body, parse_wpa_information).map(VendorElement::Wpa),
+            |element| match element {
+                VendorElement::Wpa(wpa) => encode(VendorId::WPA, wpa, gen_wpa_information),
+                _ => Err(mismatch(element)),
+            },
+        );
+        registry.register(
+            VendorId::WMM,
+            |body| decode(body, parse_wmm).map(VendorElement::Wmm),
+            |element| match element {
+                VendorElement::Wmm(wmm) => encode(VendorId::WMM, wmm, gen_wmm),
+                _ => Err(mismatch(element)),
+            },
+        );
+        registry.register(
+            VendorId::WPS,
+            |body| decode(body, parse_wps).map(VendorElement::Wps),
+            |element| match element {
+                VendorElement::Wps(wps) => encode(VendorId::WPS, wps, gen_wps),
+                _ => Err(mismatch(element)),
+            },
+        );
+        registry.register(
+            VendorId::P2P,
+            |body| decode(body, parse_p2p).map(VendorElement::P2p),
+            |element| match element {
+                VendorElement::P2p(p2p) => encode(VendorId::P2P, p2p, gen_p2p),
+                _ => Err(mismatch(element)),
+            },
+        );
+        registry.register(
+            VendorId::OWE_TRANSITION_MODE,
+            |body| decode(body, parse_owe_transition_mode).map(VendorElement::OweTransitionMode),
+            |element| match element {
+                VendorElement::OweTransitionMode(owe) => {
+                    encode(VendorId::OWE_TRANSITION_MODE, owe, gen_owe_transition_mode)
+                }
+                _ => Err(mismatch(element)),
+            },
+        );
+        registry.register(
+            VendorId::BROADCOM,
+            |body| {
+                Ok(VendorElement::Broadcom(BroadcomElement {
+                    data: body.to_vec(),
+                }))
+            },
+            |element| match element {
+                VendorElement::Broadcom(broadcom) => Ok(broadcom.data.clone()),
+                _ => Err(mismatch(element)),
+            },
+        );
+
+        registry
+    }
+}
+
+impl fmt::Debug for VendorRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VendorRegistry")
+            .field("decoders", &self.decoders.keys().collect::<Vec<_>>())
+            .field("encoders", &self.encoders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Decode an element body with a parser, which has to consume all of it.
+fn decode<T>(body: &[u8], parser: fn(&[u8]) -> IResult<&[u8], T>) -> Result<T, Error> {
+    let (_, value) = all_consuming(parser)(body)?;
+
+    Ok(value)
+}
+
+/// Encode an element with a generator, which writes the element body.
+/// The OUI and vendor type are stripped again, [VendorRegistry::encode] adds them.
+fn encode<T>(
+    vendor_id: VendorId,
+    value: &T,
+    generator: for<'a> fn(GenCursor<'a>, &T) -> GenResult<'a>,
+) -> Result<Vec<u8>, Error> {
+    let body = element_body(value, generator)?;
+
+    Ok(body[vendor_id.prefix().len()..].to_vec())
+}
+
+fn mismatch(element: &VendorElement) -> Error {
+    Error::SerializeFailure(format!(
+        "The encoder can't handle the vendor element {:?}",
+        element.vendor_id()
+    ))
+}
+
+/// A single attribute of the [Wps] element.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WpsAttribute {
+    pub attribute_type: u16,
+    pub data: Vec<u8>,
+}
+
+/// The Wi-Fi Protected Setup (WPS) element.
+///
+/// It's a vendor specific element with the [MICROSOFT_OUI] and vendor type `4`.
+/// The body is a list of attributes:
+/// - 2 bytes: Attribute type (big endian)
+/// - 2 bytes: Attribute length (big endian)
+/// - $length bytes: Attribute data
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Wps {
+    pub attributes: Vec<WpsAttribute>,
+}
+
+impl Wps {
+    /// The data of the first attribute with the given type.
+    pub fn attribute(&self, attribute_type: u16) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.attribute_type == attribute_type)
+            .map(|attribute| attribute.data.as_slice())
+    }
+
+    /// `1` if WPS isn't configured, `2` if it's configured.
+    pub fn wps_state(&self) -> Option<u8> {
+        match self.attribute(0x1044)? {
+            [state] => Some(*state),
+            _ => None,
+        }
+    }
+
+    pub fn device_name(&self) -> Option<String> {
+        self.string_attribute(0x1011)
+    }
+
+    pub fn manufacturer(&self) -> Option<String> {
+        self.string_attribute(0x1021)
+    }
+
+    pub fn model_name(&self) -> Option<String> {
+        self.string_attribute(0x1023)
+    }
+
+    pub fn model_number(&self) -> Option<String> {
+        self.string_attribute(0x1024)
+    }
+
+    fn string_attribute(&self, attribute_type: u16) -> Option<String> {
+        self.attribute(attribute_type)
+            .map(|data| String::from_utf8_lossy(data).to_string())
+    }
+}
+
+/// A single attribute of the [P2p] element.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct P2pAttribute {
+    pub attribute_id: u8,
+    pub data: Vec<u8>,
+}
+
+/// The Wi-Fi Direct (P2P) element.
+///
+/// It's a vendor specific element with the [WIFI_ALLIANCE_OUI] and vendor type `9`.
+/// The body is a list of attributes:
+/// - 1 byte: Attribute ID
+/// - 2 bytes: Attribute length (little endian)
+/// - $length bytes: Attribute data
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct P2p {
+    pub attributes: Vec<P2pAttribute>,
+}
+
+impl P2p {
+    /// The data of the first attribute with the given ID.
+    pub fn attribute(&self, attribute_id: u8) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.attribute_id == attribute_id)
+            .map(|attribute| attribute.data.as_slice())
+    }
+
+    /// The device capability bitmap and the group capability bitmap.
+    pub fn capability(&self) -> Option<(u8, u8)> {
+        match self.attribute(2)? {
+            [device, group] => Some((*device, *group)),
+            _ => None,
+        }
+    }
+}
+
+/// The OWE Transition Mode element, which links an open network to an OWE network with
+/// another SSID.
+///
+/// It's a vendor specific element with the [WIFI_ALLIANCE_OUI] and vendor type `28`.
+///
+/// Structure:
+/// - 6 bytes: BSSID of the other network
+/// - 1 byte: SSID length + SSID of the other network
+/// - 1 byte: Operating class (optional)
+/// - 1 byte: Channel (optional)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OweTransitionMode {
+    pub bssid: MacAddress,
+    pub ssid: Vec<u8>,
+    /// The operating class and channel of the other network, if it's on another channel.
+    pub operating_class_and_channel: Option<(u8, u8)>,
+}
+
+/// The Broadcom vendor element with vendor type `2`, which is sent by most Broadcom chipsets.
+///
+/// Its format isn't publicly documented, so only the data after the vendor type is exposed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BroadcomElement {
+    pub data: Vec<u8>,
+}
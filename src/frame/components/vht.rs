@@ -0,0 +1,215 @@
+use bitflags::bitflags;
+use num_enum::{FromPrimitive, IntoPrimitive};
+
+bitflags! {
+    /// The VHT capabilities information field of the [VhtCapabilities] element.
+    ///
+    /// Multi-bit fields are represented by their mask.
+    /// Use the accessor methods to get their values.
+    pub struct VhtCapabilityInfo: u32 {
+        const MAX_MPDU_LENGTH                   = 0x0000_0003;
+        const SUPPORTED_CHANNEL_WIDTH_SET       = 0x0000_000c;
+        const RX_LDPC                           = 0x0000_0010;
+        const SHORT_GI_80MHZ                    = 0x0000_0020;
+        const SHORT_GI_160MHZ                   = 0x0000_0040;
+        const TX_STBC                           = 0x0000_0080;
+        const RX_STBC                           = 0x0000_0700;
+        const SU_BEAMFORMER                     = 0x0000_0800;
+        const SU_BEAMFORMEE                     = 0x0000_1000;
+        const BEAMFORMEE_STS                    = 0x0000_e000;
+        const SOUNDING_DIMENSIONS               = 0x0007_0000;
+        const MU_BEAMFORMER                     = 0x0008_0000;
+        const MU_BEAMFORMEE                     = 0x0010_0000;
+        const TXOP_PS                           = 0x0020_0000;
+        const HTC_VHT                           = 0x0040_0000;
+        const MAX_AMPDU_LENGTH_EXPONENT         = 0x0380_0000;
+        const LINK_ADAPTATION                   = 0x0c00_0000;
+        const RX_ANTENNA_PATTERN_CONSISTENCY    = 0x1000_0000;
+        const TX_ANTENNA_PATTERN_CONSISTENCY    = 0x2000_0000;
+        const EXTENDED_NSS_BW_SUPPORT           = 0xc000_0000;
+    }
+}
+
+impl VhtCapabilityInfo {
+    /// The maximum MPDU length in octets.
+    pub fn max_mpdu_length(&self) -> u16 {
+        match self.bits() & Self::MAX_MPDU_LENGTH.bits() {
+            0 => 3895,
+            1 => 7991,
+            _ => 11454,
+        }
+    }
+
+    /// The supported channel width set.
+    /// - `0`: Neither 160 MHz nor 80+80 MHz
+    /// - `1`: 160 MHz
+    /// - `2`: 160 MHz and 80+80 MHz
+    pub fn supported_channel_width_set(&self) -> u8 {
+        ((self.bits() & Self::SUPPORTED_CHANNEL_WIDTH_SET.bits()) >> 2) as u8
+    }
+
+    /// The extended NSS bandwidth support, which is used in combination with the supported
+    /// channel width set.
+    pub fn extended_nss_bw_support(&self) -> u8 {
+        ((self.bits() & Self::EXTENDED_NSS_BW_SUPPORT.bits()) >> 30) as u8
+    }
+
+    /// Whether 160 MHz operation is supported in any form.
+    pub fn supports_160mhz(&self) -> bool {
+        self.supported_channel_width_set() > 0 || self.extended_nss_bw_support() > 0
+    }
+
+    /// The number of spatial streams that can be received with STBC (0-4).
+    pub fn rx_stbc_streams(&self) -> u8 {
+        ((self.bits() & Self::RX_STBC.bits()) >> 8) as u8
+    }
+
+    /// The maximum number of space-time streams **- 1** that can be received as beamformee.
+    pub fn beamformee_sts(&self) -> u8 {
+        ((self.bits() & Self::BEAMFORMEE_STS.bits()) >> 13) as u8
+    }
+
+    /// The number of sounding dimensions **- 1** that are used as beamformer.
+    pub fn sounding_dimensions(&self) -> u8 {
+        ((self.bits() & Self::SOUNDING_DIMENSIONS.bits()) >> 16) as u8
+    }
+
+    /// The maximum A-MPDU length in octets (`2^(13 + exponent) - 1`).
+    pub fn max_ampdu_length(&self) -> u32 {
+        let exponent = (self.bits() & Self::MAX_AMPDU_LENGTH_EXPONENT.bits()) >> 23;
+        (1 << (13 + exponent)) - 1
+    }
+}
+
+/// A VHT-MCS map, which contains the maximum supported MCS for 1 to 8 spatial streams.
+///
+/// Each spatial stream uses two bits:
+/// - `0`: MCS 0-7
+/// - `1`: MCS 0-8
+/// - `2`: MCS 0-9
+/// - `3`: Not supported
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct VhtMcsMap(pub u16);
+
+impl VhtMcsMap {
+    /// The highest supported MCS index for the given number of spatial streams (1-8).
+    /// Returns `None`, if the number of spatial streams isn't supported.
+    pub fn max_mcs(&self, spatial_streams: u8) -> Option<u8> {
+        if !(1..=8).contains(&spatial_streams) {
+            return None;
+        }
+
+        match (self.0 >> ((spatial_streams - 1) * 2)) & 0b11 {
+            0 => Some(7),
+            1 => Some(8),
+            2 => Some(9),
+            _ => None,
+        }
+    }
+
+    /// The highest number of spatial streams that's supported.
+    pub fn max_spatial_streams(&self) -> u8 {
+        (1..=8)
+            .rev()
+            .find(|streams| self.max_mcs(*streams).is_some())
+            .unwrap_or(0)
+    }
+}
+
+/// The supported VHT-MCS and NSS set of the [VhtCapabilities] element.
+///
+/// Structure:
+/// - 2 bytes: Rx VHT-MCS map
+/// - 2 bytes: Rx highest supported long GI data rate (bits 0-12) and max NSTS total (bits 13-15)
+/// - 2 bytes: Tx VHT-MCS map
+/// - 2 bytes: Tx highest supported long GI data rate (bits 0-12) and VHT extended NSS BW
+///   capable (bit 13)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SupportedVhtMcsSet {
+    pub rx_mcs_map: VhtMcsMap,
+    /// In Mb/s. `0` means that the rate is derived from the MCS map.
+    pub rx_highest_data_rate: u16,
+    pub max_nsts_total: u8,
+    pub tx_mcs_map: VhtMcsMap,
+    /// In Mb/s. `0` means that the rate is derived from the MCS map.
+    pub tx_highest_data_rate: u16,
+    pub extended_nss_bw_capable: bool,
+}
+
+/// The VHT Capabilities element, which announces the 802.11ac capabilities of a station.
+///
+/// Structure:
+/// - 4 bytes: VHT capabilities information
+/// - 8 bytes: Supported VHT-MCS and NSS set
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VhtCapabilities {
+    pub vht_capability_info: VhtCapabilityInfo,
+    pub supported_mcs_set: SupportedVhtMcsSet,
+}
+
+/// The channel width field of the [VhtOperation] element.
+///
+/// Reserved values are preserved via [VhtChannelWidth::Reserved].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum VhtChannelWidth {
+    /// 20 MHz or 40 MHz, depending on the HT Operation element.
+    TwentyOrFortyMhz = 0,
+    /// 80 MHz, 160 MHz or 80+80 MHz, depending on the center frequency segments.
+    EightyMhzOrMore = 1,
+    /// Deprecated way to signal 160 MHz.
+    OneSixtyMhz = 2,
+    /// Deprecated way to signal 80+80 MHz.
+    EightyPlusEightyMhz = 3,
+    #[num_enum(catch_all)]
+    Reserved(u8),
+}
+
+/// The VHT Operation element, which is sent by access points to describe the operation of
+/// an 802.11ac BSS.
+///
+/// Structure:
+/// - 1 byte: Channel width
+/// - 1 byte: Channel center frequency segment 0
+/// - 1 byte: Channel center frequency segment 1
+/// - 2 bytes: Basic VHT-MCS and NSS set
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VhtOperation {
+    pub channel_width: VhtChannelWidth,
+    pub channel_center_frequency_segment_0: u8,
+    pub channel_center_frequency_segment_1: u8,
+    pub basic_mcs_set: VhtMcsMap,
+}
+
+impl VhtOperation {
+    /// Whether the BSS operates on a contiguous 160 MHz channel.
+    ///
+    /// Reference: IEEE 802.11-2020, Table 9-274.
+    pub fn is_160mhz(&self) -> bool {
+        match self.channel_width {
+            VhtChannelWidth::OneSixtyMhz => true,
+            VhtChannelWidth::EightyMhzOrMore => {
+                self.channel_center_frequency_segment_1 > 0 && self.segment_distance() == 8
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the BSS operates on two non-contiguous 80 MHz channels.
+    ///
+    /// Reference: IEEE 802.11-2020, Table 9-274.
+    pub fn is_80_plus_80mhz(&self) -> bool {
+        match self.channel_width {
+            VhtChannelWidth::EightyPlusEightyMhz => true,
+            VhtChannelWidth::EightyMhzOrMore => {
+                self.channel_center_frequency_segment_1 > 0 && self.segment_distance() > 16
+            }
+            _ => false,
+        }
+    }
+
+    fn segment_distance(&self) -> u8 {
+        self.channel_center_frequency_segment_1
+            .abs_diff(self.channel_center_frequency_segment_0)
+    }
+}
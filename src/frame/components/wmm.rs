@@ -0,0 +1,127 @@
+/// The Wi-Fi Multimedia (WMM/WME) vendor element, which is the pre-standard version of 802.11e QoS.
+///
+/// It's a vendor specific element with the [MICROSOFT_OUI](super::MICROSOFT_OUI) and vendor
+/// type `2`.
+/// The first byte after the vendor type is the subtype, which decides about the format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Wmm {
+    /// Subtype `0`, usually sent by stations.
+    Information(WmmInformation),
+    /// Subtype `1`, usually sent by access points.
+    Parameter(WmmParameter),
+}
+
+/// The WMM Information element.
+///
+/// Structure:
+/// - 1 byte: Version (always `1`)
+/// - 1 byte: QoS info
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WmmInformation {
+    pub version: u8,
+    /// The QoS info field.
+    /// For access points, it contains the parameter set count and the U-APSD flag.
+    /// For stations, it contains the per-AC U-APSD flags and the max service period length.
+    pub qos_info: u8,
+}
+
+/// The WMM Parameter element.
+///
+/// Structure:
+/// - 1 byte: Version (always `1`)
+/// - 1 byte: QoS info
+/// - 1 byte: Reserved
+/// - 16 bytes: AC parameter records for Best Effort, Background, Video and Voice
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WmmParameter {
+    pub version: u8,
+    /// See [WmmInformation::qos_info].
+    pub qos_info: u8,
+    pub reserved: u8,
+    /// The parameters of each access category, in the order Best Effort, Background, Video and
+    /// Voice.
+    pub ac_parameters: [WmmAcParameter; 4],
+}
+
+impl WmmParameter {
+    /// Get the parameters of a specific access category.
+    pub fn ac_parameter(&self, category: AccessCategory) -> Option<&WmmAcParameter> {
+        self.ac_parameters
+            .iter()
+            .find(|parameter| parameter.access_category == category)
+    }
+}
+
+/// The four WMM access categories.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AccessCategory {
+    BestEffort,
+    Background,
+    Video,
+    Voice,
+}
+
+impl AccessCategory {
+    /// Create an access category from the 2 bit access category index (ACI).
+    pub fn from_index(index: u8) -> Self {
+        match index & 0b11 {
+            0 => AccessCategory::BestEffort,
+            1 => AccessCategory::Background,
+            2 => AccessCategory::Video,
+            _ => AccessCategory::Voice,
+        }
+    }
+
+    /// The 2 bit access category index (ACI).
+    pub fn index(&self) -> u8 {
+        match self {
+            AccessCategory::BestEffort => 0,
+            AccessCategory::Background => 1,
+            AccessCategory::Video => 2,
+            AccessCategory::Voice => 3,
+        }
+    }
+}
+
+/// A single AC parameter record of the [WmmParameter] element.
+///
+/// Structure:
+/// - 1 byte: ACI/AIFSN
+///   - **bit_0-3**: AIFSN
+///   - **bit_4**: Admission control mandatory
+///   - **bit_5-6**: ACI
+///   - **bit_7**: Reserved
+/// - 1 byte: ECWmin (lower 4 bits) and ECWmax (upper 4 bits)
+/// - 2 bytes: TXOP limit in units of 32 microseconds
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WmmAcParameter {
+    pub access_category: AccessCategory,
+    pub admission_control_mandatory: bool,
+    /// Arbitration inter-frame space number.
+    pub aifsn: u8,
+    /// The exponent of the minimum contention window (0-15).
+    /// Only the lower 4 bits are used.
+    pub ecw_min: u8,
+    /// The exponent of the maximum contention window (0-15).
+    /// Only the lower 4 bits are used.
+    pub ecw_max: u8,
+    /// The TXOP limit in units of 32 microseconds. `0` means a single MSDU per TXOP.
+    pub txop_limit: u16,
+}
+
+impl WmmAcParameter {
+    /// The minimum contention window in slots (`2^ECWmin - 1`).
+    pub fn cw_min(&self) -> u16 {
+        (1u16 << (self.ecw_min & 0x0f)) - 1
+    }
+
+    /// The maximum contention window in slots (`2^ECWmax - 1`).
+    pub fn cw_max(&self) -> u16 {
+        (1u16 << (self.ecw_max & 0x0f)) - 1
+    }
+
+    /// The TXOP limit in microseconds.
+    pub fn txop_limit_us(&self) -> u32 {
+        self.txop_limit as u32 * 32
+    }
+}
@@ -0,0 +1,49 @@
+use super::{AkmSuite, CipherSuite, RsnCapabilities};
+
+/// The organization identifier of Microsoft, which is used for the WPA and WMM vendor elements.
+pub const MICROSOFT_OUI: [u8; 3] = [0x00, 0x50, 0xf2];
+
+/// The legacy WPA (version 1) element.
+///
+/// It's a vendor specific element with the [MICROSOFT_OUI] and vendor type `1`.
+/// Its structure is a predecessor of the RSN element:
+/// - 2 bytes: Version (always `1`)
+/// - 4 bytes: Group cipher suite
+/// - 2 bytes: Pairwise cipher suite count + `4 * n` bytes pairwise cipher suites
+/// - 2 bytes: AKM suite count + `4 * n` bytes AKM suites
+/// - 2 bytes: Capabilities (optional)
+///
+/// The suite selectors use the [MICROSOFT_OUI] instead of the IEEE one.
+/// They're mapped to the same [CipherSuite] and [AkmSuite] variants as their RSN counterparts,
+/// e.g. `00:50:F2:02` becomes [CipherSuite::Tkip].
+///
+/// Like for the RSN element, missing fields at the end of the element fall back to the defaults
+/// (TKIP and 802.1X).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WpaInformation {
+    pub version: u16,
+    pub group_cipher: CipherSuite,
+    pub pairwise_ciphers: Vec<CipherSuite>,
+    pub akm_suites: Vec<AkmSuite>,
+    /// Most access points don't send the capabilities.
+    pub capabilities: Option<RsnCapabilities>,
+}
+
+impl Default for WpaInformation {
+    fn default() -> Self {
+        WpaInformation {
+            version: 1,
+            group_cipher: CipherSuite::Tkip,
+            pairwise_ciphers: vec![CipherSuite::Tkip],
+            akm_suites: vec![AkmSuite::Ieee8021x],
+            capabilities: None,
+        }
+    }
+}
+
+impl WpaInformation {
+    /// Whether TKIP is used as group or pairwise cipher.
+    pub fn uses_tkip(&self) -> bool {
+        self.group_cipher == CipherSuite::Tkip || self.pairwise_ciphers.contains(&CipherSuite::Tkip)
+    }
+}
@@ -1,32 +1,131 @@
 use libwifi_macros::AddressHeader;
+use num_enum::{FromPrimitive, IntoPrimitive};
 
+use super::BlockAckAction;
 use crate::frame::components::*;
 
-enum ActionCategory {
-    SpectrumManagement,
-    Qos,
-    Dls,
-    BlockAck,
-    Public,
-    RadioMeasurement,
-    FastBssTransition,
-    HighThroughput,
-    SaQuery,
-    ProtectedDualOfPublicAction,
-    Reserved,
-    VendorSpecificProtected,
-    VendorSpecific,
-    Error,
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+/// The category of an [Action] frame. It's the first byte of the action frame body.
+///
+/// Reference: IEEE 802.11-2020, Table 9-51.
+///
+/// Reserved categories and categories with the error bit (`0x80`) set are preserved via
+/// [ActionCategory::Unknown].
+pub enum ActionCategory {
+    SpectrumManagement = 0,
+    Qos = 1,
+    Dls = 2,
+    BlockAck = 3,
+    Public = 4,
+    RadioMeasurement = 5,
+    FastBssTransition = 6,
+    HighThroughput = 7,
+    SaQuery = 8,
+    ProtectedDualOfPublicAction = 9,
+    Wnm = 10,
+    UnprotectedWnm = 11,
+    Tdls = 12,
+    Mesh = 13,
+    Multihop = 14,
+    SelfProtected = 15,
+    Dmg = 16,
+    FastSessionTransfer = 18,
+    RobustAvStreaming = 19,
+    UnprotectedDmg = 20,
+    Vht = 21,
+    UnprotectedS1g = 22,
+    S1g = 23,
+    FlowControl = 24,
+    ControlResponseMcsNegotiation = 25,
+    Fils = 26,
+    Cdmg = 27,
+    Cmmg = 28,
+    Glk = 29,
+    He = 30,
+    ProtectedHe = 31,
+    Wur = 32,
+    ProtectedFtm = 34,
+    Eht = 36,
+    ProtectedEht = 37,
+    VendorSpecificProtected = 126,
+    VendorSpecific = 127,
+    #[num_enum(catch_all)]
+    Unknown(u8),
 }
 
-enum Action {
+impl ActionCategory {
+    /// Whether this is a category that has been returned by a station that didn't understand
+    /// the original action frame. In that case, the most significant bit is set.
+    pub fn is_error(&self) -> bool {
+        matches!(self, ActionCategory::Unknown(category) if category & 0x80 > 0)
+    }
+}
+
+/// The category specific body of an [Action] frame.
+///
+/// Categories that aren't handled by this library (yet) are kept in the [ActionBody::Unhandled]
+/// variant.
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum ActionBody {
+    BlockAck(BlockAckAction),
+    SaQuery(SaQueryAction),
+    VendorSpecific(VendorSpecificAction),
+    /// All bytes following the category, including the action code.
+    Unhandled(Vec<u8>),
+    /// The encrypted body of a robust action frame, whose Protected Frame bit is set.
+    /// It contains all bytes following the header, including the encrypted category.
+    Protected(Vec<u8>),
+}
+
+/// Security Association Query actions, used by stations with management frame protection to
+/// verify that an association is still valid.
+///
+/// The transaction identifier of the response mirrors the one of the request.
+#[derive(Clone, Debug)]
+pub enum SaQueryAction {
+    Request {
+        transaction_id: [u8; 2],
+        /// Optional trailing elements, e.g. the Operating Channel Information element.
+        station_info: StationInfo,
+    },
+    Response {
+        transaction_id: [u8; 2],
+        /// Optional trailing elements, e.g. the Operating Channel Information element.
+        station_info: StationInfo,
+    },
+}
 
+/// The body of a (protected) vendor specific action frame.
+///
+/// There's no action code; the organization identifier directly follows the category.
+#[derive(Clone, Debug)]
+pub struct VendorSpecificAction {
+    pub oui: [u8; 3],
+    pub data: Vec<u8>,
 }
 
+/// Action frames are used to trigger a specific action in the receiving station.
+///
+/// The first byte of the body is the [ActionCategory], the rest is category specific
+/// and can be found in the [ActionBody].
+///
+/// The body of protected frames is encrypted. Their category is unknown and the body is kept
+/// in [ActionBody::Protected].
 #[derive(Clone, Debug, AddressHeader)]
 pub struct Action {
     pub header: ManagementHeader,
-    pub category: ,
-    pub action: ,
-    pub station_info: StationInfo,
+    /// The category, `None` if the frame is protected.
+    pub category: Option<ActionCategory>,
+    pub body: ActionBody,
+}
+
+/// The same as an [Action] frame, but the receiver doesn't acknowledge it.
+#[derive(Clone, Debug, AddressHeader)]
+pub struct ActionNoAck {
+    pub header: ManagementHeader,
+    /// The category, `None` if the frame is protected.
+    pub category: Option<ActionCategory>,
+    pub body: ActionBody,
 }
@@ -5,8 +5,9 @@ use crate::frame::components::*;
 #[derive(Clone, Debug, AddressHeader)]
 pub struct AssociationRequest {
     pub header: ManagementHeader,
-    pub beacon_interval: u16,
     pub capability_info: u16,
+    /// How often the station wakes up to listen to beacons, in units of the beacon interval.
+    pub listen_interval: u16,
     pub station_info: StationInfo,
 }
 
@@ -14,7 +15,34 @@ pub struct AssociationRequest {
 pub struct AssociationResponse {
     pub header: ManagementHeader,
     pub capability_info: u16,
-    pub status_code: u16,
+    pub status_code: StatusCode,
+    pub association_id: u16,
+    pub station_info: StationInfo,
+}
+
+/// Sent by a station that's roaming to a new AP within the same ESS.
+///
+/// It's the same as an [AssociationRequest], but additionally contains the address of the AP
+/// the station is currently associated with.
+#[derive(Clone, Debug, AddressHeader)]
+pub struct ReassociationRequest {
+    pub header: ManagementHeader,
+    pub capability_info: u16,
+    /// How often the station wakes up to listen to beacons, in units of the beacon interval.
+    pub listen_interval: u16,
+    /// The address of the AP the station is currently associated with.
+    pub current_ap_address: MacAddress,
+    pub station_info: StationInfo,
+}
+
+/// The reply to a [ReassociationRequest].
+///
+/// Its format is the same as the one of an [AssociationResponse].
+#[derive(Clone, Debug, AddressHeader)]
+pub struct ReassociationResponse {
+    pub header: ManagementHeader,
+    pub capability_info: u16,
+    pub status_code: StatusCode,
     pub association_id: u16,
     pub station_info: StationInfo,
 }
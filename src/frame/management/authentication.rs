@@ -0,0 +1,91 @@
+use libwifi_macros::AddressHeader;
+use num_enum::{FromPrimitive, IntoPrimitive};
+
+use crate::frame::components::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, IntoPrimitive)]
+#[repr(u16)]
+/// The authentication algorithm that's used in an [Authentication] frame.
+///
+/// Reference: IEEE 802.11-2020, 9.4.1.1
+pub enum AuthenticationAlgorithm {
+    OpenSystem = 0,
+    SharedKey = 1,
+    FastBssTransition = 2,
+    /// Simultaneous Authentication of Equals (WPA3)
+    Sae = 3,
+    FilsSharedKey = 4,
+    FilsSharedKeyPfs = 5,
+    FilsPublicKey = 6,
+    /// Pre-Association Security Negotiation
+    Pasn = 7,
+    VendorSpecific = 65535,
+    /// Any algorithm that isn't known to this library.
+    /// The discriminant only avoids an overflow after `VendorSpecific` and doesn't mean algorithm 8.
+    #[num_enum(catch_all)]
+    Unknown(u16) = 8,
+}
+
+impl AuthenticationAlgorithm {
+    /// Whether the frame body contains algorithm specific fields before the information
+    /// elements.
+    ///
+    /// For instance, SAE frames carry the finite cyclic group, scalar and element.
+    /// Since these fields aren't self-describing, they can't be parsed as [StationInfo].
+    pub fn has_algorithm_data(&self) -> bool {
+        matches!(
+            self,
+            AuthenticationAlgorithm::Sae
+                | AuthenticationAlgorithm::Pasn
+                | AuthenticationAlgorithm::VendorSpecific
+                | AuthenticationAlgorithm::Unknown(_)
+        )
+    }
+}
+
+/// Sent by stations to authenticate against an AP and by the AP as reply.
+///
+/// For Open System and Shared Key authentication, the fixed fields are followed by
+/// information elements (for instance, the challenge text).
+/// For algorithms such as SAE, the remaining body contains algorithm specific data,
+/// which is kept as raw bytes in `algorithm_data`.
+#[derive(Clone, Debug, AddressHeader)]
+pub struct Authentication {
+    pub header: ManagementHeader,
+    pub auth_algorithm: AuthenticationAlgorithm,
+    /// The authentication transaction sequence number, starting at 1.
+    pub auth_seq: u16,
+    pub status_code: StatusCode,
+    /// Raw algorithm specific body, see [AuthenticationAlgorithm::has_algorithm_data].
+    /// Empty for all other algorithms.
+    pub algorithm_data: Vec<u8>,
+    pub station_info: StationInfo,
+}
+
+/// Sent to terminate an authentication.
+///
+/// With Management Frame Protection, the body of unicast frames is encrypted.
+/// In that case, it's kept as raw bytes in `protected_body`, `reason_code` is
+/// [ReasonCode::Reserved] and `station_info` is empty.
+#[derive(Clone, Debug, AddressHeader)]
+pub struct Deauthentication {
+    pub header: ManagementHeader,
+    pub reason_code: ReasonCode,
+    pub station_info: StationInfo,
+    /// The encrypted body, if the Protected Frame bit is set.
+    pub protected_body: Option<Vec<u8>>,
+}
+
+/// Sent to terminate an association.
+///
+/// With Management Frame Protection, the body of unicast frames is encrypted.
+/// In that case, it's kept as raw bytes in `protected_body`, `reason_code` is
+/// [ReasonCode::Reserved] and `station_info` is empty.
+#[derive(Clone, Debug, AddressHeader)]
+pub struct Disassociation {
+    pub header: ManagementHeader,
+    pub reason_code: ReasonCode,
+    pub station_info: StationInfo,
+    /// The encrypted body, if the Protected Frame bit is set.
+    pub protected_body: Option<Vec<u8>>,
+}
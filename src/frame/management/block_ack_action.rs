@@ -0,0 +1,105 @@
+use crate::frame::components::*;
+
+/// The Block Ack Parameter Set that's negotiated during the ADDBA handshake.
+///
+/// Structure of the little-endian field:
+/// - **bit_0** `amsdu_supported`: Whether A-MSDUs may be carried in the BlockAck session.
+/// - **bit_1** `immediate_policy`: `true` for immediate, `false` for delayed BlockAck.
+/// - **bit_2-5** `tid`: The traffic identifier of the session.
+/// - **bit_6-15** `buffer_size`: The amount of frames the recipient can buffer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockAckParameterSet {
+    pub amsdu_supported: bool,
+    pub immediate_policy: bool,
+    pub tid: u8,
+    pub buffer_size: u16,
+}
+
+impl BlockAckParameterSet {
+    /// Decode the parameter set from its little-endian representation.
+    pub fn from_bits(bits: u16) -> Self {
+        BlockAckParameterSet {
+            amsdu_supported: bits & 0x0001 > 0,
+            immediate_policy: bits & 0x0002 > 0,
+            tid: ((bits >> 2) & 0x000f) as u8,
+            buffer_size: bits >> 6,
+        }
+    }
+
+    /// Encode the parameter set into its little-endian representation.
+    pub fn bits(&self) -> u16 {
+        (self.amsdu_supported as u16)
+            | ((self.immediate_policy as u16) << 1)
+            | (((self.tid & 0x0f) as u16) << 2)
+            | ((self.buffer_size & 0x03ff) << 6)
+    }
+}
+
+/// Sent by the originator of a BlockAck session to request its establishment.
+#[derive(Clone, Debug)]
+pub struct AddBaRequest {
+    /// Used to match the [AddBaResponse] to this request.
+    pub dialog_token: u8,
+    pub parameters: BlockAckParameterSet,
+    /// The session timeout in time units (1024 µs). `0` disables the timeout.
+    pub timeout: u16,
+    /// The sequence number of the first frame that's sent in this session.
+    pub starting_sequence_control: SequenceControl,
+    /// Optional trailing elements, e.g. the ADDBA Extension element.
+    pub station_info: StationInfo,
+}
+
+/// The reply of the recipient to an [AddBaRequest].
+#[derive(Clone, Debug)]
+pub struct AddBaResponse {
+    pub dialog_token: u8,
+    pub status_code: StatusCode,
+    pub parameters: BlockAckParameterSet,
+    /// The session timeout in time units (1024 µs). `0` disables the timeout.
+    pub timeout: u16,
+    /// Optional trailing elements, e.g. the ADDBA Extension element.
+    pub station_info: StationInfo,
+}
+
+/// Sent by either side to tear down a BlockAck session.
+///
+/// Structure of the little-endian DELBA parameter set:
+/// - **bit_0-10**: Reserved
+/// - **bit_11** `initiator`: Whether the sender is the originator of the session.
+/// - **bit_12-15** `tid`: The traffic identifier of the session.
+#[derive(Clone, Debug)]
+pub struct DelBa {
+    /// The raw DELBA parameter set, which keeps the reserved bits.
+    pub parameters: u16,
+    pub reason_code: ReasonCode,
+    /// Optional trailing elements.
+    pub station_info: StationInfo,
+}
+
+impl DelBa {
+    /// Whether the sender is the originator of the session.
+    pub fn initiator(&self) -> bool {
+        self.parameters & 0x0800 > 0
+    }
+
+    pub fn set_initiator(&mut self, initiator: bool) {
+        self.parameters = (self.parameters & !0x0800) | ((initiator as u16) << 11);
+    }
+
+    /// The traffic identifier of the session.
+    pub fn tid(&self) -> u8 {
+        (self.parameters >> 12) as u8
+    }
+
+    pub fn set_tid(&mut self, tid: u8) {
+        self.parameters = (self.parameters & 0x0fff) | (((tid & 0x0f) as u16) << 12);
+    }
+}
+
+/// The actions of the [BlockAck](super::ActionCategory::BlockAck) category.
+#[derive(Clone, Debug)]
+pub enum BlockAckAction {
+    AddBaRequest(AddBaRequest),
+    AddBaResponse(AddBaResponse),
+    DelBa(DelBa),
+}
@@ -1,7 +1,17 @@
+mod action;
 mod association;
+mod authentication;
 mod beacon;
+mod block_ack_action;
 mod probe;
 
-pub use association::{AssociationRequest, AssociationResponse};
+pub use action::*;
+pub use association::{
+    AssociationRequest, AssociationResponse, ReassociationRequest, ReassociationResponse,
+};
+pub use authentication::{
+    Authentication, AuthenticationAlgorithm, Deauthentication, Disassociation,
+};
 pub use beacon::Beacon;
+pub use block_ack_action::*;
 pub use probe::{ProbeRequest, ProbeResponse};
@@ -27,6 +27,13 @@ pub enum Frame {
     ProbeResponse(ProbeResponse),
     AssociationRequest(AssociationRequest),
     AssociationResponse(AssociationResponse),
+    ReassociationRequest(ReassociationRequest),
+    ReassociationResponse(ReassociationResponse),
+    Authentication(Authentication),
+    Deauthentication(Deauthentication),
+    Disassociation(Disassociation),
+    Action(Action),
+    ActionNoAck(ActionNoAck),
 
     // Control Frames
     Rts(Rts),
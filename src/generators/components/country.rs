@@ -0,0 +1,37 @@
+use cookie_factory::{do_gen, gen_cond, gen_le_u8, gen_many_ref, gen_slice};
+
+use crate::frame::components::{Country, CountryTriplet};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [Country] element.
+///
+/// A padding byte is added, if the length of the element would be odd.
+pub fn gen_country<'a>(cursor: GenCursor<'a>, country: &Country) -> GenResult<'a> {
+    let length = 3 + 3 * country.triplets.len();
+    let padding = length % 2 == 1;
+
+    do_gen!(
+        cursor,
+        gen_slice!(&country.country_code)
+            >> gen_le_u8!(country.environment)
+            >> gen_many_ref!(&country.triplets, gen_triplet)
+            >> gen_cond!(padding, gen_le_u8!(0))
+    )
+}
+
+fn gen_triplet<'a>(cursor: GenCursor<'a>, triplet: &CountryTriplet) -> GenResult<'a> {
+    let bytes = match triplet {
+        CountryTriplet::Subband(subband) => [
+            subband.first_channel,
+            subband.number_of_channels,
+            subband.max_transmit_power as u8,
+        ],
+        CountryTriplet::Operating(operating) => [
+            operating.operating_extension_id,
+            operating.operating_class,
+            operating.coverage_class,
+        ],
+    };
+
+    gen_slice!(cursor, &bytes)
+}
@@ -0,0 +1,9 @@
+use cookie_factory::{do_gen, gen_le_u8};
+
+use crate::frame::components::DsParameterSet;
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [DsParameterSet] element.
+pub fn gen_ds_parameter_set<'a>(cursor: GenCursor<'a>, ds: &DsParameterSet) -> GenResult<'a> {
+    do_gen!(cursor, gen_le_u8!(ds.current_channel))
+}
@@ -0,0 +1,51 @@
+use cookie_factory::{do_gen, gen_call, gen_cond, gen_le_u16, gen_le_u8, gen_slice};
+
+use crate::frame::components::{EhtCapabilities, EhtOperation, EhtOperationInfo, ExtensionId};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of an [EhtCapabilities] element, including its extension ID.
+pub fn gen_eht_capabilities<'a>(cursor: GenCursor<'a>, eht: &EhtCapabilities) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u8!(ExtensionId::EhtCapabilities.into())
+            >> gen_slice!(&eht.mac_capabilities)
+            >> gen_slice!(&eht.phy_capabilities)
+            >> gen_slice!(eht.mcs_nss_and_ppe_thresholds.as_slice())
+    )
+}
+
+/// Serialize the body of an [EhtOperation] element, including its extension ID.
+pub fn gen_eht_operation<'a>(cursor: GenCursor<'a>, eht: &EhtOperation) -> GenResult<'a> {
+    let has_disabled_subchannels = eht
+        .operation_info
+        .is_some_and(|info| info.disabled_subchannel_bitmap.is_some());
+    let parameters = (eht.operation_info.is_some() as u8)
+        | ((has_disabled_subchannels as u8) << 1)
+        | ((eht.default_pe_duration as u8) << 2)
+        | ((eht.group_addressed_bu_indication_limit as u8) << 3)
+        | ((eht.group_addressed_bu_indication_exponent & 0b11) << 4);
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(ExtensionId::EhtOperation.into())
+            >> gen_le_u8!(parameters)
+            >> gen_slice!(&eht.basic_mcs_nss_set)
+            >> gen_cond!(
+                eht.operation_info.is_some(),
+                gen_call!(gen_operation_info, eht.operation_info.as_ref().unwrap())
+            )
+    )
+}
+
+fn gen_operation_info<'a>(cursor: GenCursor<'a>, info: &EhtOperationInfo) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u8!(info.channel_width & 0b111)
+            >> gen_le_u8!(info.channel_center_frequency_segment_0)
+            >> gen_le_u8!(info.channel_center_frequency_segment_1)
+            >> gen_cond!(
+                info.disabled_subchannel_bitmap.is_some(),
+                gen_le_u16!(info.disabled_subchannel_bitmap.unwrap())
+            )
+    )
+}
@@ -0,0 +1,9 @@
+use cookie_factory::{do_gen, gen_le_u8};
+
+use crate::frame::components::ErpInformation;
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of an [ErpInformation] element.
+pub fn gen_erp_information<'a>(cursor: GenCursor<'a>, erp: &ErpInformation) -> GenResult<'a> {
+    do_gen!(cursor, gen_le_u8!(erp.bits()))
+}
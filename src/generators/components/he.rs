@@ -0,0 +1,99 @@
+use cookie_factory::{do_gen, gen_call, gen_cond, gen_le_u16, gen_le_u8, gen_slice};
+
+use crate::frame::components::{
+    ExtensionId, He6GhzOperationInfo, HeCapabilities, HeMcsNssSet, HeOperation, HeVhtOperationInfo,
+};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [HeCapabilities] element, including its extension ID.
+///
+/// The optional MCS sets are written if they're present, independent of the PHY capabilities.
+pub fn gen_he_capabilities<'a>(cursor: GenCursor<'a>, he: &HeCapabilities) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u8!(ExtensionId::HeCapabilities.into())
+            >> gen_slice!(&he.mac_capabilities)
+            >> gen_slice!(&he.phy_capabilities)
+            >> gen_mcs_nss_set(&he.mcs_nss_80mhz)
+            >> gen_cond!(
+                he.mcs_nss_160mhz.is_some(),
+                gen_call!(gen_mcs_nss_set, he.mcs_nss_160mhz.as_ref().unwrap())
+            )
+            >> gen_cond!(
+                he.mcs_nss_80_plus_80mhz.is_some(),
+                gen_call!(gen_mcs_nss_set, he.mcs_nss_80_plus_80mhz.as_ref().unwrap())
+            )
+            >> gen_slice!(he.ppe_thresholds.as_slice())
+    )
+}
+
+/// Serialize the body of a [HeOperation] element, including its extension ID.
+pub fn gen_he_operation<'a>(cursor: GenCursor<'a>, he: &HeOperation) -> GenResult<'a> {
+    let parameters = (he.default_pe_duration as u32 & 0b111)
+        | ((he.twt_required as u32) << 3)
+        | ((he.txop_duration_rts_threshold as u32 & 0x03ff) << 4)
+        | ((he.vht_operation_info.is_some() as u32) << 14)
+        | ((he.max_cohosted_bssid_indicator.is_some() as u32) << 15)
+        | ((he.er_su_disable as u32) << 16)
+        | ((he.six_ghz_operation_info.is_some() as u32) << 17);
+    let bss_color_info = (he.bss_color & 0x3f)
+        | ((he.partial_bss_color as u8) << 6)
+        | ((he.bss_color_disabled as u8) << 7);
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(ExtensionId::HeOperation.into())
+            >> gen_slice!(&parameters.to_le_bytes()[..3])
+            >> gen_le_u8!(bss_color_info)
+            >> gen_le_u16!(he.basic_mcs_set.0)
+            >> gen_cond!(
+                he.vht_operation_info.is_some(),
+                gen_call!(
+                    gen_vht_operation_info,
+                    he.vht_operation_info.as_ref().unwrap()
+                )
+            )
+            >> gen_cond!(
+                he.max_cohosted_bssid_indicator.is_some(),
+                gen_le_u8!(he.max_cohosted_bssid_indicator.unwrap())
+            )
+            >> gen_cond!(
+                he.six_ghz_operation_info.is_some(),
+                gen_call!(
+                    gen_6ghz_operation_info,
+                    he.six_ghz_operation_info.as_ref().unwrap()
+                )
+            )
+    )
+}
+
+fn gen_mcs_nss_set<'a>(cursor: GenCursor<'a>, mcs_nss_set: &HeMcsNssSet) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u16!(mcs_nss_set.rx_mcs_map.0) >> gen_le_u16!(mcs_nss_set.tx_mcs_map.0)
+    )
+}
+
+fn gen_vht_operation_info<'a>(cursor: GenCursor<'a>, info: &HeVhtOperationInfo) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u8!(info.channel_width.into())
+            >> gen_le_u8!(info.channel_center_frequency_segment_0)
+            >> gen_le_u8!(info.channel_center_frequency_segment_1)
+    )
+}
+
+fn gen_6ghz_operation_info<'a>(cursor: GenCursor<'a>, info: &He6GhzOperationInfo) -> GenResult<'a> {
+    let control = (info.channel_width & 0b11)
+        | ((info.duplicate_beacon as u8) << 2)
+        | ((info.regulatory_info & 0b111) << 3);
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(info.primary_channel)
+            >> gen_le_u8!(control)
+            >> gen_le_u8!(info.channel_center_frequency_segment_0)
+            >> gen_le_u8!(info.channel_center_frequency_segment_1)
+            >> gen_le_u8!(info.minimum_rate)
+    )
+}
@@ -2,7 +2,7 @@ use cookie_factory::{do_gen, gen_call, gen_cond, gen_slice};
 
 use crate::{
     frame::components::{DataHeader, ManagementHeader},
-    generators::{GenCursor, GenResult},
+    generators::{GenCursor, GenResult, SerializationError},
 };
 
 use super::{frame_control::gen_frame_control, sequence_control::gen_sequence_ctrl};
@@ -19,8 +19,23 @@ pub fn gen_mgmt_header<'a>(cursor: GenCursor<'a>, mgmt_header: &ManagementHeader
     )?)
 }
 
-pub fn _gen_data_header<'a>(cursor: GenCursor<'a>, data_header: &DataHeader) -> GenResult<'a> {
-    Ok(do_gen!(
+/// Serialize a [DataHeader].
+///
+/// The forth address is only written if both `to_ds` and `from_ds` are set.
+/// The QoS control field is only written for QoS frame subtypes.
+/// In both cases, the respective field must be present in the header.
+pub fn gen_data_header<'a>(cursor: GenCursor<'a>, data_header: &DataHeader) -> GenResult<'a> {
+    let frame_control = &data_header.frame_control;
+    let has_address_4 = frame_control.to_ds() && frame_control.from_ds();
+    if has_address_4 && data_header.address_4.is_none() {
+        return Err(SerializationError::DataHeaderMissingAddress4.into());
+    }
+    let is_qos = frame_control.frame_subtype.is_qos();
+    if is_qos && data_header.qos.is_none() {
+        return Err(SerializationError::DataHeaderMissingQos.into());
+    }
+
+    do_gen!(
         cursor,
         gen_frame_control(&data_header.frame_control)
             >> gen_slice!(&data_header.duration)
@@ -29,12 +44,9 @@ pub fn _gen_data_header<'a>(cursor: GenCursor<'a>, data_header: &DataHeader) ->
             >> gen_slice!(&data_header.address_3.0)
             >> gen_sequence_ctrl(&data_header.sequence_control)
             >> gen_cond!(
-                data_header.address_4.is_some(),
+                has_address_4,
                 gen_slice!(data_header.address_4.as_ref().unwrap().0)
             )
-            >> gen_cond!(
-                data_header.qos.is_some(),
-                gen_slice!(&data_header.qos.unwrap())
-            )
-    )?)
+            >> gen_cond!(is_qos, gen_slice!(&data_header.qos.unwrap()))
+    )
 }
@@ -0,0 +1,59 @@
+use cookie_factory::{do_gen, gen_call, gen_le_u16, gen_le_u32, gen_le_u8, gen_slice};
+
+use crate::frame::components::{HtCapabilities, HtOperation, SupportedMcsSet};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [HtCapabilities] element.
+pub fn gen_ht_capabilities<'a>(cursor: GenCursor<'a>, ht: &HtCapabilities) -> GenResult<'a> {
+    let ampdu_parameters = (ht.ampdu_parameters.max_ampdu_length_exponent & 0b11)
+        | ((ht.ampdu_parameters.min_mpdu_start_spacing & 0b111) << 2);
+
+    do_gen!(
+        cursor,
+        gen_le_u16!(ht.ht_capability_info.bits())
+            >> gen_le_u8!(ampdu_parameters)
+            >> gen_supported_mcs_set(&ht.supported_mcs_set)
+            >> gen_le_u16!(ht.extended_capabilities.bits())
+            >> gen_le_u32!(ht.txbf_capabilities.bits())
+            >> gen_le_u8!(ht.asel_capabilities.bits())
+    )
+}
+
+/// Serialize the body of a [HtOperation] element.
+pub fn gen_ht_operation<'a>(cursor: GenCursor<'a>, ht: &HtOperation) -> GenResult<'a> {
+    let info_1 = ht.secondary_channel_offset.bits()
+        | ((ht.sta_channel_width as u8) << 2)
+        | ((ht.rifs_mode as u8) << 3);
+    let info_2 = ht.ht_protection.bits() as u16
+        | ((ht.nongreenfield_stas_present as u16) << 2)
+        | ((ht.obss_non_ht_stas_present as u16) << 4)
+        | ((ht.channel_center_frequency_segment_2 as u16) << 5);
+    let info_3 = ((ht.dual_beacon as u16) << 6)
+        | ((ht.dual_cts_protection as u16) << 7)
+        | ((ht.stbc_beacon as u16) << 8);
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(ht.primary_channel)
+            >> gen_le_u8!(info_1)
+            >> gen_le_u16!(info_2)
+            >> gen_le_u16!(info_3)
+            >> gen_supported_mcs_set(&ht.basic_mcs_set)
+    )
+}
+
+/// Serialize a 16 byte (basic) supported MCS set, as described in [SupportedMcsSet].
+fn gen_supported_mcs_set<'a>(cursor: GenCursor<'a>, mcs_set: &SupportedMcsSet) -> GenResult<'a> {
+    let tx_mcs_set = (mcs_set.tx_mcs_set_defined as u8)
+        | ((mcs_set.tx_rx_mcs_set_not_equal as u8) << 1)
+        | ((mcs_set.tx_max_spatial_streams & 0b11) << 2)
+        | ((mcs_set.tx_unequal_modulation as u8) << 4);
+
+    do_gen!(
+        cursor,
+        gen_slice!(&mcs_set.rx_mcs_bitmask)
+            >> gen_le_u16!(mcs_set.rx_highest_data_rate & 0x03ff)
+            >> gen_le_u8!(tx_mcs_set)
+            >> gen_slice!(&[0u8; 3])
+    )
+}
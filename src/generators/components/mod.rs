@@ -1,7 +1,35 @@
+mod country;
+mod dsss;
+mod eht;
+mod erp;
 mod frame_control;
+mod he;
 mod header;
+mod ht;
+mod multi_link;
+mod rsn;
 mod sequence_control;
 mod station_info;
+mod tim;
+mod vendor;
+mod vht;
+mod wmm;
+mod wpa;
 
+pub(crate) use country::*;
+pub(crate) use dsss::*;
+pub(crate) use eht::*;
+pub(crate) use erp::*;
+pub(crate) use frame_control::gen_frame_control;
+pub(crate) use he::*;
 pub(crate) use header::*;
+pub(crate) use ht::*;
+pub(crate) use multi_link::*;
+pub(crate) use rsn::*;
+pub(crate) use sequence_control::gen_sequence_ctrl;
 pub(crate) use station_info::*;
+pub(crate) use tim::*;
+pub(crate) use vendor::*;
+pub(crate) use vht::*;
+pub(crate) use wmm::*;
+pub(crate) use wpa::*;
@@ -0,0 +1,244 @@
+use cookie_factory::{
+    do_gen, gen_call, gen_cond, gen_le_u16, gen_le_u64, gen_le_u8, gen_many_ref, gen_slice,
+};
+
+use super::gen_fragmented;
+use crate::frame::components::{
+    BasicMultiLink, DtimInfo, ExtensionId, MultiLink, NstrIndicationBitmap, PerStaProfile,
+    ProbeRequestMultiLink,
+};
+use crate::generators::{GenCursor, GenResult};
+
+/// The subelement ID of a [PerStaProfile].
+const PER_STA_PROFILE_ID: u8 = 0;
+/// The ID of the Fragment subelement, which carries the remaining data of a long
+/// [PerStaProfile].
+const FRAGMENT_SUBELEMENT_ID: u8 = 254;
+
+/// Serialize the body of a [MultiLink] element, including its extension ID.
+///
+/// The presence bitmaps and length fields are derived from the optional fields.
+/// Per-STA profiles that are longer than 255 bytes are fragmented.
+pub fn gen_multi_link<'a>(cursor: GenCursor<'a>, multi_link: &MultiLink) -> GenResult<'a> {
+    match multi_link {
+        MultiLink::Basic(multi_link) => gen_basic_multi_link(cursor, multi_link),
+        MultiLink::ProbeRequest(multi_link) => gen_probe_request_multi_link(cursor, multi_link),
+    }
+}
+
+fn gen_basic_multi_link<'a>(cursor: GenCursor<'a>, multi_link: &BasicMultiLink) -> GenResult<'a> {
+    let presence = (multi_link.link_id.is_some() as u16)
+        | ((multi_link.bss_parameters_change_count.is_some() as u16) << 1)
+        | ((multi_link.medium_synchronization_delay.is_some() as u16) << 2)
+        | ((multi_link.eml_capabilities.is_some() as u16) << 3)
+        | ((multi_link.mld_capabilities.is_some() as u16) << 4)
+        | ((multi_link.ap_mld_id.is_some() as u16) << 5)
+        | ((multi_link.extended_mld_capabilities.is_some() as u16) << 6);
+    let common_info_length = 1
+        + 6
+        + multi_link.link_id.is_some() as usize
+        + multi_link.bss_parameters_change_count.is_some() as usize
+        + 2 * multi_link.medium_synchronization_delay.is_some() as usize
+        + 2 * multi_link.eml_capabilities.is_some() as usize
+        + 2 * multi_link.mld_capabilities.is_some() as usize
+        + multi_link.ap_mld_id.is_some() as usize
+        + 2 * multi_link.extended_mld_capabilities.is_some() as usize;
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(ExtensionId::MultiLink.into())
+            >> gen_le_u16!(presence << 4)
+            >> gen_le_u8!(common_info_length as u8)
+            >> gen_slice!(&multi_link.mld_mac_address.0)
+            >> gen_cond!(
+                multi_link.link_id.is_some(),
+                gen_le_u8!(multi_link.link_id.unwrap() & 0x0f)
+            )
+            >> gen_cond!(
+                multi_link.bss_parameters_change_count.is_some(),
+                gen_le_u8!(multi_link.bss_parameters_change_count.unwrap())
+            )
+            >> gen_cond!(
+                multi_link.medium_synchronization_delay.is_some(),
+                gen_le_u16!(multi_link.medium_synchronization_delay.unwrap())
+            )
+            >> gen_cond!(
+                multi_link.eml_capabilities.is_some(),
+                gen_le_u16!(multi_link.eml_capabilities.unwrap().0)
+            )
+            >> gen_cond!(
+                multi_link.mld_capabilities.is_some(),
+                gen_le_u16!(multi_link.mld_capabilities.unwrap().0)
+            )
+            >> gen_cond!(
+                multi_link.ap_mld_id.is_some(),
+                gen_le_u8!(multi_link.ap_mld_id.unwrap())
+            )
+            >> gen_cond!(
+                multi_link.extended_mld_capabilities.is_some(),
+                gen_le_u16!(multi_link.extended_mld_capabilities.unwrap())
+            )
+            >> gen_many_ref!(&multi_link.per_sta_profiles, gen_basic_per_sta_profile)
+    )
+}
+
+fn gen_probe_request_multi_link<'a>(
+    cursor: GenCursor<'a>,
+    multi_link: &ProbeRequestMultiLink,
+) -> GenResult<'a> {
+    let control = 1 | ((multi_link.ap_mld_id.is_some() as u16) << 4);
+    let common_info_length = 1 + multi_link.ap_mld_id.is_some() as usize;
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(ExtensionId::MultiLink.into())
+            >> gen_le_u16!(control)
+            >> gen_le_u8!(common_info_length as u8)
+            >> gen_cond!(
+                multi_link.ap_mld_id.is_some(),
+                gen_le_u8!(multi_link.ap_mld_id.unwrap())
+            )
+            >> gen_many_ref!(
+                &multi_link.per_sta_profiles,
+                gen_probe_request_per_sta_profile
+            )
+    )
+}
+
+/// The length of a per-STA profile subelement, without its header.
+fn per_sta_profile_length(profile: &PerStaProfile, has_sta_info: bool) -> usize {
+    let mut length = 2 + profile.sta_profile.len();
+    if has_sta_info {
+        length += sta_info_length(profile);
+    }
+
+    length
+}
+
+fn sta_info_length(profile: &PerStaProfile) -> usize {
+    let nstr_bitmap_length = match profile.nstr_indication_bitmap {
+        Some(NstrIndicationBitmap::OneOctet(_)) => 1,
+        Some(NstrIndicationBitmap::TwoOctets(_)) => 2,
+        None => 0,
+    };
+
+    1 + 6 * profile.sta_mac_address.is_some() as usize
+        + 2 * profile.beacon_interval.is_some() as usize
+        + 8 * profile.tsf_offset.is_some() as usize
+        + 2 * profile.dtim_info.is_some() as usize
+        + nstr_bitmap_length
+        + profile.bss_parameters_change_count.is_some() as usize
+}
+
+fn gen_basic_per_sta_profile<'a>(cursor: GenCursor<'a>, profile: &PerStaProfile) -> GenResult<'a> {
+    gen_per_sta_profile(cursor, profile, true)
+}
+
+fn gen_probe_request_per_sta_profile<'a>(
+    cursor: GenCursor<'a>,
+    profile: &PerStaProfile,
+) -> GenResult<'a> {
+    gen_per_sta_profile(cursor, profile, false)
+}
+
+/// Serialize a per-STA profile subelement.
+/// The body is serialized up front, so it can be split into fragments.
+fn gen_per_sta_profile<'a>(
+    cursor: GenCursor<'a>,
+    profile: &PerStaProfile,
+    has_sta_info: bool,
+) -> GenResult<'a> {
+    let mut body = vec![0; per_sta_profile_length(profile, has_sta_info)];
+    if has_sta_info {
+        gen_basic_per_sta_profile_body((body.as_mut_slice(), 0), profile)?;
+    } else {
+        gen_probe_request_per_sta_profile_body((body.as_mut_slice(), 0), profile)?;
+    }
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(PER_STA_PROFILE_ID) >> gen_fragmented(&body, FRAGMENT_SUBELEMENT_ID)
+    )
+}
+
+fn gen_basic_per_sta_profile_body<'a>(
+    cursor: GenCursor<'a>,
+    profile: &PerStaProfile,
+) -> GenResult<'a> {
+    let sta_control = (profile.link_id as u16 & 0x000f)
+        | ((profile.complete_profile as u16) << 4)
+        | ((profile.sta_mac_address.is_some() as u16) << 5)
+        | ((profile.beacon_interval.is_some() as u16) << 6)
+        | ((profile.tsf_offset.is_some() as u16) << 7)
+        | ((profile.dtim_info.is_some() as u16) << 8)
+        | ((profile.nstr_indication_bitmap.is_some() as u16) << 9)
+        | ((matches!(
+            profile.nstr_indication_bitmap,
+            Some(NstrIndicationBitmap::TwoOctets(_))
+        ) as u16)
+            << 10)
+        | ((profile.bss_parameters_change_count.is_some() as u16) << 11);
+
+    do_gen!(
+        cursor,
+        gen_le_u16!(sta_control)
+            >> gen_le_u8!(sta_info_length(profile) as u8)
+            >> gen_cond!(
+                profile.sta_mac_address.is_some(),
+                gen_slice!(&profile.sta_mac_address.unwrap().0)
+            )
+            >> gen_cond!(
+                profile.beacon_interval.is_some(),
+                gen_le_u16!(profile.beacon_interval.unwrap())
+            )
+            >> gen_cond!(
+                profile.tsf_offset.is_some(),
+                gen_le_u64!(profile.tsf_offset.unwrap() as u64)
+            )
+            >> gen_cond!(
+                profile.dtim_info.is_some(),
+                gen_call!(gen_dtim_info, profile.dtim_info.as_ref().unwrap())
+            )
+            >> gen_cond!(
+                profile.nstr_indication_bitmap.is_some(),
+                gen_call!(
+                    gen_nstr_indication_bitmap,
+                    profile.nstr_indication_bitmap.as_ref().unwrap()
+                )
+            )
+            >> gen_cond!(
+                profile.bss_parameters_change_count.is_some(),
+                gen_le_u8!(profile.bss_parameters_change_count.unwrap())
+            )
+            >> gen_slice!(profile.sta_profile.as_slice())
+    )
+}
+
+fn gen_probe_request_per_sta_profile_body<'a>(
+    cursor: GenCursor<'a>,
+    profile: &PerStaProfile,
+) -> GenResult<'a> {
+    let sta_control = (profile.link_id as u16 & 0x000f) | ((profile.complete_profile as u16) << 4);
+
+    do_gen!(
+        cursor,
+        gen_le_u16!(sta_control) >> gen_slice!(profile.sta_profile.as_slice())
+    )
+}
+
+fn gen_dtim_info<'a>(cursor: GenCursor<'a>, dtim_info: &DtimInfo) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u8!(dtim_info.dtim_count) >> gen_le_u8!(dtim_info.dtim_period)
+    )
+}
+
+fn gen_nstr_indication_bitmap<'a>(
+    cursor: GenCursor<'a>,
+    bitmap: &NstrIndicationBitmap,
+) -> GenResult<'a> {
+    match bitmap {
+        NstrIndicationBitmap::OneOctet(bitmap) => gen_le_u8!(cursor, *bitmap),
+        NstrIndicationBitmap::TwoOctets(bitmap) => gen_le_u16!(cursor, *bitmap),
+    }
+}
@@ -0,0 +1,56 @@
+use cookie_factory::{do_gen, gen_call, gen_cond, gen_le_u16, gen_le_u8, gen_many_ref, gen_slice};
+
+use crate::frame::components::{AkmSuite, CipherSuite, RsnInformation};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [RsnInformation] element.
+///
+/// The PMKID list is only written, if there are PMKIDs or a group management cipher.
+/// Everything up to the RSN capabilities is always written.
+pub fn gen_rsn_information<'a>(cursor: GenCursor<'a>, rsn: &RsnInformation) -> GenResult<'a> {
+    let has_pmkids = !rsn.pmkids.is_empty() || rsn.group_management_cipher.is_some();
+
+    do_gen!(
+        cursor,
+        gen_le_u16!(rsn.version)
+            >> gen_cipher_suite(&rsn.group_cipher)
+            >> gen_le_u16!(rsn.pairwise_ciphers.len() as u16)
+            >> gen_many_ref!(&rsn.pairwise_ciphers, gen_cipher_suite)
+            >> gen_le_u16!(rsn.akm_suites.len() as u16)
+            >> gen_many_ref!(&rsn.akm_suites, gen_akm_suite)
+            >> gen_le_u16!(rsn.capabilities.bits())
+            >> gen_cond!(
+                has_pmkids,
+                do_gen!(
+                    gen_le_u16!(rsn.pmkids.len() as u16) >> gen_many_ref!(&rsn.pmkids, gen_pmkid)
+                )
+            )
+            >> gen_cond!(
+                rsn.group_management_cipher.is_some(),
+                gen_call!(
+                    gen_cipher_suite,
+                    rsn.group_management_cipher.as_ref().unwrap()
+                )
+            )
+    )
+}
+
+/// Serialize a 4 byte suite selector.
+pub fn gen_suite_selector<'a>(
+    cursor: GenCursor<'a>,
+    (oui, suite_type): ([u8; 3], u8),
+) -> GenResult<'a> {
+    do_gen!(cursor, gen_slice!(&oui) >> gen_le_u8!(suite_type))
+}
+
+fn gen_cipher_suite<'a>(cursor: GenCursor<'a>, suite: &CipherSuite) -> GenResult<'a> {
+    gen_suite_selector(cursor, suite.selector())
+}
+
+fn gen_akm_suite<'a>(cursor: GenCursor<'a>, suite: &AkmSuite) -> GenResult<'a> {
+    gen_suite_selector(cursor, suite.selector())
+}
+
+fn gen_pmkid<'a>(cursor: GenCursor<'a>, pmkid: &[u8; 16]) -> GenResult<'a> {
+    do_gen!(cursor, gen_slice!(pmkid))
+}
@@ -1,67 +1,45 @@
-use cookie_factory::{do_gen, gen_call, gen_cond, gen_le_u8, gen_many_ref, gen_slice, GenError};
+use cookie_factory::{do_gen, gen_call, gen_le_u8, gen_many_ref, gen_slice};
 
-use crate::frame::components::{ManagementInfoId, StationInfo, SupportedRate};
-use crate::generators::{GenCursor, GenResult, SerializationError};
+use crate::frame::components::{ManagementInfoId, StationInfo};
+use crate::generators::{GenCursor, GenResult};
 
+/// The maximum length of the data of a single element or fragment.
+const MAX_FRAGMENT_LENGTH: usize = 255;
+
+/// Serialize all elements of a [StationInfo] in the order in which they're stored.
+///
+/// Elements with more than 255 bytes of data are split into [ManagementInfoId::Fragment]
+/// elements. This is the only place where element headers are written, the typed element
+/// generators only write the element body.
 pub fn gen_station_info<'a>(cursor: GenCursor<'a>, station_info: &StationInfo) -> GenResult<'a> {
-    do_gen!(
-        cursor,
-        // Generate ssid if present
-        gen_cond!(station_info.ssid.is_some(), gen_call!(gen_ssid, station_info.ssid.as_ref().unwrap()))
-        // Generate supported rates
-        >> gen_supported_rates(&station_info.supported_rates)
-        // Generate all other unparsed data fields
-        >> gen_many_ref!(&station_info.data, gen_unparsed_field)
-    )
+    do_gen!(cursor, gen_many_ref!(&station_info.elements, gen_element))
 }
 
-fn gen_info_element_hdr(
-    cursor: GenCursor<'_>,
-    id: ManagementInfoId,
-    data_len: usize,
-) -> GenResult<'_> {
-    let data_len: u8 = data_len
-        .try_into()
-        .map_err(|_| GenError::CustomError(SerializationError::MgmtElementTooLong.into()))?;
-    do_gen!(
-        cursor,
-        // Generate element id
-        gen_le_u8!(id.into())
-        // Generate element byte len
-        >> gen_le_u8!(data_len)
-    )
-}
+/// Write the length and data of an element or subelement, whose ID has already been written.
+///
+/// Data with more than 255 bytes is split into fragments with the given `fragment_id`.
+pub(crate) fn gen_fragmented<'a>(
+    cursor: GenCursor<'a>,
+    data: &[u8],
+    fragment_id: u8,
+) -> GenResult<'a> {
+    let mut chunks = data.chunks(MAX_FRAGMENT_LENGTH);
+    let first = chunks.next().unwrap_or_default();
+    let mut cursor = do_gen!(cursor, gen_le_u8!(first.len() as u8) >> gen_slice!(first))?;
 
-fn gen_ssid<'a>(cursor: GenCursor<'a>, ssid: &String) -> GenResult<'a> {
-    let ssid_utf8 = ssid.as_bytes();
-    do_gen!(
-        cursor,
-        // Generate ssid element header
-        gen_info_element_hdr(ManagementInfoId::SSID, ssid_utf8.len())
-        // Generate ssid utf8 payload
-        >> gen_slice!(ssid_utf8)
-    )
-}
+    for chunk in chunks {
+        cursor = do_gen!(
+            cursor,
+            gen_le_u8!(fragment_id) >> gen_le_u8!(chunk.len() as u8) >> gen_slice!(chunk)
+        )?;
+    }
 
-fn gen_supported_rates<'a>(cursor: GenCursor<'a>, rates: &Vec<SupportedRate>) -> GenResult<'a> {
-    do_gen!(
-        cursor,
-        // Generate supported rates element header
-        gen_info_element_hdr(ManagementInfoId::SupportedRates, rates.len())
-        // Generate supported rate payload bytes for each rate in the list
-        >> gen_many_ref!(rates, |cursor, rate: &SupportedRate| gen_le_u8!(cursor, rate.0))
-    )
+    Ok(cursor)
 }
 
-fn gen_unparsed_field<'a>(
-    cursor: GenCursor<'a>,
-    field: &(ManagementInfoId, Vec<u8>),
-) -> GenResult<'a> {
+fn gen_element<'a>(cursor: GenCursor<'a>, field: &(ManagementInfoId, Vec<u8>)) -> GenResult<'a> {
     do_gen!(
         cursor,
-        // Generate unparsed element header
-        gen_info_element_hdr(field.0, field.1.len())
-        // Generate the payload
-        >> gen_slice!(field.1.as_slice())
+        gen_le_u8!(field.0.into()) >> gen_fragmented(&field.1, ManagementInfoId::Fragment.into())
     )
 }
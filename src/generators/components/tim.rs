@@ -0,0 +1,17 @@
+use cookie_factory::{do_gen, gen_le_u8, gen_slice};
+
+use crate::frame::components::Tim;
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [Tim] element.
+pub fn gen_tim<'a>(cursor: GenCursor<'a>, tim: &Tim) -> GenResult<'a> {
+    let bitmap_control = (tim.group_addressed_traffic as u8) | (tim.bitmap_offset << 1);
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(tim.dtim_count)
+            >> gen_le_u8!(tim.dtim_period)
+            >> gen_le_u8!(bitmap_control)
+            >> gen_slice!(tim.partial_virtual_bitmap.as_slice())
+    )
+}
@@ -0,0 +1,61 @@
+use cookie_factory::{
+    do_gen, gen_be_u16, gen_cond, gen_le_u16, gen_le_u8, gen_many_ref, gen_slice,
+};
+
+use crate::frame::components::{OweTransitionMode, P2p, P2pAttribute, VendorId, Wps, WpsAttribute};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [Wps] vendor element, including its OUI and vendor type.
+pub fn gen_wps<'a>(cursor: GenCursor<'a>, wps: &Wps) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_slice!(&VendorId::WPS.prefix()) >> gen_many_ref!(&wps.attributes, gen_wps_attribute)
+    )
+}
+
+fn gen_wps_attribute<'a>(cursor: GenCursor<'a>, attribute: &WpsAttribute) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_be_u16!(attribute.attribute_type)
+            >> gen_be_u16!(attribute.data.len() as u16)
+            >> gen_slice!(attribute.data.as_slice())
+    )
+}
+
+/// Serialize the body of a [P2p] vendor element, including its OUI and vendor type.
+pub fn gen_p2p<'a>(cursor: GenCursor<'a>, p2p: &P2p) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_slice!(&VendorId::P2P.prefix()) >> gen_many_ref!(&p2p.attributes, gen_p2p_attribute)
+    )
+}
+
+fn gen_p2p_attribute<'a>(cursor: GenCursor<'a>, attribute: &P2pAttribute) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u8!(attribute.attribute_id)
+            >> gen_le_u16!(attribute.data.len() as u16)
+            >> gen_slice!(attribute.data.as_slice())
+    )
+}
+
+/// Serialize the body of an [OweTransitionMode] vendor element, including its OUI and vendor
+/// type.
+pub fn gen_owe_transition_mode<'a>(
+    cursor: GenCursor<'a>,
+    owe: &OweTransitionMode,
+) -> GenResult<'a> {
+    let (operating_class, channel) = owe.operating_class_and_channel.unwrap_or_default();
+
+    do_gen!(
+        cursor,
+        gen_slice!(&VendorId::OWE_TRANSITION_MODE.prefix())
+            >> gen_slice!(&owe.bssid.0)
+            >> gen_le_u8!(owe.ssid.len() as u8)
+            >> gen_slice!(owe.ssid.as_slice())
+            >> gen_cond!(
+                owe.operating_class_and_channel.is_some(),
+                do_gen!(gen_le_u8!(operating_class) >> gen_le_u8!(channel))
+            )
+    )
+}
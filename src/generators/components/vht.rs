@@ -0,0 +1,33 @@
+use cookie_factory::{do_gen, gen_le_u16, gen_le_u32, gen_le_u8};
+
+use crate::frame::components::{VhtCapabilities, VhtOperation};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [VhtCapabilities] element.
+pub fn gen_vht_capabilities<'a>(cursor: GenCursor<'a>, vht: &VhtCapabilities) -> GenResult<'a> {
+    let mcs_set = &vht.supported_mcs_set;
+    let rx_highest =
+        (mcs_set.rx_highest_data_rate & 0x1fff) | ((mcs_set.max_nsts_total as u16) << 13);
+    let tx_highest =
+        (mcs_set.tx_highest_data_rate & 0x1fff) | ((mcs_set.extended_nss_bw_capable as u16) << 13);
+
+    do_gen!(
+        cursor,
+        gen_le_u32!(vht.vht_capability_info.bits())
+            >> gen_le_u16!(mcs_set.rx_mcs_map.0)
+            >> gen_le_u16!(rx_highest)
+            >> gen_le_u16!(mcs_set.tx_mcs_map.0)
+            >> gen_le_u16!(tx_highest)
+    )
+}
+
+/// Serialize the body of a [VhtOperation] element.
+pub fn gen_vht_operation<'a>(cursor: GenCursor<'a>, vht: &VhtOperation) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u8!(vht.channel_width.into())
+            >> gen_le_u8!(vht.channel_center_frequency_segment_0)
+            >> gen_le_u8!(vht.channel_center_frequency_segment_1)
+            >> gen_le_u16!(vht.basic_mcs_set.0)
+    )
+}
@@ -0,0 +1,44 @@
+use cookie_factory::{do_gen, gen_le_u16, gen_le_u8, gen_many_ref, gen_slice};
+
+use crate::frame::components::{Wmm, WmmAcParameter, MICROSOFT_OUI};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [Wmm] vendor element, including its OUI and vendor type.
+pub fn gen_wmm<'a>(cursor: GenCursor<'a>, wmm: &Wmm) -> GenResult<'a> {
+    let subtype = match wmm {
+        Wmm::Information(_) => 0,
+        Wmm::Parameter(_) => 1,
+    };
+
+    let cursor = do_gen!(
+        cursor,
+        gen_slice!(&MICROSOFT_OUI) >> gen_le_u8!(2) >> gen_le_u8!(subtype)
+    )?;
+
+    match wmm {
+        Wmm::Information(info) => do_gen!(
+            cursor,
+            gen_le_u8!(info.version) >> gen_le_u8!(info.qos_info)
+        ),
+        Wmm::Parameter(parameter) => do_gen!(
+            cursor,
+            gen_le_u8!(parameter.version)
+                >> gen_le_u8!(parameter.qos_info)
+                >> gen_le_u8!(parameter.reserved)
+                >> gen_many_ref!(&parameter.ac_parameters, gen_ac_parameter)
+        ),
+    }
+}
+
+/// Serialize a single AC parameter record, as described in [WmmAcParameter].
+fn gen_ac_parameter<'a>(cursor: GenCursor<'a>, parameter: &WmmAcParameter) -> GenResult<'a> {
+    let aci_aifsn = (parameter.access_category.index() << 5)
+        | ((parameter.admission_control_mandatory as u8) << 4)
+        | (parameter.aifsn & 0x0f);
+    let ecw = ((parameter.ecw_max & 0x0f) << 4) | (parameter.ecw_min & 0x0f);
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(aci_aifsn) >> gen_le_u8!(ecw) >> gen_le_u16!(parameter.txop_limit)
+    )
+}
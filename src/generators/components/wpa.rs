@@ -0,0 +1,41 @@
+use cookie_factory::{do_gen, gen_call, gen_cond, gen_le_u16, gen_le_u8, gen_many_ref, gen_slice};
+
+use super::gen_suite_selector;
+use crate::frame::components::{AkmSuite, CipherSuite, WpaInformation, IEEE_OUI, MICROSOFT_OUI};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize the body of a [WpaInformation] vendor element, including its OUI and vendor type.
+pub fn gen_wpa_information<'a>(cursor: GenCursor<'a>, wpa: &WpaInformation) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_slice!(&MICROSOFT_OUI)
+            >> gen_le_u8!(1)
+            >> gen_le_u16!(wpa.version)
+            >> gen_wpa_cipher_suite(&wpa.group_cipher)
+            >> gen_le_u16!(wpa.pairwise_ciphers.len() as u16)
+            >> gen_many_ref!(&wpa.pairwise_ciphers, gen_wpa_cipher_suite)
+            >> gen_le_u16!(wpa.akm_suites.len() as u16)
+            >> gen_many_ref!(&wpa.akm_suites, gen_wpa_akm_suite)
+            >> gen_cond!(
+                wpa.capabilities.is_some(),
+                gen_le_u16!(wpa.capabilities.unwrap().bits())
+            )
+    )
+}
+
+/// WPA uses the Microsoft OUI instead of the IEEE one for its suites.
+fn wpa_selector((oui, suite_type): ([u8; 3], u8)) -> ([u8; 3], u8) {
+    if oui == IEEE_OUI {
+        (MICROSOFT_OUI, suite_type)
+    } else {
+        (oui, suite_type)
+    }
+}
+
+fn gen_wpa_cipher_suite<'a>(cursor: GenCursor<'a>, suite: &CipherSuite) -> GenResult<'a> {
+    gen_suite_selector(cursor, wpa_selector(suite.selector()))
+}
+
+fn gen_wpa_akm_suite<'a>(cursor: GenCursor<'a>, suite: &AkmSuite) -> GenResult<'a> {
+    gen_suite_selector(cursor, wpa_selector(suite.selector()))
+}
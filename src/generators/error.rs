@@ -4,6 +4,7 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 #[derive(IntoPrimitive, TryFromPrimitive, Debug, thiserror::Error)]
 #[repr(u32)]
 pub enum SerializationError {
+    /// Not returned anymore, long elements are split into Fragment elements.
     #[error("Management Frame Info Element length exceeds length limit (255 bytes)!")]
     MgmtElementTooLong = 0,
     #[error("Serialization of frame type 4 unsupported!")]
@@ -14,6 +15,14 @@ pub enum SerializationError {
     FCtrlReservedSubtype,
     #[error("Can't serialize unhandled frame subtype!")]
     FCtrlUnhandledSubtype,
+    #[error("Invalid number of TIDs for the BlockAck mode!")]
+    BlockAckInvalidTidCount,
+    #[error("BlockAck info doesn't match the BlockAck mode!")]
+    BlockAckModeMismatch,
+    #[error("Data header has to_ds and from_ds set, but no forth address!")]
+    DataHeaderMissingAddress4,
+    #[error("Data header of a QoS frame subtype has no QoS control field!")]
+    DataHeaderMissingQos,
 }
 
 impl From<SerializationError> for GenError {
@@ -0,0 +1,105 @@
+use cookie_factory::{do_gen, gen_call, gen_le_u16, gen_le_u8, gen_slice};
+
+use crate::frame::*;
+use crate::generators::components::{gen_mgmt_header, gen_sequence_ctrl, gen_station_info};
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize an [Action] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Category
+/// - Category specific body
+pub fn gen_action<'a>(cursor: GenCursor<'a>, frame: &Action) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_category(frame.category)
+            >> gen_action_body(&frame.body)
+    )
+}
+
+/// Serialize an [ActionNoAck] frame.
+///
+/// The structure is the same as the one of an [Action] frame.
+pub fn gen_action_no_ack<'a>(cursor: GenCursor<'a>, frame: &ActionNoAck) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_category(frame.category)
+            >> gen_action_body(&frame.body)
+    )
+}
+
+/// Serialize the category, which is omitted for protected frames.
+fn gen_category(cursor: GenCursor<'_>, category: Option<ActionCategory>) -> GenResult<'_> {
+    match category {
+        Some(category) => gen_le_u8!(cursor, category.into()),
+        None => Ok(cursor),
+    }
+}
+
+/// Serialize the category specific body of an action frame.
+fn gen_action_body<'a>(cursor: GenCursor<'a>, body: &ActionBody) -> GenResult<'a> {
+    match body {
+        ActionBody::BlockAck(action) => gen_block_ack_action(cursor, action),
+        ActionBody::SaQuery(action) => gen_sa_query(cursor, action),
+        ActionBody::VendorSpecific(action) => do_gen!(
+            cursor,
+            gen_slice!(&action.oui) >> gen_slice!(action.data.as_slice())
+        ),
+        ActionBody::Unhandled(data) | ActionBody::Protected(data) => {
+            gen_slice!(cursor, data.as_slice())
+        }
+    }
+}
+
+/// Serialize a BlockAck action body, including its action code.
+fn gen_block_ack_action<'a>(cursor: GenCursor<'a>, action: &BlockAckAction) -> GenResult<'a> {
+    match action {
+        BlockAckAction::AddBaRequest(request) => do_gen!(
+            cursor,
+            gen_le_u8!(0)
+                >> gen_le_u8!(request.dialog_token)
+                >> gen_le_u16!(request.parameters.bits())
+                >> gen_le_u16!(request.timeout)
+                >> gen_sequence_ctrl(&request.starting_sequence_control)
+                >> gen_station_info(&request.station_info)
+        ),
+        BlockAckAction::AddBaResponse(response) => do_gen!(
+            cursor,
+            gen_le_u8!(1)
+                >> gen_le_u8!(response.dialog_token)
+                >> gen_le_u16!(response.status_code.into())
+                >> gen_le_u16!(response.parameters.bits())
+                >> gen_le_u16!(response.timeout)
+                >> gen_station_info(&response.station_info)
+        ),
+        BlockAckAction::DelBa(delba) => do_gen!(
+            cursor,
+            gen_le_u8!(2)
+                >> gen_le_u16!(delba.parameters)
+                >> gen_le_u16!(delba.reason_code.into())
+                >> gen_station_info(&delba.station_info)
+        ),
+    }
+}
+
+/// Serialize a SA Query action body.
+fn gen_sa_query<'a>(cursor: GenCursor<'a>, action: &SaQueryAction) -> GenResult<'a> {
+    let (action_code, transaction_id, station_info) = match action {
+        SaQueryAction::Request {
+            transaction_id,
+            station_info,
+        } => (0, transaction_id, station_info),
+        SaQueryAction::Response {
+            transaction_id,
+            station_info,
+        } => (1, transaction_id, station_info),
+    };
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(action_code) >> gen_slice!(transaction_id) >> gen_station_info(station_info)
+    )
+}
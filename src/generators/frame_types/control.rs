@@ -0,0 +1,209 @@
+use cookie_factory::{do_gen, gen_call, gen_le_u16, gen_le_u64, gen_many_ref, gen_slice, GenError};
+
+use crate::frame::components::SequenceControl;
+use crate::frame::*;
+use crate::generators::components::{gen_frame_control, gen_sequence_ctrl};
+use crate::generators::{GenCursor, GenResult, SerializationError};
+
+/// Serialize a [Rts] frame.
+///
+/// The general structure is:
+/// - FrameControl
+/// - Duration
+/// - Destination
+/// - Source
+pub fn gen_rts<'a>(cursor: GenCursor<'a>, frame: &Rts) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_frame_control(&frame.frame_control)
+            >> gen_slice!(&frame.duration)
+            >> gen_slice!(&frame.destination.0)
+            >> gen_slice!(&frame.source.0)
+    )
+}
+
+/// Serialize a [Cts] frame.
+///
+/// The general structure is:
+/// - FrameControl
+/// - Duration
+/// - Destination
+pub fn gen_cts<'a>(cursor: GenCursor<'a>, frame: &Cts) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_frame_control(&frame.frame_control)
+            >> gen_slice!(&frame.duration)
+            >> gen_slice!(&frame.destination.0)
+    )
+}
+
+/// Serialize an [Ack] frame.
+///
+/// The general structure is:
+/// - FrameControl
+/// - Duration
+/// - Destination
+pub fn gen_ack<'a>(cursor: GenCursor<'a>, frame: &Ack) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_frame_control(&frame.frame_control)
+            >> gen_slice!(&frame.duration)
+            >> gen_slice!(&frame.destination.0)
+    )
+}
+
+/// Serialize a [BlockAckRequest] frame.
+///
+/// The general structure is:
+/// - FrameControl
+/// - Duration
+/// - Destination
+/// - Source
+/// - BlockAckRequest control
+/// - BlockAckRequest information
+///
+/// In Multi-TID mode, the information field contains a Per-TID info and a starting sequence
+/// control for each requested TID.
+/// In all other modes, it only contains the starting sequence control of the single TID.
+pub fn gen_block_ack_request<'a>(cursor: GenCursor<'a>, frame: &BlockAckRequest) -> GenResult<'a> {
+    let tids = &frame.requested_tids;
+    let tid_info = match frame.mode {
+        BlockAckMode::MultiTidBlockAck => multi_tid_info(tids.len())?,
+        BlockAckMode::BasicBlockAck | BlockAckMode::CompressedBlockAck => {
+            if tids.len() != 1 {
+                return Err(SerializationError::BlockAckInvalidTidCount.into());
+            }
+            tids[0].0
+        }
+    };
+    let control = block_ack_control(frame.policy, &frame.mode, tid_info);
+
+    let cursor = do_gen!(
+        cursor,
+        gen_frame_control(&frame.frame_control)
+            >> gen_slice!(&frame.duration)
+            >> gen_slice!(&frame.destination.0)
+            >> gen_slice!(&frame.source.0)
+            >> gen_le_u16!(control)
+    )?;
+
+    match frame.mode {
+        BlockAckMode::MultiTidBlockAck => do_gen!(cursor, gen_many_ref!(tids, gen_requested_tid)),
+        BlockAckMode::BasicBlockAck | BlockAckMode::CompressedBlockAck => {
+            gen_sequence_ctrl(cursor, &tids[0].1)
+        }
+    }
+}
+
+/// Serialize a [BlockAck] frame.
+///
+/// The general structure is:
+/// - FrameControl
+/// - Duration
+/// - Destination
+/// - Source
+/// - BlockAck control
+/// - BlockAck information
+///
+/// The information field depends on the [BlockAckMode]:
+/// - Basic: Starting sequence control and a 128 byte bitmap.
+/// - Compressed: Starting sequence control and a 8 byte bitmap.
+/// - Multi-TID: Per-TID info, starting sequence control and a 8 byte bitmap for each TID.
+pub fn gen_block_ack<'a>(cursor: GenCursor<'a>, frame: &BlockAck) -> GenResult<'a> {
+    let tid_info = match (&frame.mode, &frame.acks) {
+        (BlockAckMode::BasicBlockAck, BlockAckInfo::Basic((tid, _, _))) => *tid,
+        (BlockAckMode::CompressedBlockAck, BlockAckInfo::Compressed(acks)) => {
+            if acks.len() != 1 {
+                return Err(SerializationError::BlockAckInvalidTidCount.into());
+            }
+            acks[0].0
+        }
+        (BlockAckMode::MultiTidBlockAck, BlockAckInfo::Compressed(acks)) => {
+            multi_tid_info(acks.len())?
+        }
+        _ => return Err(SerializationError::BlockAckModeMismatch.into()),
+    };
+    let control = block_ack_control(frame.policy, &frame.mode, tid_info);
+
+    let cursor = do_gen!(
+        cursor,
+        gen_frame_control(&frame.frame_control)
+            >> gen_slice!(&frame.duration)
+            >> gen_slice!(&frame.destination.0)
+            >> gen_slice!(&frame.source.0)
+            >> gen_le_u16!(control)
+    )?;
+
+    match (&frame.mode, &frame.acks) {
+        (BlockAckMode::MultiTidBlockAck, BlockAckInfo::Compressed(acks)) => {
+            do_gen!(cursor, gen_many_ref!(acks, gen_acked_tid))
+        }
+        (_, BlockAckInfo::Compressed(acks)) => do_gen!(
+            cursor,
+            gen_sequence_ctrl(&acks[0].1) >> gen_le_u64!(acks[0].2)
+        ),
+        (_, BlockAckInfo::Basic((_, sequence_control, bitmap))) => do_gen!(
+            cursor,
+            gen_sequence_ctrl(sequence_control) >> gen_slice!(bitmap)
+        ),
+    }
+}
+
+/// Serialize a single Multi-TID BlockAckRequest entry (Per-TID info and starting sequence control).
+fn gen_requested_tid<'a>(
+    cursor: GenCursor<'a>,
+    (tid, sequence_control): &(u8, SequenceControl),
+) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u16!(per_tid_info(*tid)) >> gen_sequence_ctrl(sequence_control)
+    )
+}
+
+/// Serialize a single Multi-TID BlockAck entry (Per-TID info, starting sequence control and bitmap).
+fn gen_acked_tid<'a>(
+    cursor: GenCursor<'a>,
+    (tid, sequence_control, bitmap): &(u8, SequenceControl, u64),
+) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_le_u16!(per_tid_info(*tid))
+            >> gen_sequence_ctrl(sequence_control)
+            >> gen_le_u64!(*bitmap)
+    )
+}
+
+/// Build the little-endian BlockAck(Request) control field.
+///
+/// - **bit_0**: Ack policy
+/// - **bit_1**: Multi-TID
+/// - **bit_2**: Compressed bitmap
+/// - **bit_3-11**: Reserved
+/// - **bit_12-15**: TID_INFO
+fn block_ack_control(policy: bool, mode: &BlockAckMode, tid_info: u8) -> u16 {
+    let (multi_tid, compressed_bitmap) = match mode {
+        BlockAckMode::BasicBlockAck => (false, false),
+        BlockAckMode::CompressedBlockAck => (false, true),
+        BlockAckMode::MultiTidBlockAck => (true, true),
+    };
+
+    (policy as u16)
+        | ((multi_tid as u16) << 1)
+        | ((compressed_bitmap as u16) << 2)
+        | (((tid_info & 0x0f) as u16) << 12)
+}
+
+/// In Multi-TID mode, TID_INFO contains the number of TIDs **- 1**.
+/// Since it's a 4 bit field, there can be between 1 and 16 TIDs.
+fn multi_tid_info(tid_count: usize) -> Result<u8, GenError> {
+    if tid_count == 0 || tid_count > 16 {
+        return Err(SerializationError::BlockAckInvalidTidCount.into());
+    }
+
+    Ok((tid_count - 1) as u8)
+}
+
+/// The Per-TID info consists of 12 reserved bits and the 4 bit TID in the upper bits.
+fn per_tid_info(tid: u8) -> u16 {
+    ((tid & 0x0f) as u16) << 12
+}
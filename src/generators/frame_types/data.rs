@@ -0,0 +1,31 @@
+use cookie_factory::{do_gen, gen_call, gen_slice};
+
+use crate::frame::*;
+use crate::generators::components::gen_data_header;
+use crate::generators::{GenCursor, GenResult};
+
+/// Serialize a [Data] frame.
+pub fn gen_data<'a>(cursor: GenCursor<'a>, frame: &Data) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_data_header(&frame.header) >> gen_slice!(frame.data.as_slice())
+    )
+}
+
+/// Serialize a [NullData] frame.
+pub fn gen_null_data<'a>(cursor: GenCursor<'a>, frame: &NullData) -> GenResult<'a> {
+    gen_data_header(cursor, &frame.header)
+}
+
+/// Serialize a [QosData] frame.
+pub fn gen_qos_data<'a>(cursor: GenCursor<'a>, frame: &QosData) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_data_header(&frame.header) >> gen_slice!(frame.data.as_slice())
+    )
+}
+
+/// Serialize a [QosNull] frame.
+pub fn gen_qos_null<'a>(cursor: GenCursor<'a>, frame: &QosNull) -> GenResult<'a> {
+    gen_data_header(cursor, &frame.header)
+}
@@ -1,11 +1,103 @@
-use cookie_factory::{do_gen, gen_call, gen_le_u16, gen_le_u64};
+use cookie_factory::{do_gen, gen_call, gen_le_u16, gen_le_u64, gen_slice};
 
+use crate::frame::*;
 use crate::generators::components::{gen_mgmt_header, gen_station_info};
-use crate::{
-    frame::Beacon,
-    generators::{GenCursor, GenResult},
-};
+use crate::generators::{GenCursor, GenResult};
 
+/// Serialize an [AssociationRequest] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Capability info
+/// - Listen interval
+/// - Dynamic fields
+pub fn gen_association_request<'a>(
+    cursor: GenCursor<'a>,
+    frame: &AssociationRequest,
+) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_le_u16!(frame.capability_info)
+            >> gen_le_u16!(frame.listen_interval)
+            >> gen_station_info(&frame.station_info)
+    )
+}
+
+/// Serialize an [AssociationResponse] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Capability info
+/// - Status code
+/// - Association id
+/// - Dynamic fields
+pub fn gen_association_response<'a>(
+    cursor: GenCursor<'a>,
+    frame: &AssociationResponse,
+) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_le_u16!(frame.capability_info)
+            >> gen_le_u16!(frame.status_code.into())
+            >> gen_le_u16!(frame.association_id)
+            >> gen_station_info(&frame.station_info)
+    )
+}
+
+/// Serialize a [ReassociationRequest] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Capability info
+/// - Listen interval
+/// - Current AP address
+/// - Dynamic fields
+pub fn gen_reassociation_request<'a>(
+    cursor: GenCursor<'a>,
+    frame: &ReassociationRequest,
+) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_le_u16!(frame.capability_info)
+            >> gen_le_u16!(frame.listen_interval)
+            >> gen_slice!(&frame.current_ap_address.0)
+            >> gen_station_info(&frame.station_info)
+    )
+}
+
+/// Serialize a [ReassociationResponse] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Capability info
+/// - Status code
+/// - Association id
+/// - Dynamic fields
+pub fn gen_reassociation_response<'a>(
+    cursor: GenCursor<'a>,
+    frame: &ReassociationResponse,
+) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_le_u16!(frame.capability_info)
+            >> gen_le_u16!(frame.status_code.into())
+            >> gen_le_u16!(frame.association_id)
+            >> gen_station_info(&frame.station_info)
+    )
+}
+
+/// Serialize a [Beacon] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Timestamp
+/// - Beacon interval
+/// - Capability info
+/// - Dynamic fields
 pub fn gen_beacon<'a>(cursor: GenCursor<'a>, frame: &Beacon) -> GenResult<'a> {
     do_gen!(
         cursor,
@@ -16,3 +108,103 @@ pub fn gen_beacon<'a>(cursor: GenCursor<'a>, frame: &Beacon) -> GenResult<'a> {
             >> gen_station_info(&frame.station_info)
     )
 }
+
+/// Serialize a [ProbeRequest] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Dynamic fields
+pub fn gen_probe_request<'a>(cursor: GenCursor<'a>, frame: &ProbeRequest) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header) >> gen_station_info(&frame.station_info)
+    )
+}
+
+/// Serialize a [ProbeResponse] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Timestamp
+/// - Beacon interval
+/// - Capability info
+/// - Dynamic fields
+pub fn gen_probe_response<'a>(cursor: GenCursor<'a>, frame: &ProbeResponse) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_le_u64!(frame.timestamp)
+            >> gen_le_u16!(frame.beacon_interval)
+            >> gen_le_u16!(frame.capability_info)
+            >> gen_station_info(&frame.station_info)
+    )
+}
+
+/// Serialize an [Authentication] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Authentication algorithm number
+/// - Authentication transaction sequence number
+/// - Status code
+/// - Algorithm specific data (e.g. SAE)
+/// - Dynamic fields
+pub fn gen_authentication<'a>(cursor: GenCursor<'a>, frame: &Authentication) -> GenResult<'a> {
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_le_u16!(frame.auth_algorithm.into())
+            >> gen_le_u16!(frame.auth_seq)
+            >> gen_le_u16!(frame.status_code.into())
+            >> gen_slice!(frame.algorithm_data.as_slice())
+            >> gen_station_info(&frame.station_info)
+    )
+}
+
+/// Serialize a [Deauthentication] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Reason code
+/// - Dynamic fields
+///
+/// The body of protected frames is written as is.
+pub fn gen_deauthentication<'a>(cursor: GenCursor<'a>, frame: &Deauthentication) -> GenResult<'a> {
+    if let Some(body) = &frame.protected_body {
+        return do_gen!(
+            cursor,
+            gen_mgmt_header(&frame.header) >> gen_slice!(body.as_slice())
+        );
+    }
+
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_le_u16!(frame.reason_code.into())
+            >> gen_station_info(&frame.station_info)
+    )
+}
+
+/// Serialize a [Disassociation] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Reason code
+/// - Dynamic fields
+///
+/// The body of protected frames is written as is.
+pub fn gen_disassociation<'a>(cursor: GenCursor<'a>, frame: &Disassociation) -> GenResult<'a> {
+    if let Some(body) = &frame.protected_body {
+        return do_gen!(
+            cursor,
+            gen_mgmt_header(&frame.header) >> gen_slice!(body.as_slice())
+        );
+    }
+
+    do_gen!(
+        cursor,
+        gen_mgmt_header(&frame.header)
+            >> gen_le_u16!(frame.reason_code.into())
+            >> gen_station_info(&frame.station_info)
+    )
+}
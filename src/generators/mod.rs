@@ -1,10 +1,20 @@
 mod components;
 mod error;
 mod frame_types;
+mod pcap;
+mod radiotap;
 
+pub(crate) use components::{
+    gen_country, gen_ds_parameter_set, gen_eht_capabilities, gen_eht_operation,
+    gen_erp_information, gen_he_capabilities, gen_he_operation, gen_ht_capabilities,
+    gen_ht_operation, gen_multi_link, gen_owe_transition_mode, gen_p2p, gen_rsn_information,
+    gen_tim, gen_vht_capabilities, gen_vht_operation, gen_wmm, gen_wpa_information, gen_wps,
+};
 use cookie_factory::GenError;
 pub(crate) use error::SerializationError;
 pub use frame_types::*;
+pub(crate) use pcap::*;
+pub(crate) use radiotap::gen_radiotap;
 
 pub type GenCursor<'a> = (&'a mut [u8], usize);
 pub type GenResult<'a> = Result<GenCursor<'a>, GenError>;
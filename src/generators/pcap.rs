@@ -0,0 +1,190 @@
+use std::time::Duration;
+
+use cookie_factory::{do_gen, gen_call, gen_le_u16, gen_le_u32, gen_le_u64, gen_slice};
+
+use crate::generators::{GenCursor, GenResult};
+use crate::pcap::*;
+
+/// The length of the block type, the leading and the trailing block length of a pcapng block.
+const BLOCK_FRAME_LENGTH: usize = 12;
+pub(crate) const PCAP_HEADER_LENGTH: usize = 24;
+pub(crate) const PCAP_RECORD_HEADER_LENGTH: usize = 16;
+pub(crate) const SECTION_HEADER_LENGTH: usize = BLOCK_FRAME_LENGTH + 16;
+
+/// The options of a pcapng block as code and value.
+pub(crate) type PcapNgOptions = Vec<(u16, Vec<u8>)>;
+
+/// The number of padding bytes, which are needed to align data to 32 bits.
+fn padding(length: usize) -> usize {
+    length.next_multiple_of(4) - length
+}
+
+/// Serialize the header of a classic pcap file in little-endian byte order.
+///
+/// The magic number depends on the timestamp resolution, which has to be either
+/// microseconds or nanoseconds. The FCS length is stored in the upper bits of the link type.
+pub fn gen_pcap_header<'a>(cursor: GenCursor<'a>, interface: &Interface) -> GenResult<'a> {
+    let magic = if interface.timestamp_resolution == TimestampResolution::NANOSECONDS {
+        PCAP_MAGIC_NANOSECONDS
+    } else {
+        PCAP_MAGIC_MICROSECONDS
+    };
+    let fcs_information = interface
+        .fcs_length
+        .map_or(0, |length| 0x0400_0000 | (u32::from(length / 2) << 28));
+    let link_type = u32::from(u16::from(interface.link_type)) | fcs_information;
+
+    do_gen!(
+        cursor,
+        gen_le_u32!(magic)
+            >> gen_le_u16!(2_u16)
+            >> gen_le_u16!(4_u16)
+            >> gen_le_u32!(0_u32)
+            >> gen_le_u32!(0_u32)
+            >> gen_le_u32!(interface.snap_length)
+            >> gen_le_u32!(link_type)
+    )
+}
+
+/// Serialize a packet record of a classic pcap file.
+pub fn gen_pcap_record<'a>(
+    cursor: GenCursor<'a>,
+    timestamp: Duration,
+    timestamp_resolution: TimestampResolution,
+    original_length: u32,
+    data: &[u8],
+) -> GenResult<'a> {
+    let fraction =
+        timestamp_resolution.to_ticks(Duration::from_nanos(u64::from(timestamp.subsec_nanos())));
+
+    do_gen!(
+        cursor,
+        gen_le_u32!(timestamp.as_secs() as u32)
+            >> gen_le_u32!(fraction as u32)
+            >> gen_le_u32!(data.len() as u32)
+            >> gen_le_u32!(original_length)
+            >> gen_slice!(data)
+    )
+}
+
+/// The length of the given options, including the `opt_endofopt` option.
+pub(crate) fn pcapng_options_length(options: &PcapNgOptions) -> usize {
+    if options.is_empty() {
+        return 0;
+    }
+
+    options
+        .iter()
+        .map(|(_, value)| 4 + value.len() + padding(value.len()))
+        .sum::<usize>()
+        + 4
+}
+
+/// Serialize the options of a pcapng block, followed by the `opt_endofopt` option.
+fn gen_pcapng_options<'a>(mut cursor: GenCursor<'a>, options: &PcapNgOptions) -> GenResult<'a> {
+    for (code, value) in options {
+        cursor = do_gen!(
+            cursor,
+            gen_le_u16!(*code)
+                >> gen_le_u16!(value.len() as u16)
+                >> gen_slice!(value.as_slice())
+                >> gen_slice!(&[0; 3][..padding(value.len())])
+        )?;
+    }
+    if options.is_empty() {
+        return Ok(cursor);
+    }
+
+    do_gen!(cursor, gen_le_u16!(OPTION_END) >> gen_le_u16!(0_u16))
+}
+
+/// Serialize a little-endian pcapng section header block without options.
+/// The length of the section isn't known, as it's written on the fly.
+pub fn gen_section_header(cursor: GenCursor<'_>) -> GenResult<'_> {
+    do_gen!(
+        cursor,
+        gen_le_u32!(PCAPNG_SECTION_HEADER)
+            >> gen_le_u32!(SECTION_HEADER_LENGTH as u32)
+            >> gen_le_u32!(PCAPNG_BYTE_ORDER_MAGIC)
+            >> gen_le_u16!(1_u16)
+            >> gen_le_u16!(0_u16)
+            >> gen_le_u64!(u64::MAX)
+            >> gen_le_u32!(SECTION_HEADER_LENGTH as u32)
+    )
+}
+
+/// The options of an interface description block.
+/// The timestamp resolution is only written, if it isn't the default resolution.
+/// The `if_fcslen` option contains the FCS length in bits.
+pub(crate) fn interface_options(interface: &Interface) -> PcapNgOptions {
+    let mut options = Vec::new();
+    if interface.timestamp_resolution != TimestampResolution::default() {
+        options.push((
+            OPTION_IF_TSRESOL,
+            vec![interface.timestamp_resolution.to_option()],
+        ));
+    }
+    if let Some(length) = interface.fcs_length {
+        options.push((OPTION_IF_FCSLEN, vec![length.saturating_mul(8)]));
+    }
+
+    options
+}
+
+pub(crate) fn interface_description_length(interface: &Interface) -> usize {
+    BLOCK_FRAME_LENGTH + 8 + pcapng_options_length(&interface_options(interface))
+}
+
+/// Serialize a pcapng interface description block.
+///
+/// The structure is described in [Interface].
+pub fn gen_interface_description<'a>(
+    cursor: GenCursor<'a>,
+    interface: &Interface,
+) -> GenResult<'a> {
+    let length = interface_description_length(interface) as u32;
+
+    do_gen!(
+        cursor,
+        gen_le_u32!(BLOCK_INTERFACE_DESCRIPTION)
+            >> gen_le_u32!(length)
+            >> gen_le_u16!(u16::from(interface.link_type))
+            >> gen_le_u16!(0_u16)
+            >> gen_le_u32!(interface.snap_length)
+            >> gen_call!(gen_pcapng_options, &interface_options(interface))
+            >> gen_le_u32!(length)
+    )
+}
+
+pub(crate) fn enhanced_packet_length(data_length: usize, options: &PcapNgOptions) -> usize {
+    BLOCK_FRAME_LENGTH + 20 + data_length + padding(data_length) + pcapng_options_length(options)
+}
+
+/// Serialize a pcapng enhanced packet block.
+///
+/// The timestamp is given in units of the resolution of the interface.
+pub fn gen_enhanced_packet<'a>(
+    cursor: GenCursor<'a>,
+    interface_id: u32,
+    timestamp: u64,
+    original_length: u32,
+    data: &[u8],
+    options: &PcapNgOptions,
+) -> GenResult<'a> {
+    let length = enhanced_packet_length(data.len(), options) as u32;
+
+    do_gen!(
+        cursor,
+        gen_le_u32!(BLOCK_ENHANCED_PACKET)
+            >> gen_le_u32!(length)
+            >> gen_le_u32!(interface_id)
+            >> gen_le_u32!((timestamp >> 32) as u32)
+            >> gen_le_u32!(timestamp as u32)
+            >> gen_le_u32!(data.len() as u32)
+            >> gen_le_u32!(original_length)
+            >> gen_slice!(data)
+            >> gen_slice!(&[0; 3][..padding(data.len())])
+            >> gen_call!(gen_pcapng_options, options)
+            >> gen_le_u32!(length)
+    )
+}
@@ -0,0 +1,163 @@
+use cookie_factory::{do_gen, gen_le_u16, gen_le_u32, gen_le_u8, gen_slice};
+
+use crate::generators::{GenCursor, GenResult};
+use crate::radiotap::*;
+
+/// Serialize a [Radiotap] header.
+///
+/// All typed fields that are set are written in the order of their presence bits, each aligned
+/// relative to the start of the header. The presence bitmap and the header length are
+/// calculated from these fields, [Radiotap::present] and [Radiotap::length] are ignored.
+///
+/// Only a single presence bitmap of the default radiotap namespace is written, which means
+/// that [Radiotap::antennas] and [Radiotap::vendor_namespaces] aren't serialized.
+pub fn gen_radiotap<'a>(cursor: GenCursor<'a>, radiotap: &Radiotap) -> GenResult<'a> {
+    let mut fields = RadiotapFields::default();
+
+    if let Some(tsft) = radiotap.tsft {
+        fields.push(FIELD_TSFT, &tsft.to_le_bytes());
+    }
+    if let Some(flags) = radiotap.flags {
+        fields.push(FIELD_FLAGS, &[flags.bits()]);
+    }
+    if let Some(rate) = radiotap.rate {
+        fields.push(FIELD_RATE, &[rate]);
+    }
+    if let Some(channel) = radiotap.channel {
+        fields.push(
+            FIELD_CHANNEL,
+            &[
+                channel.frequency.to_le_bytes(),
+                channel.flags.bits().to_le_bytes(),
+            ]
+            .concat(),
+        );
+    }
+    if let Some(signal) = radiotap.antenna_signal {
+        fields.push(FIELD_ANTENNA_SIGNAL, &signal.to_le_bytes());
+    }
+    if let Some(noise) = radiotap.antenna_noise {
+        fields.push(FIELD_ANTENNA_NOISE, &noise.to_le_bytes());
+    }
+    if let Some(lock_quality) = radiotap.lock_quality {
+        fields.push(FIELD_LOCK_QUALITY, &lock_quality.to_le_bytes());
+    }
+    if let Some(attenuation) = radiotap.tx_attenuation {
+        fields.push(FIELD_TX_ATTENUATION, &attenuation.to_le_bytes());
+    }
+    if let Some(attenuation) = radiotap.db_tx_attenuation {
+        fields.push(FIELD_DB_TX_ATTENUATION, &attenuation.to_le_bytes());
+    }
+    if let Some(power) = radiotap.dbm_tx_power {
+        fields.push(FIELD_DBM_TX_POWER, &power.to_le_bytes());
+    }
+    if let Some(antenna) = radiotap.antenna {
+        fields.push(FIELD_ANTENNA, &[antenna]);
+    }
+    if let Some(signal) = radiotap.db_antenna_signal {
+        fields.push(FIELD_DB_ANTENNA_SIGNAL, &[signal]);
+    }
+    if let Some(noise) = radiotap.db_antenna_noise {
+        fields.push(FIELD_DB_ANTENNA_NOISE, &[noise]);
+    }
+    if let Some(flags) = radiotap.rx_flags {
+        fields.push(FIELD_RX_FLAGS, &flags.bits().to_le_bytes());
+    }
+    if let Some(flags) = radiotap.tx_flags {
+        fields.push(FIELD_TX_FLAGS, &flags.bits().to_le_bytes());
+    }
+    if let Some(retries) = radiotap.rts_retries {
+        fields.push(FIELD_RTS_RETRIES, &[retries]);
+    }
+    if let Some(retries) = radiotap.data_retries {
+        fields.push(FIELD_DATA_RETRIES, &[retries]);
+    }
+    if let Some(xchannel) = radiotap.xchannel {
+        fields.push(
+            FIELD_XCHANNEL,
+            &[
+                &xchannel.flags.to_le_bytes()[..],
+                &xchannel.frequency.to_le_bytes(),
+                &[xchannel.channel, xchannel.max_power],
+            ]
+            .concat(),
+        );
+    }
+    if let Some(mcs) = radiotap.mcs {
+        fields.push(FIELD_MCS, &[mcs.known.bits(), mcs.flags, mcs.index]);
+    }
+    if let Some(status) = radiotap.ampdu_status {
+        fields.push(
+            FIELD_AMPDU_STATUS,
+            &[
+                &status.reference.to_le_bytes()[..],
+                &status.flags.bits().to_le_bytes(),
+                &[status.delimiter_crc, 0],
+            ]
+            .concat(),
+        );
+    }
+    if let Some(vht) = radiotap.vht {
+        fields.push(
+            FIELD_VHT,
+            &[
+                &vht.known.bits().to_le_bytes()[..],
+                &[vht.flags.bits(), vht.bandwidth],
+                &vht.mcs_nss,
+                &[vht.coding, vht.group_id],
+                &vht.partial_aid.to_le_bytes(),
+            ]
+            .concat(),
+        );
+    }
+    if let Some(timestamp) = radiotap.timestamp {
+        fields.push(
+            FIELD_TIMESTAMP,
+            &[
+                &timestamp.timestamp.to_le_bytes()[..],
+                &timestamp.accuracy.to_le_bytes(),
+                &[timestamp.unit_position, timestamp.flags],
+            ]
+            .concat(),
+        );
+    }
+    if let Some(he) = radiotap.he {
+        let data: Vec<u8> = he.data.iter().flat_map(|word| word.to_le_bytes()).collect();
+        fields.push(FIELD_HE, &data);
+    }
+
+    do_gen!(
+        cursor,
+        gen_le_u8!(0)
+            >> gen_le_u8!(0)
+            >> gen_le_u16!(fields.header_length() as u16)
+            >> gen_le_u32!(fields.present)
+            >> gen_slice!(fields.data.as_slice())
+    )
+}
+
+/// The presence bitmap and the aligned data of all fields of a radiotap header.
+#[derive(Default)]
+struct RadiotapFields {
+    present: u32,
+    data: Vec<u8>,
+}
+
+impl RadiotapFields {
+    /// The length of the header before the field data, with a single presence bitmap.
+    const DATA_OFFSET: usize = FIXED_HEADER_LENGTH + 4;
+
+    /// Add a field. Fields have to be added in the order of their presence bits.
+    fn push(&mut self, field: u32, value: &[u8]) {
+        let (align, _) = field_layout(field).expect("Only known fields are serialized");
+        let padded_length =
+            (Self::DATA_OFFSET + self.data.len()).next_multiple_of(align) - Self::DATA_OFFSET;
+        self.data.resize(padded_length, 0);
+        self.data.extend_from_slice(value);
+        self.present |= 1 << field;
+    }
+
+    fn header_length(&self) -> usize {
+        Self::DATA_OFFSET + self.data.len()
+    }
+}
@@ -8,22 +8,45 @@ mod frame_types;
 mod generators;
 /// [nom] parsers for internal usage.
 mod parsers;
+/// Reading of pcap and pcapng capture files.
+pub mod pcap;
+/// [Radiotap](radiotap::Radiotap) headers of captured and injected frames.
+pub mod radiotap;
 /// All traits used or provided by this library.
 mod traits;
 
 use crate::error::Error;
 use crate::generators::*;
 use crate::parsers::*;
+use crate::radiotap::Radiotap;
 
 // Re-exports for user convenience
 pub use crate::frame::Frame;
 pub use crate::frame_types::*;
 pub use crate::traits::*;
 
+/// The length of the frame check sequence at the end of a frame.
+const FCS_LENGTH: usize = 4;
+
 /// Parse IEE 802.11 frames from raw bytes.
 ///
+/// `has_fcs` tells whether the bytes end with the 4 byte frame check sequence, which is the
+/// case for many captures. It's removed before the frame is parsed, so frames with and without
+/// FCS result in the same [Frame].
 /// This function doesn't do FCS checks. These need to be done separately.
-pub fn parse_frame(input: &[u8]) -> Result<Frame, Error> {
+pub fn parse_frame(input: &[u8], has_fcs: bool) -> Result<Frame, Error> {
+    let input = if has_fcs {
+        let Some(length) = input.len().checked_sub(FCS_LENGTH) else {
+            return Err(Error::Incomplete(format!(
+                "The frame is shorter than its FCS ({} bytes)",
+                FCS_LENGTH
+            )));
+        };
+        &input[..length]
+    } else {
+        input
+    };
+
     let (input, frame_control) = parse_frame_control(input)?;
     //println!(
     //    "Type/Subtype: {:?}, {:?}",
@@ -39,6 +62,13 @@ pub fn parse_frame(input: &[u8]) -> Result<Frame, Error> {
         FrameSubType::ProbeResponse => parse_probe_response(frame_control, input),
         FrameSubType::AssociationRequest => parse_association_request(frame_control, input),
         FrameSubType::AssociationResponse => parse_association_response(frame_control, input),
+        FrameSubType::ReassociationRequest => parse_reassociation_request(frame_control, input),
+        FrameSubType::ReassociationResponse => parse_reassociation_response(frame_control, input),
+        FrameSubType::Authentication => parse_authentication(frame_control, input),
+        FrameSubType::Deauthentication => parse_deauthentication(frame_control, input),
+        FrameSubType::Disassociation => parse_disassociation(frame_control, input),
+        FrameSubType::Action => parse_action(frame_control, input),
+        FrameSubType::ActionNoAck => parse_action_no_ack(frame_control, input),
 
         // Control
         FrameSubType::Rts => parse_rts(frame_control, input),
@@ -56,16 +86,61 @@ pub fn parse_frame(input: &[u8]) -> Result<Frame, Error> {
     }
 }
 
+/// Parse a radiotap header.
+///
+/// Returns the [Radiotap] header and the remaining bytes, which usually contain an
+/// IEEE 802.11 frame.
+pub fn parse_radiotap(input: &[u8]) -> Result<(Radiotap, &[u8]), Error> {
+    parsers::parse_radiotap(input)
+}
+
+/// Parse an IEEE 802.11 frame with a leading radiotap header, as it's delivered by interfaces
+/// in monitor mode.
+///
+/// Whether the frame ends with an FCS is taken from the flags of the radiotap header.
+pub fn parse_radiotap_frame(input: &[u8]) -> Result<(Radiotap, Frame), Error> {
+    let (radiotap, input) = parsers::parse_radiotap(input)?;
+    let frame = parse_frame(input, radiotap.has_fcs())?;
+
+    Ok((radiotap, frame))
+}
+
+/// Serialize a [Frame] into the given buffer.
+///
+/// Returns the amount of bytes that have been written to the buffer.
+/// The FCS isn't appended. If you need it, it has to be calculated and added separately.
 pub fn serialize_frame(buffer: &mut [u8], frame: &Frame) -> Result<usize, Error> {
     let initial_cursor: GenCursor = (buffer, 0);
 
     let final_result = match frame {
+        // Management
         Frame::Beacon(beacon) => gen_beacon(initial_cursor, beacon),
-        _ => {
-            return Err(Error::Incomplete(
-                "Hasn't been implemented yet!".to_string(),
-            ))
+        Frame::ProbeRequest(request) => gen_probe_request(initial_cursor, request),
+        Frame::ProbeResponse(response) => gen_probe_response(initial_cursor, response),
+        Frame::AssociationRequest(request) => gen_association_request(initial_cursor, request),
+        Frame::AssociationResponse(response) => gen_association_response(initial_cursor, response),
+        Frame::ReassociationRequest(request) => gen_reassociation_request(initial_cursor, request),
+        Frame::ReassociationResponse(response) => {
+            gen_reassociation_response(initial_cursor, response)
         }
+        Frame::Authentication(auth) => gen_authentication(initial_cursor, auth),
+        Frame::Deauthentication(deauth) => gen_deauthentication(initial_cursor, deauth),
+        Frame::Disassociation(disassoc) => gen_disassociation(initial_cursor, disassoc),
+        Frame::Action(action) => gen_action(initial_cursor, action),
+        Frame::ActionNoAck(action) => gen_action_no_ack(initial_cursor, action),
+
+        // Control
+        Frame::Rts(rts) => gen_rts(initial_cursor, rts),
+        Frame::Cts(cts) => gen_cts(initial_cursor, cts),
+        Frame::Ack(ack) => gen_ack(initial_cursor, ack),
+        Frame::BlockAckRequest(request) => gen_block_ack_request(initial_cursor, request),
+        Frame::BlockAck(block_ack) => gen_block_ack(initial_cursor, block_ack),
+
+        // Data
+        Frame::Data(data) => gen_data(initial_cursor, data),
+        Frame::NullData(null_data) => gen_null_data(initial_cursor, null_data),
+        Frame::QosData(qos_data) => gen_qos_data(initial_cursor, qos_data),
+        Frame::QosNull(qos_null) => gen_qos_null(initial_cursor, qos_null),
     };
 
     if let Ok(final_cursor) = final_result {
@@ -75,5 +150,62 @@ pub fn serialize_frame(buffer: &mut [u8], frame: &Frame) -> Result<usize, Error>
     }
 }
 
+/// Serialize a [Radiotap] header into the given buffer.
+///
+/// Returns the amount of bytes that have been written to the buffer.
+/// The presence bitmap and the length are calculated from the fields that are set.
+pub fn serialize_radiotap(buffer: &mut [u8], radiotap: &Radiotap) -> Result<usize, Error> {
+    let (_, length) = gen_radiotap((buffer, 0), radiotap)?;
+
+    Ok(length)
+}
+
+/// Serialize a [Radiotap] header, followed by a [Frame], into the given buffer.
+///
+/// The result can be injected by an interface in monitor mode.
+/// Returns the amount of bytes that have been written to the buffer.
+///
+/// As [serialize_frame] doesn't append an FCS, the [RadiotapFlags::FCS] flag shouldn't be set.
+///
+/// ```
+/// use libwifi::frame::components::{FrameControl, MacAddress};
+/// use libwifi::frame::{Ack, Frame};
+/// use libwifi::radiotap::{Radiotap, TxFlags};
+/// use libwifi::{FrameSubType, FrameType};
+///
+/// let frame = Frame::Ack(Ack {
+///     frame_control: FrameControl {
+///         protocol_version: 0,
+///         frame_type: FrameType::Control,
+///         frame_subtype: FrameSubType::Ack,
+///         flags: 0,
+///     },
+///     duration: [0, 0],
+///     destination: MacAddress([0x02, 0, 0, 0, 0, 1]),
+/// });
+/// let radiotap = Radiotap {
+///     rate: Some(12),
+///     tx_flags: Some(TxFlags::NO_ACK | TxFlags::NO_SEQNO),
+///     data_retries: Some(0),
+///     ..Default::default()
+/// };
+///
+/// let mut buffer = [0; 64];
+/// let length = libwifi::serialize_radiotap_frame(&mut buffer, &radiotap, &frame).unwrap();
+/// assert_eq!(length, 13 + 10);
+/// ```
+///
+/// [RadiotapFlags::FCS]: radiotap::RadiotapFlags::FCS
+pub fn serialize_radiotap_frame(
+    buffer: &mut [u8],
+    radiotap: &Radiotap,
+    frame: &Frame,
+) -> Result<usize, Error> {
+    let radiotap_length = serialize_radiotap(buffer, radiotap)?;
+    let frame_length = serialize_frame(&mut buffer[radiotap_length..], frame)?;
+
+    Ok(radiotap_length + frame_length)
+}
+
 #[cfg(doctest)]
 doc_comment::doctest!("../README.md");
@@ -0,0 +1,48 @@
+use nom::bytes::complete::{tag, take};
+use nom::combinator::opt;
+use nom::multi::many0;
+use nom::number::complete::u8 as get_u8;
+use nom::sequence::tuple;
+use nom::IResult;
+
+use super::clone_slice;
+use crate::frame::components::{Country, CountryTriplet, OperatingTriplet, SubbandTriplet};
+
+/// Parse the body of a Country element.
+///
+/// The structure is described in [Country].
+/// A trailing padding byte is skipped.
+pub fn parse_country(input: &[u8]) -> IResult<&[u8], Country> {
+    let (remaining, (country_code, environment, triplets, _)) =
+        tuple((take(2usize), get_u8, many0(parse_triplet), opt(tag([0]))))(input)?;
+
+    Ok((
+        remaining,
+        Country {
+            country_code: clone_slice::<2>(country_code),
+            environment,
+            triplets,
+        },
+    ))
+}
+
+/// Parse a single [CountryTriplet], whose type is determined by its first byte.
+fn parse_triplet(input: &[u8]) -> IResult<&[u8], CountryTriplet> {
+    let (remaining, (first, second, third)) = tuple((get_u8, get_u8, get_u8))(input)?;
+
+    let triplet = if first >= OperatingTriplet::MIN_EXTENSION_ID {
+        CountryTriplet::Operating(OperatingTriplet {
+            operating_extension_id: first,
+            operating_class: second,
+            coverage_class: third,
+        })
+    } else {
+        CountryTriplet::Subband(SubbandTriplet {
+            first_channel: first,
+            number_of_channels: second,
+            max_transmit_power: third as i8,
+        })
+    };
+
+    Ok((remaining, triplet))
+}
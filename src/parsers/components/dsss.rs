@@ -0,0 +1,13 @@
+use nom::number::complete::u8 as get_u8;
+use nom::IResult;
+
+use crate::frame::components::DsParameterSet;
+
+/// Parse the body of a DS Parameter Set element.
+///
+/// The structure is described in [DsParameterSet].
+pub fn parse_ds_parameter_set(input: &[u8]) -> IResult<&[u8], DsParameterSet> {
+    let (remaining, current_channel) = get_u8(input)?;
+
+    Ok((remaining, DsParameterSet { current_channel }))
+}
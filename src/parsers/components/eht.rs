@@ -0,0 +1,69 @@
+use nom::bytes::complete::take;
+use nom::combinator::{cond, rest};
+use nom::number::complete::{le_u16, u8 as get_u8};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use super::clone_slice;
+use crate::frame::components::{EhtCapabilities, EhtOperation, EhtOperationInfo};
+
+/// Parse the body of an EHT Capabilities element, without the extension ID.
+///
+/// The structure is described in [EhtCapabilities].
+pub fn parse_eht_capabilities(input: &[u8]) -> IResult<&[u8], EhtCapabilities> {
+    let (remaining, (mac_capabilities, phy_capabilities, mcs_nss_and_ppe_thresholds)) =
+        tuple((take(2usize), take(9usize), rest))(input)?;
+
+    Ok((
+        remaining,
+        EhtCapabilities {
+            mac_capabilities: clone_slice::<2>(mac_capabilities),
+            phy_capabilities: clone_slice::<9>(phy_capabilities),
+            mcs_nss_and_ppe_thresholds: mcs_nss_and_ppe_thresholds.to_vec(),
+        },
+    ))
+}
+
+/// Parse the body of an EHT Operation element, without the extension ID.
+///
+/// The structure is described in [EhtOperation].
+pub fn parse_eht_operation(input: &[u8]) -> IResult<&[u8], EhtOperation> {
+    let (input, (parameters, basic_mcs_nss_set)) = tuple((get_u8, take(4usize)))(input)?;
+    let has_disabled_subchannels = parameters & 0x02 > 0;
+    let (input, operation_info) = cond(parameters & 0x01 > 0, |input| {
+        parse_operation_info(input, has_disabled_subchannels)
+    })(input)?;
+
+    Ok((
+        input,
+        EhtOperation {
+            default_pe_duration: parameters & 0x04 > 0,
+            group_addressed_bu_indication_limit: parameters & 0x08 > 0,
+            group_addressed_bu_indication_exponent: (parameters >> 4) & 0b11,
+            basic_mcs_nss_set: clone_slice::<4>(basic_mcs_nss_set),
+            operation_info,
+        },
+    ))
+}
+
+fn parse_operation_info(
+    input: &[u8],
+    has_disabled_subchannels: bool,
+) -> IResult<&[u8], EhtOperationInfo> {
+    let (remaining, (control, segment_0, segment_1, disabled_subchannel_bitmap)) = tuple((
+        get_u8,
+        get_u8,
+        get_u8,
+        cond(has_disabled_subchannels, le_u16),
+    ))(input)?;
+
+    Ok((
+        remaining,
+        EhtOperationInfo {
+            channel_width: control & 0b111,
+            channel_center_frequency_segment_0: segment_0,
+            channel_center_frequency_segment_1: segment_1,
+            disabled_subchannel_bitmap,
+        },
+    ))
+}
@@ -0,0 +1,13 @@
+use nom::number::complete::u8 as get_u8;
+use nom::IResult;
+
+use crate::frame::components::ErpInformation;
+
+/// Parse the body of an ERP element.
+///
+/// The structure is described in [ErpInformation].
+pub fn parse_erp_information(input: &[u8]) -> IResult<&[u8], ErpInformation> {
+    let (remaining, parameters) = get_u8(input)?;
+
+    Ok((remaining, ErpInformation::from_bits_truncate(parameters)))
+}
@@ -0,0 +1,119 @@
+use nom::bytes::complete::take;
+use nom::combinator::{cond, rest};
+use nom::number::complete::{le_u16, le_u24, u8 as get_u8};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use super::clone_slice;
+use crate::frame::components::{
+    He6GhzOperationInfo, HeCapabilities, HeMcsMap, HeMcsNssSet, HeOperation, HeVhtOperationInfo,
+    VhtChannelWidth,
+};
+
+/// Parse the body of a HE Capabilities element, without the extension ID.
+///
+/// The structure is described in [HeCapabilities].
+pub fn parse_he_capabilities(input: &[u8]) -> IResult<&[u8], HeCapabilities> {
+    let (input, (mac_capabilities, phy_capabilities, mcs_nss_80mhz)) =
+        tuple((take(6usize), take(11usize), parse_mcs_nss_set))(input)?;
+
+    let mut he = HeCapabilities {
+        mac_capabilities: clone_slice::<6>(mac_capabilities),
+        phy_capabilities: clone_slice::<11>(phy_capabilities),
+        mcs_nss_80mhz,
+        mcs_nss_160mhz: None,
+        mcs_nss_80_plus_80mhz: None,
+        ppe_thresholds: Vec::new(),
+    };
+
+    let (input, (mcs_nss_160mhz, mcs_nss_80_plus_80mhz)) = tuple((
+        cond(he.supports_160mhz(), parse_mcs_nss_set),
+        cond(he.supports_80_plus_80mhz(), parse_mcs_nss_set),
+    ))(input)?;
+    he.mcs_nss_160mhz = mcs_nss_160mhz;
+    he.mcs_nss_80_plus_80mhz = mcs_nss_80_plus_80mhz;
+
+    // The PPE thresholds are the last field and fill the rest of the element.
+    let (input, ppe_thresholds) = cond(he.ppe_thresholds_present(), rest)(input)?;
+    he.ppe_thresholds = ppe_thresholds
+        .map(|bytes| bytes.to_vec())
+        .unwrap_or_default();
+
+    Ok((input, he))
+}
+
+/// Parse the body of a HE Operation element, without the extension ID.
+///
+/// The structure is described in [HeOperation].
+pub fn parse_he_operation(input: &[u8]) -> IResult<&[u8], HeOperation> {
+    let (input, (parameters, bss_color_info, basic_mcs_set)) =
+        tuple((le_u24, get_u8, le_u16))(input)?;
+
+    let (input, (vht_operation_info, max_cohosted_bssid_indicator, six_ghz_operation_info)) =
+        tuple((
+            cond(parameters & 0x00_4000 > 0, parse_vht_operation_info),
+            cond(parameters & 0x00_8000 > 0, get_u8),
+            cond(parameters & 0x02_0000 > 0, parse_6ghz_operation_info),
+        ))(input)?;
+
+    Ok((
+        input,
+        HeOperation {
+            default_pe_duration: (parameters & 0b111) as u8,
+            twt_required: parameters & 0x00_0008 > 0,
+            txop_duration_rts_threshold: ((parameters >> 4) & 0x03ff) as u16,
+            er_su_disable: parameters & 0x01_0000 > 0,
+            bss_color: bss_color_info & 0x3f,
+            partial_bss_color: bss_color_info & 0x40 > 0,
+            bss_color_disabled: bss_color_info & 0x80 > 0,
+            basic_mcs_set: HeMcsMap(basic_mcs_set),
+            vht_operation_info,
+            max_cohosted_bssid_indicator,
+            six_ghz_operation_info,
+        },
+    ))
+}
+
+fn parse_mcs_nss_set(input: &[u8]) -> IResult<&[u8], HeMcsNssSet> {
+    let (remaining, (rx_mcs_map, tx_mcs_map)) = tuple((le_u16, le_u16))(input)?;
+
+    Ok((
+        remaining,
+        HeMcsNssSet {
+            rx_mcs_map: HeMcsMap(rx_mcs_map),
+            tx_mcs_map: HeMcsMap(tx_mcs_map),
+        },
+    ))
+}
+
+fn parse_vht_operation_info(input: &[u8]) -> IResult<&[u8], HeVhtOperationInfo> {
+    let (remaining, (channel_width, segment_0, segment_1)) =
+        tuple((get_u8, get_u8, get_u8))(input)?;
+
+    Ok((
+        remaining,
+        HeVhtOperationInfo {
+            channel_width: VhtChannelWidth::from(channel_width),
+            channel_center_frequency_segment_0: segment_0,
+            channel_center_frequency_segment_1: segment_1,
+        },
+    ))
+}
+
+fn parse_6ghz_operation_info(input: &[u8]) -> IResult<&[u8], He6GhzOperationInfo> {
+    let (remaining, (primary_channel, control, segment_0, segment_1, minimum_rate)) =
+        tuple((get_u8, get_u8, get_u8, get_u8, get_u8))(input)?;
+
+    Ok((
+        remaining,
+        He6GhzOperationInfo {
+            primary_channel,
+            channel_width: control & 0b11,
+            duplicate_beacon: control & 0x04 > 0,
+            regulatory_info: (control >> 3) & 0b111,
+            channel_center_frequency_segment_0: segment_0,
+            channel_center_frequency_segment_1: segment_1,
+            minimum_rate,
+        },
+    ))
+}
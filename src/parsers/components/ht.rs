@@ -0,0 +1,99 @@
+use nom::bytes::complete::take;
+use nom::number::complete::{le_u16, le_u32, u8 as get_u8};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use super::clone_slice;
+use crate::frame::components::{
+    AmpduParameters, AselCapabilities, HtCapabilities, HtCapabilityInfo, HtExtendedCapabilities,
+    HtOperation, HtProtection, SecondaryChannelOffset, SupportedMcsSet, TxBeamformingCapabilities,
+};
+
+/// Parse the body of a HT Capabilities element.
+///
+/// The structure is described in [HtCapabilities].
+pub fn parse_ht_capabilities(input: &[u8]) -> IResult<&[u8], HtCapabilities> {
+    let (remaining, (capability_info, ampdu_parameters, supported_mcs_set, extended, txbf, asel)) =
+        tuple((
+            le_u16,
+            get_u8,
+            parse_supported_mcs_set,
+            le_u16,
+            le_u32,
+            get_u8,
+        ))(input)?;
+
+    Ok((
+        remaining,
+        HtCapabilities {
+            ht_capability_info: HtCapabilityInfo::from_bits_truncate(capability_info),
+            ampdu_parameters: AmpduParameters {
+                max_ampdu_length_exponent: ampdu_parameters & 0b11,
+                min_mpdu_start_spacing: (ampdu_parameters >> 2) & 0b111,
+            },
+            supported_mcs_set,
+            extended_capabilities: HtExtendedCapabilities::from_bits_truncate(extended),
+            txbf_capabilities: TxBeamformingCapabilities::from_bits_truncate(txbf),
+            asel_capabilities: AselCapabilities::from_bits_truncate(asel),
+        },
+    ))
+}
+
+/// Parse the body of a HT Operation element.
+///
+/// The structure is described in [HtOperation].
+///
+/// HT operation information:
+/// - 1 byte:
+///   - **bit_0-1**: Secondary channel offset
+///   - **bit_2**: STA channel width
+///   - **bit_3**: RIFS mode
+/// - 2 bytes:
+///   - **bit_0-1**: HT protection
+///   - **bit_2**: Nongreenfield HT STAs present
+///   - **bit_4**: OBSS non-HT STAs present
+///   - **bit_5-12**: Channel center frequency segment 2
+/// - 2 bytes:
+///   - **bit_6**: Dual beacon
+///   - **bit_7**: Dual CTS protection
+///   - **bit_8**: STBC beacon
+pub fn parse_ht_operation(input: &[u8]) -> IResult<&[u8], HtOperation> {
+    let (remaining, (primary_channel, info_1, info_2, info_3, basic_mcs_set)) =
+        tuple((get_u8, get_u8, le_u16, le_u16, parse_supported_mcs_set))(input)?;
+
+    Ok((
+        remaining,
+        HtOperation {
+            primary_channel,
+            secondary_channel_offset: SecondaryChannelOffset::from_bits(info_1),
+            sta_channel_width: info_1 & 0x04 > 0,
+            rifs_mode: info_1 & 0x08 > 0,
+            ht_protection: HtProtection::from_bits(info_2 as u8),
+            nongreenfield_stas_present: info_2 & 0x0004 > 0,
+            obss_non_ht_stas_present: info_2 & 0x0010 > 0,
+            channel_center_frequency_segment_2: ((info_2 >> 5) & 0xff) as u8,
+            dual_beacon: info_3 & 0x0040 > 0,
+            dual_cts_protection: info_3 & 0x0080 > 0,
+            stbc_beacon: info_3 & 0x0100 > 0,
+            basic_mcs_set,
+        },
+    ))
+}
+
+/// Parse a 16 byte (basic) supported MCS set, as described in [SupportedMcsSet].
+fn parse_supported_mcs_set(input: &[u8]) -> IResult<&[u8], SupportedMcsSet> {
+    let (remaining, (rx_mcs_bitmask, rx_highest_data_rate, tx_mcs_set, _reserved)) =
+        tuple((take(10usize), le_u16, get_u8, take(3usize)))(input)?;
+
+    Ok((
+        remaining,
+        SupportedMcsSet {
+            rx_mcs_bitmask: clone_slice::<10>(rx_mcs_bitmask),
+            rx_highest_data_rate: rx_highest_data_rate & 0x03ff,
+            tx_mcs_set_defined: tx_mcs_set & 0x01 > 0,
+            tx_rx_mcs_set_not_equal: tx_mcs_set & 0x02 > 0,
+            tx_max_spatial_streams: (tx_mcs_set >> 2) & 0b11,
+            tx_unequal_modulation: tx_mcs_set & 0x10 > 0,
+        },
+    ))
+}
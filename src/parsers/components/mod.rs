@@ -1,17 +1,46 @@
-use nom::bytes::complete::take;
+use nom::bytes::complete::{tag, take};
+use nom::multi::length_data;
+use nom::number::complete::u8 as get_u8;
+use nom::sequence::tuple;
 use nom::IResult;
 
 use crate::frame::components::MacAddress;
 
+mod country;
+mod dsss;
+mod eht;
+mod erp;
 mod frame_control;
+mod he;
 mod header;
+mod ht;
+mod multi_link;
+mod rsn;
 mod sequence_control;
 mod station_info;
+mod tim;
+mod vendor;
+mod vht;
+mod wmm;
+mod wpa;
 
+pub use country::parse_country;
+pub use dsss::parse_ds_parameter_set;
+pub use eht::{parse_eht_capabilities, parse_eht_operation};
+pub use erp::parse_erp_information;
 pub use frame_control::parse_frame_control;
+pub use he::{parse_he_capabilities, parse_he_operation};
 pub use header::*;
+pub use ht::{parse_ht_capabilities, parse_ht_operation};
+pub use multi_link::{parse_multi_link, parse_non_inheritance};
+pub use rsn::parse_rsn_information;
 pub use sequence_control::parse_sequence_control;
 pub use station_info::parse_station_info;
+pub use tim::parse_tim;
+pub use vendor::{parse_owe_transition_mode, parse_p2p, parse_wps};
+pub use vht::{parse_vht_capabilities, parse_vht_operation};
+pub use wmm::parse_wmm;
+pub use wpa::parse_wpa_information;
 
 /// Parse mac addresses.
 /// Just take 6 bytes, clone them and create a new MacAddress struct from those bytes.
@@ -20,6 +49,32 @@ pub fn parse_mac(input: &[u8]) -> IResult<&[u8], MacAddress> {
     Ok((remaining, MacAddress(clone_slice::<6>(bytes))))
 }
 
+/// Parse the length and data of an element or subelement, whose ID has already been parsed.
+///
+/// Data that doesn't fit into 255 bytes is split into fragments, which directly follow the
+/// element and have the given `fragment_id`. Fragments are only taken, as long as the previous
+/// part had the maximum length of 255 bytes. Empty fragments are never taken.
+pub(crate) fn parse_fragmented(input: &[u8], fragment_id: u8) -> IResult<&[u8], Vec<u8>> {
+    let (mut input, data) = length_data(get_u8)(input)?;
+    let mut data = data.to_vec();
+
+    let mut last_length = data.len();
+    while last_length == 255 {
+        let fragment: IResult<&[u8], (&[u8], &[u8])> =
+            tuple((tag([fragment_id]), length_data(get_u8)))(input);
+        match fragment {
+            Ok((remaining, (_, fragment))) if !fragment.is_empty() => {
+                data.extend_from_slice(fragment);
+                last_length = fragment.len();
+                input = remaining;
+            }
+            _ => break,
+        }
+    }
+
+    Ok((input, data))
+}
+
 /// A convenience method to get a fixed-size slice copyfrom any slice.
 /// This will always use the first `X` bytes of the slice.
 pub(crate) fn clone_slice<const X: usize>(slice: &[u8]) -> [u8; X] {
@@ -0,0 +1,219 @@
+use nom::bytes::complete::{tag, take};
+use nom::combinator::{all_consuming, cond, fail};
+use nom::multi::{length_data, many0};
+use nom::number::complete::{le_i64, le_u16, u8 as get_u8};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use super::{parse_fragmented, parse_mac};
+use crate::frame::components::{
+    BasicMultiLink, DtimInfo, EmlCapabilities, MldCapabilities, MultiLink, NonInheritance,
+    NstrIndicationBitmap, PerStaProfile, ProbeRequestMultiLink,
+};
+
+/// The subelement ID of a [PerStaProfile].
+const PER_STA_PROFILE_ID: u8 = 0;
+/// The ID of the Fragment subelement, which carries the remaining data of a long
+/// [PerStaProfile].
+const FRAGMENT_SUBELEMENT_ID: u8 = 254;
+
+/// Parse the body of a Multi-Link element, without the extension ID.
+///
+/// The structure is described in [MultiLink].
+/// Only the Basic and Probe Request variants are supported.
+/// Elements with reserved bits or unknown subelements fail to parse, so they can be kept as
+/// raw data.
+pub fn parse_multi_link(input: &[u8]) -> IResult<&[u8], MultiLink> {
+    let (input, control) = le_u16(input)?;
+    let presence = control >> 4;
+
+    match control & 0b1111 {
+        0 if presence & !0x007f == 0 => {
+            let (input, multi_link) = parse_basic_multi_link(input, presence)?;
+            Ok((input, MultiLink::Basic(multi_link)))
+        }
+        1 if presence & !0x0001 == 0 => {
+            let (input, multi_link) = parse_probe_request_multi_link(input, presence)?;
+            Ok((input, MultiLink::ProbeRequest(multi_link)))
+        }
+        _ => fail(input),
+    }
+}
+
+/// Parse the body of a Non-Inheritance element, without the extension ID.
+///
+/// The structure is described in [NonInheritance].
+pub fn parse_non_inheritance(input: &[u8]) -> IResult<&[u8], NonInheritance> {
+    let (remaining, (element_ids, extension_ids)) =
+        tuple((length_data(get_u8), length_data(get_u8)))(input)?;
+
+    Ok((
+        remaining,
+        NonInheritance {
+            element_ids: element_ids.to_vec(),
+            extension_ids: extension_ids.to_vec(),
+        },
+    ))
+}
+
+fn parse_basic_multi_link(input: &[u8], presence: u16) -> IResult<&[u8], BasicMultiLink> {
+    let (input, common_info) = parse_common_info(input)?;
+    let (
+        _,
+        (
+            mld_mac_address,
+            link_id_info,
+            bss_parameters_change_count,
+            medium_synchronization_delay,
+            eml_capabilities,
+            mld_capabilities,
+            ap_mld_id,
+            extended_mld_capabilities,
+        ),
+    ) = all_consuming(tuple((
+        parse_mac,
+        cond(presence & 0x0001 > 0, get_u8),
+        cond(presence & 0x0002 > 0, get_u8),
+        cond(presence & 0x0004 > 0, le_u16),
+        cond(presence & 0x0008 > 0, le_u16),
+        cond(presence & 0x0010 > 0, le_u16),
+        cond(presence & 0x0020 > 0, get_u8),
+        cond(presence & 0x0040 > 0, le_u16),
+    )))(common_info)?;
+    // The upper bits of the link ID info are reserved.
+    if link_id_info.is_some_and(|info| info & 0xf0 != 0) {
+        return fail(input);
+    }
+
+    let (input, per_sta_profiles) = many0(|input| parse_per_sta_profile(input, true))(input)?;
+
+    Ok((
+        input,
+        BasicMultiLink {
+            mld_mac_address,
+            link_id: link_id_info.map(|info| info & 0x0f),
+            bss_parameters_change_count,
+            medium_synchronization_delay,
+            eml_capabilities: eml_capabilities.map(EmlCapabilities),
+            mld_capabilities: mld_capabilities.map(MldCapabilities),
+            ap_mld_id,
+            extended_mld_capabilities,
+            per_sta_profiles,
+        },
+    ))
+}
+
+fn parse_probe_request_multi_link(
+    input: &[u8],
+    presence: u16,
+) -> IResult<&[u8], ProbeRequestMultiLink> {
+    let (input, common_info) = parse_common_info(input)?;
+    let (_, ap_mld_id) = all_consuming(cond(presence & 0x0001 > 0, get_u8))(common_info)?;
+
+    let (input, per_sta_profiles) = many0(|input| parse_per_sta_profile(input, false))(input)?;
+
+    Ok((
+        input,
+        ProbeRequestMultiLink {
+            ap_mld_id,
+            per_sta_profiles,
+        },
+    ))
+}
+
+/// Take the common info field, whose first byte is its own length.
+fn parse_common_info(input: &[u8]) -> IResult<&[u8], &[u8]> {
+    let (input, length) = get_u8(input)?;
+    if length == 0 {
+        return fail(input);
+    }
+
+    take(length - 1)(input)
+}
+
+/// Parse a Per-STA Profile subelement, including its fragments.
+/// The STA info field only exists in the Basic variant of the Multi-Link element.
+fn parse_per_sta_profile(input: &[u8], has_sta_info: bool) -> IResult<&[u8], PerStaProfile> {
+    let (remaining, (_, body)) = tuple((tag([PER_STA_PROFILE_ID]), |input| {
+        parse_fragmented(input, FRAGMENT_SUBELEMENT_ID)
+    }))(input)?;
+
+    // The body is reassembled, so errors can't point into it.
+    match parse_per_sta_profile_body(&body, has_sta_info) {
+        Ok((_, profile)) => Ok((remaining, profile)),
+        Err(_) => fail(input),
+    }
+}
+
+/// Parse the reassembled body of a Per-STA Profile subelement.
+fn parse_per_sta_profile_body(input: &[u8], has_sta_info: bool) -> IResult<&[u8], PerStaProfile> {
+    let (body, sta_control) = le_u16(input)?;
+
+    let mut profile = PerStaProfile {
+        link_id: (sta_control & 0x000f) as u8,
+        complete_profile: sta_control & 0x0010 > 0,
+        ..Default::default()
+    };
+
+    if !has_sta_info {
+        if sta_control & !0x001f != 0 {
+            return fail(input);
+        }
+        profile.sta_profile = body.to_vec();
+        return Ok((&[], profile));
+    }
+
+    // The size of the NSTR indication bitmap must only be set, if the bitmap is present.
+    if sta_control & 0xf000 != 0 || sta_control & 0x0600 == 0x0400 {
+        return fail(input);
+    }
+
+    let (body, sta_info) = parse_common_info(body)?;
+    let two_octet_bitmap = sta_control & 0x0400 > 0;
+    let (_, (sta_mac_address, beacon_interval, tsf_offset, dtim_info, nstr_bitmap, change_count)) =
+        all_consuming(tuple((
+            cond(sta_control & 0x0020 > 0, parse_mac),
+            cond(sta_control & 0x0040 > 0, le_u16),
+            cond(sta_control & 0x0080 > 0, le_i64),
+            cond(sta_control & 0x0100 > 0, parse_dtim_info),
+            cond(sta_control & 0x0200 > 0, |input| {
+                parse_nstr_indication_bitmap(input, two_octet_bitmap)
+            }),
+            cond(sta_control & 0x0800 > 0, get_u8),
+        )))(sta_info)?;
+
+    profile.sta_mac_address = sta_mac_address;
+    profile.beacon_interval = beacon_interval;
+    profile.tsf_offset = tsf_offset;
+    profile.dtim_info = dtim_info;
+    profile.nstr_indication_bitmap = nstr_bitmap;
+    profile.bss_parameters_change_count = change_count;
+    profile.sta_profile = body.to_vec();
+
+    Ok((&[], profile))
+}
+
+fn parse_dtim_info(input: &[u8]) -> IResult<&[u8], DtimInfo> {
+    let (remaining, (dtim_count, dtim_period)) = tuple((get_u8, get_u8))(input)?;
+
+    Ok((
+        remaining,
+        DtimInfo {
+            dtim_count,
+            dtim_period,
+        },
+    ))
+}
+
+fn parse_nstr_indication_bitmap(
+    input: &[u8],
+    two_octets: bool,
+) -> IResult<&[u8], NstrIndicationBitmap> {
+    if two_octets {
+        let (remaining, bitmap) = le_u16(input)?;
+        Ok((remaining, NstrIndicationBitmap::TwoOctets(bitmap)))
+    } else {
+        let (remaining, bitmap) = get_u8(input)?;
+        Ok((remaining, NstrIndicationBitmap::OneOctet(bitmap)))
+    }
+}
@@ -0,0 +1,88 @@
+use nom::bytes::complete::take;
+use nom::multi::count;
+use nom::number::complete::{le_u16, u8 as get_u8};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use super::clone_slice;
+use crate::frame::components::{AkmSuite, CipherSuite, RsnCapabilities, RsnInformation};
+
+/// Parse the body of a RSN element.
+///
+/// The structure is described in [RsnInformation].
+/// Every field after the version is optional and may be cut off at the end of the element.
+/// Missing fields keep the values of [RsnInformation::default].
+pub fn parse_rsn_information(input: &[u8]) -> IResult<&[u8], RsnInformation> {
+    let (mut input, version) = le_u16(input)?;
+    let mut rsn = RsnInformation {
+        version,
+        ..Default::default()
+    };
+
+    if input.is_empty() {
+        return Ok((input, rsn));
+    }
+    (input, rsn.group_cipher) = parse_cipher_suite(input)?;
+
+    if input.is_empty() {
+        return Ok((input, rsn));
+    }
+    let suite_count;
+    (input, suite_count) = le_u16(input)?;
+    (input, rsn.pairwise_ciphers) = count(parse_cipher_suite, suite_count as usize)(input)?;
+
+    if input.is_empty() {
+        return Ok((input, rsn));
+    }
+    let suite_count;
+    (input, suite_count) = le_u16(input)?;
+    (input, rsn.akm_suites) = count(parse_akm_suite, suite_count as usize)(input)?;
+
+    if input.is_empty() {
+        return Ok((input, rsn));
+    }
+    let capabilities;
+    (input, capabilities) = le_u16(input)?;
+    rsn.capabilities = RsnCapabilities::from_bits_truncate(capabilities);
+
+    if input.is_empty() {
+        return Ok((input, rsn));
+    }
+    let pmkid_count;
+    (input, pmkid_count) = le_u16(input)?;
+    (input, rsn.pmkids) = count(parse_pmkid, pmkid_count as usize)(input)?;
+
+    if input.is_empty() {
+        return Ok((input, rsn));
+    }
+    let group_management_cipher;
+    (input, group_management_cipher) = parse_cipher_suite(input)?;
+    rsn.group_management_cipher = Some(group_management_cipher);
+
+    Ok((input, rsn))
+}
+
+/// Parse a 4 byte suite selector into its organization identifier and suite type.
+pub fn parse_suite_selector(input: &[u8]) -> IResult<&[u8], ([u8; 3], u8)> {
+    let (remaining, (oui, suite_type)) = tuple((take(3usize), get_u8))(input)?;
+
+    Ok((remaining, (clone_slice::<3>(oui), suite_type)))
+}
+
+fn parse_cipher_suite(input: &[u8]) -> IResult<&[u8], CipherSuite> {
+    let (remaining, (oui, suite_type)) = parse_suite_selector(input)?;
+
+    Ok((remaining, CipherSuite::from_selector(oui, suite_type)))
+}
+
+fn parse_akm_suite(input: &[u8]) -> IResult<&[u8], AkmSuite> {
+    let (remaining, (oui, suite_type)) = parse_suite_selector(input)?;
+
+    Ok((remaining, AkmSuite::from_selector(oui, suite_type)))
+}
+
+fn parse_pmkid(input: &[u8]) -> IResult<&[u8], [u8; 16]> {
+    let (remaining, pmkid) = take(16usize)(input)?;
+
+    Ok((remaining, clone_slice::<16>(pmkid)))
+}
@@ -1,22 +1,20 @@
-use nom::complete::take;
-use nom::error::Error;
-use nom::sequence::tuple;
-use nom::{bits, IResult};
+use nom::number::complete::le_u16;
+use nom::IResult;
 
 use crate::frame::components::SequenceControl;
 
-/// Parse and return the [ManagementHeader] from a given payload.
+/// Parse and return the [SequenceControl] from a given payload.
+///
+/// The field is a little-endian u16.
+/// The lower 4 bits are the fragment number, the upper 12 bits are the sequence number.
 pub fn parse_sequence_control(input: &[u8]) -> IResult<&[u8], SequenceControl> {
-    let (remaining, (fragment_number, sequence_number)) =
-        bits::<_, (u8, u16), Error<(&[u8], usize)>, _, _>(tuple((take(4usize), take(12usize))))(
-            input,
-        )?;
+    let (remaining, sequence_control) = le_u16(input)?;
 
     Ok((
         remaining,
         SequenceControl {
-            fragment_number,
-            sequence_number,
+            fragment_number: (sequence_control & 0x000f) as u8,
+            sequence_number: sequence_control >> 4,
         },
     ))
 }
@@ -1,9 +1,6 @@
-use nom::bytes::complete::take;
-use nom::number::complete::u8 as get_u8;
-use nom::sequence::tuple;
-use nom::IResult;
-
-use crate::frame::components::{ManagementInfoId, StationInfo, SupportedRate};
+use super::parse_fragmented;
+use crate::error::Error;
+use crate::frame::components::{ManagementInfoId, StationInfo};
 
 /// Parse variable length and variable field information.
 /// The general structure of the data looks like this:
@@ -14,42 +11,34 @@ use crate::frame::components::{ManagementInfoId, StationInfo, SupportedRate};
 ///
 /// This format is only used in management frames.
 ///
+/// Elements with more than 255 bytes of data are followed by [ManagementInfoId::Fragment]
+/// elements, which are reassembled into a single element.
+///
+/// All of the input is parsed as elements, so it must end exactly at the end of the last
+/// element. An FCS has to be removed beforehand.
+/// An element that's longer than the remaining input results in an [Error::TruncatedElement].
+///
 /// There might be multiple elements with the same element id,
 /// which is why StationInfo uses a Vec instead of BTreeMap as a data structure.
-pub fn parse_station_info(mut input: &[u8]) -> IResult<&[u8], StationInfo> {
+/// The elements are stored as they are, typed elements are parsed on demand by [StationInfo].
+pub fn parse_station_info(mut input: &[u8]) -> Result<StationInfo, Error> {
     let mut station_info = StationInfo::default();
 
-    let mut element_id;
-    let mut length;
-    let mut data;
-    loop {
-        (input, (element_id, length)) = tuple((get_u8, get_u8))(input)?;
-        //println!("Element id {}, Length: {}", element_id, length);
-        (input, data) = take(length)(input)?;
-        //println!("Extracted data: {:?}", data);
-
-        if let Ok(element) = ManagementInfoId::try_from(element_id) {
-            match element {
-                ManagementInfoId::SSID => {
-                    let mut ssid = String::from_utf8_lossy(data).to_string();
-                    // Remove null chars. Some APs seem to enjoy sending those.
-                    ssid = ssid.replace('\0', " ");
-                    station_info.ssid = Some(ssid);
-                }
-                ManagementInfoId::SupportedRates => {
-                    station_info.supported_rates =
-                        data.iter().map(|rate| SupportedRate(*rate)).collect()
-                }
-                _ => {
-                    station_info.data.push((element, data.to_vec()));
-                }
-            }
-        } // TODO: Put some kind of opt-in warning here?
+    // Some frames, such as Deauthentication frames, usually don't contain any elements.
+    while let Some((element_id, body)) = input.split_first() {
+        let element_id = ManagementInfoId::from(*element_id);
+        let Ok((remaining, data)) = parse_fragmented(body, ManagementInfoId::Fragment.into())
+        else {
+            // Either the length byte or parts of the data are missing.
+            let missing = body
+                .first()
+                .map_or(1, |length| 1 + usize::from(*length) - body.len());
+            return Err(Error::TruncatedElement(element_id, missing));
+        };
 
-        if input.len() <= 4 {
-            break;
-        }
+        station_info.elements.push((element_id, data));
+        input = remaining;
     }
 
-    Ok((input, station_info))
+    Ok(station_info)
 }
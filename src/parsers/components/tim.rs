@@ -0,0 +1,29 @@
+use nom::combinator::{fail, rest};
+use nom::number::complete::u8 as get_u8;
+use nom::sequence::tuple;
+use nom::IResult;
+
+use crate::frame::components::Tim;
+
+/// Parse the body of a TIM element.
+///
+/// The structure is described in [Tim].
+/// The partial virtual bitmap consists of all remaining bytes and mustn't be empty.
+pub fn parse_tim(input: &[u8]) -> IResult<&[u8], Tim> {
+    let (remaining, (dtim_count, dtim_period, bitmap_control, bitmap)) =
+        tuple((get_u8, get_u8, get_u8, rest))(input)?;
+    if bitmap.is_empty() {
+        return fail(input);
+    }
+
+    Ok((
+        remaining,
+        Tim {
+            dtim_count,
+            dtim_period,
+            group_addressed_traffic: bitmap_control & 0x01 > 0,
+            bitmap_offset: bitmap_control >> 1,
+            partial_virtual_bitmap: bitmap.to_vec(),
+        },
+    ))
+}
@@ -0,0 +1,58 @@
+use nom::combinator::{cond, map};
+use nom::multi::{length_data, many0};
+use nom::number::complete::{be_u16, le_u16, u8 as get_u8};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use super::parse_mac;
+use crate::frame::components::{OweTransitionMode, P2p, P2pAttribute, Wps, WpsAttribute};
+
+/// Parse the body of a WPS vendor element.
+/// The body starts right after the OUI and vendor type.
+///
+/// The structure is described in [Wps].
+pub fn parse_wps(input: &[u8]) -> IResult<&[u8], Wps> {
+    let (remaining, attributes) = many0(map(
+        tuple((be_u16, length_data(be_u16))),
+        |(attribute_type, data): (u16, &[u8])| WpsAttribute {
+            attribute_type,
+            data: data.to_vec(),
+        },
+    ))(input)?;
+
+    Ok((remaining, Wps { attributes }))
+}
+
+/// Parse the body of a P2P vendor element.
+/// The body starts right after the OUI and vendor type.
+///
+/// The structure is described in [P2p].
+pub fn parse_p2p(input: &[u8]) -> IResult<&[u8], P2p> {
+    let (remaining, attributes) = many0(map(
+        tuple((get_u8, length_data(le_u16))),
+        |(attribute_id, data): (u8, &[u8])| P2pAttribute {
+            attribute_id,
+            data: data.to_vec(),
+        },
+    ))(input)?;
+
+    Ok((remaining, P2p { attributes }))
+}
+
+/// Parse the body of an OWE Transition Mode vendor element.
+/// The body starts right after the OUI and vendor type.
+///
+/// The structure is described in [OweTransitionMode].
+pub fn parse_owe_transition_mode(input: &[u8]) -> IResult<&[u8], OweTransitionMode> {
+    let (input, (bssid, ssid)) = tuple((parse_mac, length_data(get_u8)))(input)?;
+    let (remaining, channel) = cond(!input.is_empty(), tuple((get_u8, get_u8)))(input)?;
+
+    Ok((
+        remaining,
+        OweTransitionMode {
+            bssid,
+            ssid: ssid.to_vec(),
+            operating_class_and_channel: channel,
+        },
+    ))
+}
@@ -0,0 +1,49 @@
+use nom::number::complete::{le_u16, le_u32, u8 as get_u8};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use crate::frame::components::{
+    SupportedVhtMcsSet, VhtCapabilities, VhtCapabilityInfo, VhtChannelWidth, VhtMcsMap,
+    VhtOperation,
+};
+
+/// Parse the body of a VHT Capabilities element.
+///
+/// The structure is described in [VhtCapabilities] and [SupportedVhtMcsSet].
+pub fn parse_vht_capabilities(input: &[u8]) -> IResult<&[u8], VhtCapabilities> {
+    let (remaining, (capability_info, rx_mcs_map, rx_highest, tx_mcs_map, tx_highest)) =
+        tuple((le_u32, le_u16, le_u16, le_u16, le_u16))(input)?;
+
+    Ok((
+        remaining,
+        VhtCapabilities {
+            vht_capability_info: VhtCapabilityInfo::from_bits_truncate(capability_info),
+            supported_mcs_set: SupportedVhtMcsSet {
+                rx_mcs_map: VhtMcsMap(rx_mcs_map),
+                rx_highest_data_rate: rx_highest & 0x1fff,
+                max_nsts_total: (rx_highest >> 13) as u8,
+                tx_mcs_map: VhtMcsMap(tx_mcs_map),
+                tx_highest_data_rate: tx_highest & 0x1fff,
+                extended_nss_bw_capable: tx_highest & 0x2000 > 0,
+            },
+        },
+    ))
+}
+
+/// Parse the body of a VHT Operation element.
+///
+/// The structure is described in [VhtOperation].
+pub fn parse_vht_operation(input: &[u8]) -> IResult<&[u8], VhtOperation> {
+    let (remaining, (channel_width, segment_0, segment_1, basic_mcs_set)) =
+        tuple((get_u8, get_u8, get_u8, le_u16))(input)?;
+
+    Ok((
+        remaining,
+        VhtOperation {
+            channel_width: VhtChannelWidth::from(channel_width),
+            channel_center_frequency_segment_0: segment_0,
+            channel_center_frequency_segment_1: segment_1,
+            basic_mcs_set: VhtMcsMap(basic_mcs_set),
+        },
+    ))
+}
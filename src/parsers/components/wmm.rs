@@ -0,0 +1,59 @@
+use nom::combinator::fail;
+use nom::number::complete::{le_u16, u8 as get_u8};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use crate::frame::components::{AccessCategory, Wmm, WmmAcParameter, WmmInformation, WmmParameter};
+
+/// Parse the body of a WMM vendor element.
+/// The body starts right after the OUI and vendor type.
+///
+/// The structure is described in [Wmm].
+/// Subtypes other than Information and Parameter are rejected.
+pub fn parse_wmm(input: &[u8]) -> IResult<&[u8], Wmm> {
+    let (input, (subtype, version, qos_info)) = tuple((get_u8, get_u8, get_u8))(input)?;
+
+    match subtype {
+        0 => Ok((
+            input,
+            Wmm::Information(WmmInformation { version, qos_info }),
+        )),
+        1 => {
+            let (input, (reserved, best_effort, background, video, voice)) = tuple((
+                get_u8,
+                parse_ac_parameter,
+                parse_ac_parameter,
+                parse_ac_parameter,
+                parse_ac_parameter,
+            ))(input)?;
+
+            Ok((
+                input,
+                Wmm::Parameter(WmmParameter {
+                    version,
+                    qos_info,
+                    reserved,
+                    ac_parameters: [best_effort, background, video, voice],
+                }),
+            ))
+        }
+        _ => fail(input),
+    }
+}
+
+/// Parse a single AC parameter record, as described in [WmmAcParameter].
+fn parse_ac_parameter(input: &[u8]) -> IResult<&[u8], WmmAcParameter> {
+    let (remaining, (aci_aifsn, ecw, txop_limit)) = tuple((get_u8, get_u8, le_u16))(input)?;
+
+    Ok((
+        remaining,
+        WmmAcParameter {
+            access_category: AccessCategory::from_index(aci_aifsn >> 5),
+            admission_control_mandatory: aci_aifsn & 0x10 > 0,
+            aifsn: aci_aifsn & 0x0f,
+            ecw_min: ecw & 0x0f,
+            ecw_max: ecw >> 4,
+            txop_limit,
+        },
+    ))
+}
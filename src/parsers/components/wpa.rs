@@ -0,0 +1,71 @@
+use nom::multi::count;
+use nom::number::complete::le_u16;
+use nom::IResult;
+
+use super::rsn::parse_suite_selector;
+use crate::frame::components::{
+    AkmSuite, CipherSuite, RsnCapabilities, WpaInformation, IEEE_OUI, MICROSOFT_OUI,
+};
+
+/// Parse the body of a WPA vendor element.
+/// The body starts right after the OUI and vendor type.
+///
+/// The structure is described in [WpaInformation].
+pub fn parse_wpa_information(input: &[u8]) -> IResult<&[u8], WpaInformation> {
+    let (mut input, version) = le_u16(input)?;
+    let mut wpa = WpaInformation {
+        version,
+        ..Default::default()
+    };
+
+    if input.is_empty() {
+        return Ok((input, wpa));
+    }
+    (input, wpa.group_cipher) = parse_wpa_cipher_suite(input)?;
+
+    if input.is_empty() {
+        return Ok((input, wpa));
+    }
+    let suite_count;
+    (input, suite_count) = le_u16(input)?;
+    (input, wpa.pairwise_ciphers) = count(parse_wpa_cipher_suite, suite_count as usize)(input)?;
+
+    if input.is_empty() {
+        return Ok((input, wpa));
+    }
+    let suite_count;
+    (input, suite_count) = le_u16(input)?;
+    (input, wpa.akm_suites) = count(parse_wpa_akm_suite, suite_count as usize)(input)?;
+
+    if input.is_empty() {
+        return Ok((input, wpa));
+    }
+    let capabilities;
+    (input, capabilities) = le_u16(input)?;
+    wpa.capabilities = Some(RsnCapabilities::from_bits_truncate(capabilities));
+
+    Ok((input, wpa))
+}
+
+/// WPA uses the Microsoft OUI for its suites, which are mapped to the IEEE suites.
+fn wpa_selector((oui, suite_type): ([u8; 3], u8)) -> ([u8; 3], u8) {
+    if oui == MICROSOFT_OUI {
+        (IEEE_OUI, suite_type)
+    } else {
+        (oui, suite_type)
+    }
+}
+
+fn parse_wpa_cipher_suite(input: &[u8]) -> IResult<&[u8], CipherSuite> {
+    let (remaining, selector) = parse_suite_selector(input)?;
+    let (oui, suite_type) = wpa_selector(selector);
+
+    Ok((remaining, CipherSuite::from_selector(oui, suite_type)))
+}
+
+fn parse_wpa_akm_suite(input: &[u8]) -> IResult<&[u8], AkmSuite> {
+    let (remaining, selector) = parse_suite_selector(input)?;
+    let (oui, suite_type) = wpa_selector(selector);
+
+    Ok((remaining, AkmSuite::from_selector(oui, suite_type)))
+}
@@ -0,0 +1,159 @@
+use nom::bytes::complete::take;
+use nom::number::complete::{le_u16, u8 as get_u8};
+use nom::sequence::tuple;
+
+use crate::error::Error;
+use crate::frame::components::{FrameControl, ManagementHeader, ReasonCode, StatusCode};
+use crate::frame::*;
+use crate::parsers::{
+    clone_slice, parse_management_header, parse_sequence_control, parse_station_info,
+};
+
+/// Parse an [Action] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Category
+/// - Category specific body
+pub fn parse_action(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
+    let (input, header) = parse_management_header(frame_control, input)?;
+    let (category, body) = parse_category_and_body(&header, input)?;
+
+    Ok(Frame::Action(Action {
+        header,
+        category,
+        body,
+    }))
+}
+
+/// Parse an [ActionNoAck] frame.
+///
+/// The structure is the same as the one of an [Action] frame.
+pub fn parse_action_no_ack(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
+    let (input, header) = parse_management_header(frame_control, input)?;
+    let (category, body) = parse_category_and_body(&header, input)?;
+
+    Ok(Frame::ActionNoAck(ActionNoAck {
+        header,
+        category,
+        body,
+    }))
+}
+
+/// Parse the category and dispatch to the respective category body parser.
+///
+/// Categories that aren't handled yet are saved as raw bytes.
+/// The body of protected frames is encrypted, which is why neither the category nor the body
+/// are parsed.
+fn parse_category_and_body(
+    header: &ManagementHeader,
+    input: &[u8],
+) -> Result<(Option<ActionCategory>, ActionBody), Error> {
+    if header.frame_control.wep() {
+        return Ok((None, ActionBody::Protected(input.to_vec())));
+    }
+
+    let (input, category) = get_u8(input)?;
+    let category = ActionCategory::from(category);
+
+    let body = match category {
+        ActionCategory::BlockAck => parse_block_ack_action(input)?,
+        ActionCategory::SaQuery => parse_sa_query(input)?,
+        ActionCategory::VendorSpecific | ActionCategory::VendorSpecificProtected => {
+            parse_vendor_specific_action(input)?
+        }
+        _ => ActionBody::Unhandled(input.to_vec()),
+    };
+
+    Ok((Some(category), body))
+}
+
+/// Parse a BlockAck action body.
+///
+/// The first byte is the action code:
+/// - 0: ADDBA Request
+/// - 1: ADDBA Response
+/// - 2: DELBA
+///
+/// The elements at the end of each body are parsed up to the end of the input.
+fn parse_block_ack_action(input: &[u8]) -> Result<ActionBody, Error> {
+    let (remaining, action) = get_u8(input)?;
+
+    let action = match action {
+        0 => {
+            let (remaining, (dialog_token, parameters, timeout, starting_sequence_control)) =
+                tuple((get_u8, le_u16, le_u16, parse_sequence_control))(remaining)?;
+
+            BlockAckAction::AddBaRequest(AddBaRequest {
+                dialog_token,
+                parameters: BlockAckParameterSet::from_bits(parameters),
+                timeout,
+                starting_sequence_control,
+                station_info: parse_station_info(remaining)?,
+            })
+        }
+        1 => {
+            let (remaining, (dialog_token, status_code, parameters, timeout)) =
+                tuple((get_u8, le_u16, le_u16, le_u16))(remaining)?;
+
+            BlockAckAction::AddBaResponse(AddBaResponse {
+                dialog_token,
+                status_code: StatusCode::from(status_code),
+                parameters: BlockAckParameterSet::from_bits(parameters),
+                timeout,
+                station_info: parse_station_info(remaining)?,
+            })
+        }
+        2 => {
+            let (remaining, (parameters, reason_code)) = tuple((le_u16, le_u16))(remaining)?;
+
+            BlockAckAction::DelBa(DelBa {
+                parameters,
+                reason_code: ReasonCode::from(reason_code),
+                station_info: parse_station_info(remaining)?,
+            })
+        }
+        _ => return Ok(ActionBody::Unhandled(input.to_vec())),
+    };
+
+    Ok(ActionBody::BlockAck(action))
+}
+
+/// Parse a SA Query action body.
+///
+/// The general structure is:
+/// - Action code (0: Request, 1: Response)
+/// - Transaction identifier (2 bytes)
+/// - Optional elements, e.g. the Operating Channel Information element
+fn parse_sa_query(input: &[u8]) -> Result<ActionBody, Error> {
+    let (remaining, (action, transaction_id)) = tuple((get_u8, take(2usize)))(input)?;
+    let transaction_id = clone_slice::<2>(transaction_id);
+
+    let action = match action {
+        0 => SaQueryAction::Request {
+            transaction_id,
+            station_info: parse_station_info(remaining)?,
+        },
+        1 => SaQueryAction::Response {
+            transaction_id,
+            station_info: parse_station_info(remaining)?,
+        },
+        _ => return Ok(ActionBody::Unhandled(input.to_vec())),
+    };
+
+    Ok(ActionBody::SaQuery(action))
+}
+
+/// Parse a vendor specific action body.
+///
+/// The general structure is:
+/// - Organization identifier (3 bytes)
+/// - Vendor specific content
+fn parse_vendor_specific_action(input: &[u8]) -> Result<ActionBody, Error> {
+    let (remaining, oui) = take(3usize)(input)?;
+
+    Ok(ActionBody::VendorSpecific(VendorSpecificAction {
+        oui: clone_slice::<3>(oui),
+        data: remaining.to_vec(),
+    }))
+}
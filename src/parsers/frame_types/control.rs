@@ -1,15 +1,30 @@
-use nom::bits;
 use nom::bytes::complete::take;
-use nom::complete::take as bit_take;
-use nom::error::Error as NomError;
-use nom::number::complete::le_u64;
+use nom::number::complete::{le_u16, le_u64};
 use nom::sequence::tuple;
 
 use crate::frame::components::{FrameControl, SequenceControl};
 use crate::frame::*;
-use crate::parsers::{clone_slice, flag, parse_mac};
+use crate::parsers::{clone_slice, parse_mac};
 use crate::{error::Error, parsers::parse_sequence_control};
 
+/// Split the little-endian BlockAck(Request) control field into its components.
+///
+/// - **bit_0**: Ack policy
+/// - **bit_1**: Multi-TID
+/// - **bit_2**: Compressed bitmap
+/// - **bit_3-11**: Reserved
+/// - **bit_12-15**: TID_INFO
+///
+/// Returns a tuple of `(policy, multi_tid, compressed_bitmap, tid_info)`.
+fn split_block_ack_control(control: u16) -> (bool, bool, bool, u8) {
+    (
+        control & 0x0001 > 0,
+        control & 0x0002 > 0,
+        control & 0x0004 > 0,
+        (control >> 12) as u8,
+    )
+}
+
 /// Parse a [Rts] frame.
 ///
 /// The general structure is:
@@ -66,17 +81,9 @@ pub fn parse_ack(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Err
 /// This is a rather complicated one, but the docs should make things more clear.
 pub fn parse_block_ack_request(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
     let (mut request_information, (duration, destination, source, bar_control)) =
-        tuple((take(2usize), parse_mac, parse_mac, take(2usize)))(input)?;
+        tuple((take(2usize), parse_mac, parse_mac, le_u16))(input)?;
 
-    let (_, (policy, multi_tid, compressed_bitmap, _, tid_info)) =
-        bits::<_, (bool, bool, bool, u16, u8), NomError<(&[u8], usize)>, _, _>(tuple((
-            flag,
-            flag,
-            flag,
-            // These are the reserved
-            bit_take(9usize),
-            bit_take(4usize),
-        )))(bar_control)?;
+    let (policy, multi_tid, compressed_bitmap, tid_info) = split_block_ack_control(bar_control);
 
     // The TID_INFO and the BAR information field work in conjunction to provide information on
     // the number of TIDs in let number = ((vector[0] as u16) << 8) | vector[1] as u16;the request and starting sequence control and per TID info in the
@@ -114,23 +121,20 @@ pub fn parse_block_ack_request(frame_control: FrameControl, input: &[u8]) -> Res
 
     match mode {
         BlockAckMode::MultiTidBlockAck => {
-            let mut inner_tid_info: &[u8];
+            let mut per_tid_info: u16;
             let mut sequence_control: SequenceControl;
 
             // Multi TID mode, we expect tid_info + 1 elements in the
             for _ in 0..tid_info + 1 {
                 // Each requested is split into two segments
-                // - 2 bytes `tid_info`:
-                //      `tid_info` is split into 12 bits reserved space and 4 bits TID value
+                // - 2 bytes `per_tid_info`:
+                //      `per_tid_info` is split into 12 bits reserved space and 4 bits TID value
                 // - 2 bytes squence control
-                (request_information, (inner_tid_info, sequence_control)) =
-                    tuple((take(2usize), parse_sequence_control))(request_information)?;
+                (request_information, (per_tid_info, sequence_control)) =
+                    tuple((le_u16, parse_sequence_control))(request_information)?;
 
-                // Extract the 4 bits TID
-                let (_, (_, tid)) = bits::<_, (u16, u8), NomError<(&[u8], usize)>, _, _>(tuple((
-                    bit_take(12usize),
-                    bit_take(4usize),
-                )))(inner_tid_info)?;
+                // Extract the upper 4 bits TID
+                let tid = (per_tid_info >> 12) as u8;
 
                 requested_tids.push((tid, sequence_control));
             }
@@ -159,18 +163,10 @@ pub fn parse_block_ack_request(frame_control: FrameControl, input: &[u8]) -> Res
 /// Check the inline docs and the docs of [BlockAck] for more information.
 /// This is a rather complicated one, but the docs should make things more clear.
 pub fn parse_block_ack(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
-    let (mut ack_information, (duration, destination, source, bar_control)) =
-        tuple((take(2usize), parse_mac, parse_mac, take(2usize)))(input)?;
+    let (mut ack_information, (duration, destination, source, ba_control)) =
+        tuple((take(2usize), parse_mac, parse_mac, le_u16))(input)?;
 
-    let (_, (policy, multi_tid, compressed_bitmap, _, tid_info)) =
-        bits::<_, (bool, bool, bool, u16, u8), NomError<(&[u8], usize)>, _, _>(tuple((
-            flag,
-            flag,
-            flag,
-            // These are the reserved
-            bit_take(9usize),
-            bit_take(4usize),
-        )))(bar_control)?;
+    let (policy, multi_tid, compressed_bitmap, tid_info) = split_block_ack_control(ba_control);
 
     // The TID_INFO and the BAR information field work in conjunction to provide information on
     // the number of TIDs in let number = ((vector[0] as u16) << 8) | vector[1] as u16;the request and starting sequence control and per TID info in the
@@ -207,7 +203,7 @@ pub fn parse_block_ack(frame_control: FrameControl, input: &[u8]) -> Result<Fram
 
     let acks = match mode {
         BlockAckMode::MultiTidBlockAck => {
-            let mut inner_tid_info: &[u8];
+            let mut per_tid_info: u16;
             let mut sequence_control: SequenceControl;
             let mut bitmap: u64;
 
@@ -217,18 +213,15 @@ pub fn parse_block_ack(frame_control: FrameControl, input: &[u8]) -> Result<Fram
             // Multi TID mode, we expect tid_info + 1 elements in the
             for _ in 0..tid_info + 1 {
                 // Each requested is split into three segments
-                // - 2 bytes `tid_info`:
-                //      `tid_info` is split into 12 bits reserved space and 4 bits TID value
+                // - 2 bytes `per_tid_info`:
+                //      `per_tid_info` is split into 12 bits reserved space and 4 bits TID value
                 // - 2 bytes squence control
                 // - 8 bytes BlockAck bitmap
-                (ack_information, (inner_tid_info, sequence_control, bitmap)) =
-                    tuple((take(2usize), parse_sequence_control, le_u64))(ack_information)?;
+                (ack_information, (per_tid_info, sequence_control, bitmap)) =
+                    tuple((le_u16, parse_sequence_control, le_u64))(ack_information)?;
 
-                // Extract the 4 bits TID
-                let (_, (_, tid)) = bits::<_, (u16, u8), NomError<(&[u8], usize)>, _, _>(tuple((
-                    bit_take(12usize),
-                    bit_take(4usize),
-                )))(inner_tid_info)?;
+                // Extract the upper 4 bits TID
+                let tid = (per_tid_info >> 12) as u8;
 
                 acks.push((tid, sequence_control, bitmap));
             }
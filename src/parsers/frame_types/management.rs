@@ -2,29 +2,29 @@ use nom::number::complete::{le_u16, le_u64};
 use nom::sequence::tuple;
 
 use crate::error::Error;
-use crate::frame::components::{CapabilityInfo, FrameControl};
+use crate::frame::components::{CapabilityInfo, FrameControl, ReasonCode, StationInfo, StatusCode};
 use crate::frame::*;
-use crate::parsers::{parse_management_header, parse_station_info};
+use crate::parsers::{parse_mac, parse_management_header, parse_station_info};
 
 /// Parse an [AssociationRequest] frame.
 ///
 /// The general structure is:
 /// - ManagementHeader
-/// - Beacon interval
 /// - Capability info
+/// - Listen interval
 /// - Dynamic fields
 pub fn parse_association_request(
     frame_control: FrameControl,
     input: &[u8],
 ) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
-    let (_, (beacon_interval, capability_info, station_info)) =
-        tuple((le_u16, le_u16, parse_station_info))(input)?;
+    let (input, (capability_info, listen_interval)) = tuple((le_u16, le_u16))(input)?;
+    let station_info = parse_station_info(input)?;
 
     Ok(Frame::AssociationRequest(AssociationRequest {
         header,
-        beacon_interval,
         capability_info,
+        listen_interval,
         station_info,
     }))
 }
@@ -42,13 +42,66 @@ pub fn parse_association_response(
     input: &[u8],
 ) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
-    let (_, (capability_info, status_code, association_id, station_info)) =
-        tuple((le_u16, le_u16, le_u16, parse_station_info))(input)?;
+    let (input, (capability_info, status_code, association_id)) =
+        tuple((le_u16, le_u16, le_u16))(input)?;
+    let station_info = parse_station_info(input)?;
 
     Ok(Frame::AssociationResponse(AssociationResponse {
         header,
         capability_info,
-        status_code,
+        status_code: StatusCode::from(status_code),
+        association_id,
+        station_info,
+    }))
+}
+
+/// Parse a [ReassociationRequest] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Capability info
+/// - Listen interval
+/// - Current AP address
+/// - Dynamic fields
+pub fn parse_reassociation_request(
+    frame_control: FrameControl,
+    input: &[u8],
+) -> Result<Frame, Error> {
+    let (input, header) = parse_management_header(frame_control, input)?;
+    let (input, (capability_info, listen_interval, current_ap_address)) =
+        tuple((le_u16, le_u16, parse_mac))(input)?;
+    let station_info = parse_station_info(input)?;
+
+    Ok(Frame::ReassociationRequest(ReassociationRequest {
+        header,
+        capability_info,
+        listen_interval,
+        current_ap_address,
+        station_info,
+    }))
+}
+
+/// Parse a [ReassociationResponse] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Capability info
+/// - Status code
+/// - Association id
+/// - Dynamic fields
+pub fn parse_reassociation_response(
+    frame_control: FrameControl,
+    input: &[u8],
+) -> Result<Frame, Error> {
+    let (input, header) = parse_management_header(frame_control, input)?;
+    let (input, (capability_info, status_code, association_id)) =
+        tuple((le_u16, le_u16, le_u16))(input)?;
+    let station_info = parse_station_info(input)?;
+
+    Ok(Frame::ReassociationResponse(ReassociationResponse {
+        header,
+        capability_info,
+        status_code: StatusCode::from(status_code),
         association_id,
         station_info,
     }))
@@ -63,8 +116,9 @@ pub fn parse_association_response(
 /// - Dynamic fields
 pub fn parse_beacon(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
-    let (_, (timestamp, beacon_interval, capability_info_raw, station_info)) =
-        tuple((le_u64, le_u16, le_u16, parse_station_info))(input)?;
+    let (input, (timestamp, beacon_interval, capability_info_raw)) =
+        tuple((le_u64, le_u16, le_u16))(input)?;
+    let station_info = parse_station_info(input)?;
 
     Ok(Frame::Beacon(Beacon {
         header,
@@ -82,7 +136,7 @@ pub fn parse_beacon(frame_control: FrameControl, input: &[u8]) -> Result<Frame,
 /// - Dynamic fields
 pub fn parse_probe_request(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
-    let (_, station_info) = parse_station_info(input)?;
+    let station_info = parse_station_info(input)?;
 
     Ok(Frame::ProbeRequest(ProbeRequest {
         header,
@@ -99,8 +153,9 @@ pub fn parse_probe_request(frame_control: FrameControl, input: &[u8]) -> Result<
 /// - Dynamic fields
 pub fn parse_probe_response(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
     let (input, header) = parse_management_header(frame_control, input)?;
-    let (_, (timestamp, beacon_interval, capability_info, station_info)) =
-        tuple((le_u64, le_u16, le_u16, parse_station_info))(input)?;
+    let (input, (timestamp, beacon_interval, capability_info)) =
+        tuple((le_u64, le_u16, le_u16))(input)?;
+    let station_info = parse_station_info(input)?;
 
     Ok(Frame::ProbeResponse(ProbeResponse {
         header,
@@ -110,3 +165,92 @@ pub fn parse_probe_response(frame_control: FrameControl, input: &[u8]) -> Result
         station_info,
     }))
 }
+
+/// Parse an [Authentication] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Authentication algorithm number
+/// - Authentication transaction sequence number
+/// - Status code
+/// - Algorithm specific data (e.g. SAE) or dynamic fields
+pub fn parse_authentication(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
+    let (input, header) = parse_management_header(frame_control, input)?;
+    let (input, (auth_algorithm, auth_seq, status_code)) = tuple((le_u16, le_u16, le_u16))(input)?;
+
+    let auth_algorithm = AuthenticationAlgorithm::from(auth_algorithm);
+    let (algorithm_data, station_info) = if auth_algorithm.has_algorithm_data() {
+        (input.to_vec(), StationInfo::default())
+    } else {
+        (Vec::new(), parse_station_info(input)?)
+    };
+
+    Ok(Frame::Authentication(Authentication {
+        header,
+        auth_algorithm,
+        auth_seq,
+        status_code: StatusCode::from(status_code),
+        algorithm_data,
+        station_info,
+    }))
+}
+
+/// Parse a [Deauthentication] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Reason code
+/// - Dynamic fields
+///
+/// If the frame is protected, the encrypted body is kept as is.
+pub fn parse_deauthentication(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
+    let (input, header) = parse_management_header(frame_control, input)?;
+    if header.frame_control.wep() {
+        return Ok(Frame::Deauthentication(Deauthentication {
+            header,
+            reason_code: ReasonCode::Reserved,
+            station_info: StationInfo::default(),
+            protected_body: Some(input.to_vec()),
+        }));
+    }
+
+    let (input, reason_code) = le_u16(input)?;
+    let station_info = parse_station_info(input)?;
+
+    Ok(Frame::Deauthentication(Deauthentication {
+        header,
+        reason_code: ReasonCode::from(reason_code),
+        station_info,
+        protected_body: None,
+    }))
+}
+
+/// Parse a [Disassociation] frame.
+///
+/// The general structure is:
+/// - ManagementHeader
+/// - Reason code
+/// - Dynamic fields
+///
+/// If the frame is protected, the encrypted body is kept as is.
+pub fn parse_disassociation(frame_control: FrameControl, input: &[u8]) -> Result<Frame, Error> {
+    let (input, header) = parse_management_header(frame_control, input)?;
+    if header.frame_control.wep() {
+        return Ok(Frame::Disassociation(Disassociation {
+            header,
+            reason_code: ReasonCode::Reserved,
+            station_info: StationInfo::default(),
+            protected_body: Some(input.to_vec()),
+        }));
+    }
+
+    let (input, reason_code) = le_u16(input)?;
+    let station_info = parse_station_info(input)?;
+
+    Ok(Frame::Disassociation(Disassociation {
+        header,
+        reason_code: ReasonCode::from(reason_code),
+        station_info,
+        protected_body: None,
+    }))
+}
@@ -1,7 +1,9 @@
+mod action;
 mod control;
 mod data;
 mod management;
 
+pub use action::*;
 pub use control::*;
 pub use data::*;
 pub use management::*;
@@ -0,0 +1,267 @@
+use nom::bytes::complete::take;
+use nom::number::complete::{be_u32, le_u16, le_u32, u16 as get_u16, u32 as get_u32, u8 as get_u8};
+use nom::number::Endianness;
+use nom::sequence::tuple;
+use nom::IResult;
+
+use crate::pcap::*;
+
+/// The type of the 802.11-Common field of a PPI header.
+const PPI_FIELD_80211_COMMON: u16 = 2;
+/// The flag of the 802.11-Common field, which is set if the frame contains an FCS.
+const PPI_COMMON_FCS_PRESENT: u16 = 0x0001;
+/// The flag of a PPI header, which is set if its fields are aligned to 32 bits.
+const PPI_FLAG_ALIGNED: u8 = 0x01;
+
+/// Parse the header of a classic pcap file after its magic number.
+///
+/// Structure:
+/// - 2 bytes: Major version
+/// - 2 bytes: Minor version
+/// - 4 bytes: Time zone offset (unused)
+/// - 4 bytes: Timestamp accuracy (unused)
+/// - 4 bytes: Snap length
+/// - 4 bytes: Link type (lower 16 bits) and FCS information (upper 4 bits and bit 26)
+pub fn parse_pcap_header(
+    input: &[u8],
+    endianness: Endianness,
+    timestamp_resolution: TimestampResolution,
+) -> IResult<&[u8], Interface> {
+    let (remaining, (_major, _minor, _zone, _sigfigs, snap_length, link_type)) = tuple((
+        get_u16(endianness),
+        get_u16(endianness),
+        get_u32(endianness),
+        get_u32(endianness),
+        get_u32(endianness),
+        get_u32(endianness),
+    ))(input)?;
+
+    // The FCS length is given in 16 bit words, if bit 26 is set.
+    let fcs_length = (link_type & 0x0400_0000 != 0).then_some((link_type >> 28) as u8 * 2);
+
+    Ok((
+        remaining,
+        Interface {
+            link_type: LinkType::from(link_type as u16),
+            snap_length,
+            timestamp_resolution,
+            fcs_length,
+        },
+    ))
+}
+
+/// Parse the header of a packet record of a classic pcap file.
+///
+/// Returns the seconds, the fraction of the second, the captured length and the original
+/// length of the packet.
+pub fn parse_pcap_record_header(
+    input: &[u8],
+    endianness: Endianness,
+) -> IResult<&[u8], (u32, u32, u32, u32)> {
+    tuple((
+        get_u32(endianness),
+        get_u32(endianness),
+        get_u32(endianness),
+        get_u32(endianness),
+    ))(input)
+}
+
+/// Parse the options at the end of a pcapng block body.
+///
+/// Each option consists of a 2 byte code, a 2 byte length and a value, which is padded to 32
+/// bits. The list ends with an `opt_endofopt` option or the end of the body.
+pub fn parse_pcapng_options(
+    mut input: &[u8],
+    endianness: Endianness,
+) -> IResult<&[u8], Vec<(u16, &[u8])>> {
+    let mut options = Vec::new();
+    while !input.is_empty() {
+        let (remaining, (code, length)) = tuple((get_u16(endianness), get_u16(endianness)))(input)?;
+        if code == OPTION_END {
+            return Ok((remaining, options));
+        }
+
+        let length = usize::from(length);
+        let (remaining, value) = take(length)(remaining)?;
+        let padding = length.next_multiple_of(4) - length;
+        let (remaining, _) = take(padding.min(remaining.len()))(remaining)?;
+
+        options.push((code, value));
+        input = remaining;
+    }
+
+    Ok((input, options))
+}
+
+/// Parse the body of a pcapng interface description block.
+///
+/// Structure:
+/// - 2 bytes: Link type
+/// - 2 bytes: Reserved
+/// - 4 bytes: Snap length
+/// - Variable: Options, of which `if_tsresol` and `if_fcslen` are used.
+///
+/// The `if_fcslen` option contains the FCS length in bits, it's converted into bytes.
+pub fn parse_interface_description(
+    input: &[u8],
+    endianness: Endianness,
+) -> IResult<&[u8], Interface> {
+    let (remaining, (link_type, _reserved, snap_length)) = tuple((
+        get_u16(endianness),
+        get_u16(endianness),
+        get_u32(endianness),
+    ))(input)?;
+    let (remaining, options) = parse_pcapng_options(remaining, endianness)?;
+
+    let mut interface = Interface {
+        link_type: LinkType::from(link_type),
+        snap_length,
+        timestamp_resolution: TimestampResolution::default(),
+        fcs_length: None,
+    };
+    for (code, value) in options {
+        match (code, value.first()) {
+            (OPTION_IF_TSRESOL, Some(resolution)) => {
+                interface.timestamp_resolution = TimestampResolution::from_option(*resolution);
+            }
+            (OPTION_IF_FCSLEN, Some(bits)) => interface.fcs_length = Some(*bits / 8),
+            _ => (),
+        }
+    }
+
+    Ok((remaining, interface))
+}
+
+/// The fields of a pcapng enhanced packet block.
+pub struct EnhancedPacket<'a> {
+    pub interface_id: u32,
+    pub timestamp: u64,
+    pub original_length: u32,
+    pub data: &'a [u8],
+    pub comments: Vec<String>,
+}
+
+/// Parse the body of a pcapng enhanced packet block.
+///
+/// Structure:
+/// - 4 bytes: Interface ID
+/// - 8 bytes: Timestamp as upper and lower 32 bits, in units of the interface's resolution
+/// - 4 bytes: Captured length
+/// - 4 bytes: Original length
+/// - Variable: Packet data, padded to 32 bits
+/// - Variable: Options, of which `opt_comment` is used.
+pub fn parse_enhanced_packet(
+    input: &[u8],
+    endianness: Endianness,
+) -> IResult<&[u8], EnhancedPacket<'_>> {
+    let (
+        remaining,
+        (interface_id, timestamp_high, timestamp_low, captured_length, original_length),
+    ) = tuple((
+        get_u32(endianness),
+        get_u32(endianness),
+        get_u32(endianness),
+        get_u32(endianness),
+        get_u32(endianness),
+    ))(input)?;
+
+    let captured_length = captured_length as usize;
+    let (remaining, data) = take(captured_length)(remaining)?;
+    let padding = captured_length.next_multiple_of(4) - captured_length;
+    let (remaining, _) = take(padding.min(remaining.len()))(remaining)?;
+    let (remaining, options) = parse_pcapng_options(remaining, endianness)?;
+
+    let comments = options
+        .into_iter()
+        .filter(|(code, _)| *code == OPTION_COMMENT)
+        .map(|(_, value)| String::from_utf8_lossy(value).into_owned())
+        .collect();
+
+    Ok((
+        remaining,
+        EnhancedPacket {
+            interface_id,
+            timestamp: (u64::from(timestamp_high) << 32) | u64::from(timestamp_low),
+            original_length,
+            data,
+            comments,
+        },
+    ))
+}
+
+/// Parse the body of a pcapng simple packet block.
+///
+/// Structure:
+/// - 4 bytes: Original length
+/// - Variable: Packet data, padded to 32 bits
+///
+/// The captured length is the original length, limited by the snap length of the first
+/// interface and the size of the block.
+pub fn parse_simple_packet(
+    input: &[u8],
+    endianness: Endianness,
+    snap_length: u32,
+) -> IResult<&[u8], (u32, &[u8])> {
+    let (remaining, original_length) = get_u32(endianness)(input)?;
+
+    let mut captured_length = original_length as usize;
+    if snap_length != 0 {
+        captured_length = captured_length.min(snap_length as usize);
+    }
+    let (remaining, data) = take(captured_length.min(remaining.len()))(remaining)?;
+
+    Ok((remaining, (original_length, data)))
+}
+
+/// Parse a PPI (Per-Packet Information) header.
+///
+/// Returns the link type of the packet after the header and whether it contains an FCS, if
+/// the header has an 802.11-Common field.
+///
+/// Structure (little-endian):
+/// - 1 byte: Version
+/// - 1 byte: Flags
+/// - 2 bytes: Header length
+/// - 4 bytes: Link type of the packet
+/// - Variable: Fields, each with a 2 byte type, a 2 byte length and data
+pub fn parse_ppi_header(input: &[u8]) -> IResult<&[u8], (u32, Option<bool>)> {
+    let (_, (_version, flags, length, link_type)) = tuple((get_u8, get_u8, le_u16, le_u32))(input)?;
+    let (remaining, header) = take(length)(input)?;
+
+    let mut fields = header.get(8..).unwrap_or_default();
+    let mut has_fcs = None;
+    while fields.len() >= 4 {
+        let (rest, (field_type, field_length)) = tuple((le_u16, le_u16))(fields)?;
+        let (rest, data) = take(field_length)(rest)?;
+
+        // 8 bytes TSFT, followed by the flags.
+        if field_type == PPI_FIELD_80211_COMMON && data.len() >= 10 {
+            let (_, common_flags) = le_u16(&data[8..])?;
+            has_fcs = Some(common_flags & PPI_COMMON_FCS_PRESENT != 0);
+        }
+
+        fields = rest;
+        if flags & PPI_FLAG_ALIGNED != 0 {
+            let field_length = usize::from(field_length);
+            let padding = field_length.next_multiple_of(4) - field_length;
+            fields = fields.get(padding..).unwrap_or_default();
+        }
+    }
+
+    Ok((remaining, (link_type, has_fcs)))
+}
+
+/// Parse an AVS capture header.
+///
+/// Returns the version of the header.
+///
+/// Structure (big-endian):
+/// - 4 bytes: Version (`0x80211001` or `0x80211002`)
+/// - 4 bytes: Header length
+/// - Variable: Fields, which aren't parsed
+pub fn parse_avs_header(input: &[u8]) -> IResult<&[u8], u32> {
+    let (_, (version, length)) = tuple((be_u32, be_u32))(input)?;
+    let (remaining, _) = take(length)(input)?;
+
+    Ok((remaining, version))
+}
@@ -0,0 +1,326 @@
+use nom::bytes::complete::take;
+use nom::number::complete::{le_i8, le_u16, le_u32, le_u64, u8 as get_u8};
+use nom::sequence::tuple;
+use nom::IResult;
+
+use super::clone_slice;
+use crate::error::Error;
+use crate::radiotap::*;
+
+/// Parse a radiotap header.
+///
+/// Returns the header and the data after it, which usually is an IEEE 802.11 frame.
+///
+/// The presence bitmaps are walked in order, including extended bitmaps and vendor
+/// namespaces, whose data is skipped by its skip length.
+/// Each field is aligned relative to the start of the header.
+/// As the size of unknown fields isn't known, parsing of the fields stops at the first unknown
+/// field. The data after the header is still returned, as its position is defined by the
+/// header length.
+pub fn parse_radiotap(input: &[u8]) -> Result<(Radiotap, &[u8]), Error> {
+    let (_, (version, _, length)) = tuple((get_u8, get_u8, le_u16))(input)?;
+    if version != 0 {
+        return Err(Error::ParseFailure(
+            format!("Unsupported radiotap version {}", version),
+            input.to_vec(),
+        ));
+    }
+    let header_length = usize::from(length);
+    if header_length < FIXED_HEADER_LENGTH + 4 {
+        return Err(Error::ParseFailure(
+            format!("Invalid radiotap header length {}", header_length),
+            input.to_vec(),
+        ));
+    }
+    if input.len() < header_length {
+        return Err(Error::Incomplete(format!(
+            "The radiotap header has {} bytes, but only {} bytes are available",
+            header_length,
+            input.len()
+        )));
+    }
+    let (header, remaining) = input.split_at(header_length);
+
+    let present = parse_presence_words(&header[FIXED_HEADER_LENGTH..])?;
+    let mut reader = FieldReader {
+        header,
+        offset: FIXED_HEADER_LENGTH + 4 * present.len(),
+    };
+    let mut radiotap = Radiotap {
+        version,
+        length,
+        present: present.clone(),
+        ..Default::default()
+    };
+
+    let mut vendor_namespace: Option<VendorNamespace> = None;
+    let mut antenna = AntennaInfo::default();
+    let mut bit_offset = 0;
+    for word in present {
+        match vendor_namespace.as_mut() {
+            // The fields of a vendor namespace have already been skipped.
+            Some(namespace) => namespace.present.push(word),
+            None => {
+                for bit in 0..29 {
+                    if word & (1 << bit) == 0 {
+                        continue;
+                    }
+                    let field = bit_offset + bit;
+                    if !parse_field(&mut radiotap, &mut antenna, &mut reader, field)? {
+                        finish_namespace(&mut radiotap, &mut antenna, &mut vendor_namespace);
+                        return Ok((radiotap, remaining));
+                    }
+                }
+            }
+        }
+
+        if word & PRESENT_VENDOR_NAMESPACE != 0 {
+            finish_namespace(&mut radiotap, &mut antenna, &mut vendor_namespace);
+            let (_, (oui, sub_namespace, skip_length)) =
+                tuple((take(3usize), get_u8, le_u16))(reader.field(2, 6)?)?;
+            vendor_namespace = Some(VendorNamespace {
+                oui: clone_slice::<3>(oui),
+                sub_namespace,
+                present: Vec::new(),
+                data: reader.field(1, usize::from(skip_length))?.to_vec(),
+            });
+        } else if word & PRESENT_RADIOTAP_NAMESPACE != 0 {
+            finish_namespace(&mut radiotap, &mut antenna, &mut vendor_namespace);
+            bit_offset = 0;
+        } else {
+            bit_offset += 32;
+        }
+    }
+    finish_namespace(&mut radiotap, &mut antenna, &mut vendor_namespace);
+
+    Ok((radiotap, remaining))
+}
+
+/// Parse all presence words. Each word with the extension bit is followed by another one.
+fn parse_presence_words(mut input: &[u8]) -> Result<Vec<u32>, Error> {
+    let mut present = Vec::new();
+    loop {
+        let word;
+        (input, word) = le_u32(input)?;
+        present.push(word);
+
+        if word & PRESENT_EXT == 0 {
+            return Ok(present);
+        }
+    }
+}
+
+/// Store the antenna fields and vendor data of a namespace, once it's complete.
+fn finish_namespace(
+    radiotap: &mut Radiotap,
+    antenna: &mut AntennaInfo,
+    vendor_namespace: &mut Option<VendorNamespace>,
+) {
+    if !antenna.is_empty() {
+        radiotap.antennas.push(std::mem::take(antenna));
+    }
+    if let Some(namespace) = vendor_namespace.take() {
+        radiotap.vendor_namespaces.push(namespace);
+    }
+}
+
+/// Reads the data of fields at their alignment relative to the start of the header.
+struct FieldReader<'a> {
+    header: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> FieldReader<'a> {
+    fn field(&mut self, align: usize, size: usize) -> Result<&'a [u8], Error> {
+        let start = self.offset.next_multiple_of(align);
+        let Some(data) = self.header.get(start..start + size) else {
+            return Err(Error::Incomplete(format!(
+                "A radiotap field at offset {} with {} bytes exceeds the header length {}",
+                start,
+                size,
+                self.header.len()
+            )));
+        };
+        self.offset = start + size;
+
+        Ok(data)
+    }
+}
+
+/// Parse a single field of the default radiotap namespace.
+///
+/// Returns `false` if the field is unknown, in which case no further fields can be parsed.
+fn parse_field(
+    radiotap: &mut Radiotap,
+    antenna: &mut AntennaInfo,
+    reader: &mut FieldReader,
+    field: u32,
+) -> Result<bool, Error> {
+    let Some((align, size)) = field_layout(field) else {
+        return Ok(false);
+    };
+    let data = reader.field(align, size)?;
+
+    match field {
+        FIELD_TSFT => radiotap.tsft = Some(le_u64(data)?.1),
+        FIELD_FLAGS => radiotap.flags = Some(RadiotapFlags::from_bits_truncate(data[0])),
+        FIELD_RATE => radiotap.rate = Some(data[0]),
+        FIELD_CHANNEL => radiotap.channel = Some(parse_channel(data)?.1),
+        FIELD_ANTENNA_SIGNAL => {
+            let (_, signal) = le_i8(data)?;
+            antenna.signal = Some(signal);
+            radiotap.antenna_signal.get_or_insert(signal);
+        }
+        FIELD_ANTENNA_NOISE => {
+            let (_, noise) = le_i8(data)?;
+            antenna.noise = Some(noise);
+            radiotap.antenna_noise.get_or_insert(noise);
+        }
+        FIELD_LOCK_QUALITY => radiotap.lock_quality = Some(le_u16(data)?.1),
+        FIELD_TX_ATTENUATION => radiotap.tx_attenuation = Some(le_u16(data)?.1),
+        FIELD_DB_TX_ATTENUATION => radiotap.db_tx_attenuation = Some(le_u16(data)?.1),
+        FIELD_DBM_TX_POWER => radiotap.dbm_tx_power = Some(le_i8(data)?.1),
+        FIELD_ANTENNA => {
+            antenna.antenna = Some(data[0]);
+            radiotap.antenna.get_or_insert(data[0]);
+        }
+        FIELD_DB_ANTENNA_SIGNAL => radiotap.db_antenna_signal = Some(data[0]),
+        FIELD_DB_ANTENNA_NOISE => radiotap.db_antenna_noise = Some(data[0]),
+        FIELD_RX_FLAGS => {
+            radiotap.rx_flags = Some(RxFlags::from_bits_truncate(le_u16(data)?.1));
+        }
+        FIELD_TX_FLAGS => {
+            radiotap.tx_flags = Some(TxFlags::from_bits_truncate(le_u16(data)?.1));
+        }
+        FIELD_RTS_RETRIES => radiotap.rts_retries = Some(data[0]),
+        FIELD_DATA_RETRIES => radiotap.data_retries = Some(data[0]),
+        FIELD_XCHANNEL => radiotap.xchannel = Some(parse_xchannel(data)?.1),
+        FIELD_MCS => radiotap.mcs = Some(parse_mcs(data)?.1),
+        FIELD_AMPDU_STATUS => radiotap.ampdu_status = Some(parse_ampdu_status(data)?.1),
+        FIELD_VHT => radiotap.vht = Some(parse_vht(data)?.1),
+        FIELD_TIMESTAMP => radiotap.timestamp = Some(parse_timestamp(data)?.1),
+        FIELD_HE => radiotap.he = Some(parse_he(data)?.1),
+        // Known fields without a typed representation.
+        _ => (),
+    }
+
+    Ok(true)
+}
+
+/// Parse the Channel field.
+///
+/// The structure is described in [Channel].
+fn parse_channel(input: &[u8]) -> IResult<&[u8], Channel> {
+    let (remaining, (frequency, flags)) = tuple((le_u16, le_u16))(input)?;
+
+    Ok((
+        remaining,
+        Channel {
+            frequency,
+            flags: ChannelFlags::from_bits_truncate(flags),
+        },
+    ))
+}
+
+/// Parse the XChannel field.
+///
+/// The structure is described in [XChannel].
+fn parse_xchannel(input: &[u8]) -> IResult<&[u8], XChannel> {
+    let (remaining, (flags, frequency, channel, max_power)) =
+        tuple((le_u32, le_u16, get_u8, get_u8))(input)?;
+
+    Ok((
+        remaining,
+        XChannel {
+            flags,
+            frequency,
+            channel,
+            max_power,
+        },
+    ))
+}
+
+/// Parse the MCS field.
+///
+/// The structure is described in [Mcs].
+fn parse_mcs(input: &[u8]) -> IResult<&[u8], Mcs> {
+    let (remaining, (known, flags, index)) = tuple((get_u8, get_u8, get_u8))(input)?;
+
+    Ok((
+        remaining,
+        Mcs {
+            known: McsKnown::from_bits_truncate(known),
+            flags,
+            index,
+        },
+    ))
+}
+
+/// Parse the A-MPDU status field.
+///
+/// The structure is described in [AmpduStatus].
+fn parse_ampdu_status(input: &[u8]) -> IResult<&[u8], AmpduStatus> {
+    let (remaining, (reference, flags, delimiter_crc, _reserved)) =
+        tuple((le_u32, le_u16, get_u8, get_u8))(input)?;
+
+    Ok((
+        remaining,
+        AmpduStatus {
+            reference,
+            flags: AmpduFlags::from_bits_truncate(flags),
+            delimiter_crc,
+        },
+    ))
+}
+
+/// Parse the VHT field.
+///
+/// The structure is described in [Vht].
+fn parse_vht(input: &[u8]) -> IResult<&[u8], Vht> {
+    let (remaining, (known, flags, bandwidth, mcs_nss, coding, group_id, partial_aid)) =
+        tuple((le_u16, get_u8, get_u8, take(4usize), get_u8, get_u8, le_u16))(input)?;
+
+    Ok((
+        remaining,
+        Vht {
+            known: VhtKnown::from_bits_truncate(known),
+            flags: VhtFlags::from_bits_truncate(flags),
+            bandwidth,
+            mcs_nss: clone_slice::<4>(mcs_nss),
+            coding,
+            group_id,
+            partial_aid,
+        },
+    ))
+}
+
+/// Parse the timestamp field.
+///
+/// The structure is described in [Timestamp].
+fn parse_timestamp(input: &[u8]) -> IResult<&[u8], Timestamp> {
+    let (remaining, (timestamp, accuracy, unit_position, flags)) =
+        tuple((le_u64, le_u16, get_u8, get_u8))(input)?;
+
+    Ok((
+        remaining,
+        Timestamp {
+            timestamp,
+            accuracy,
+            unit_position,
+            flags,
+        },
+    ))
+}
+
+/// Parse the HE field, which consists of six 16 bit words.
+fn parse_he(input: &[u8]) -> IResult<&[u8], He> {
+    let (remaining, (data1, data2, data3, data4, data5, data6)) =
+        tuple((le_u16, le_u16, le_u16, le_u16, le_u16, le_u16))(input)?;
+
+    Ok((
+        remaining,
+        He {
+            data: [data1, data2, data3, data4, data5, data6],
+        },
+    ))
+}
@@ -0,0 +1,245 @@
+//! Reading and writing of pcap and pcapng capture files.
+//!
+//! Both formats are implemented in pure Rust and don't depend on libpcap.
+//! Captured packets are parsed into [Frame]s, depending on the link type of their interface.
+//! Frames and raw packets can be written to new capture files.
+use std::time::Duration;
+
+use num_enum::{FromPrimitive, IntoPrimitive};
+
+use crate::error::Error;
+use crate::parsers::{parse_avs_header, parse_ppi_header};
+use crate::radiotap::Radiotap;
+use crate::{parse_frame, parse_radiotap, Frame};
+
+mod reader;
+mod writer;
+
+pub use reader::PcapReader;
+pub use writer::PcapWriter;
+
+pub(crate) const PCAP_MAGIC_MICROSECONDS: u32 = 0xa1b2_c3d4;
+pub(crate) const PCAP_MAGIC_NANOSECONDS: u32 = 0xa1b2_3c4d;
+/// The block type of a pcapng section header block, which is the same in both byte orders.
+pub(crate) const PCAPNG_SECTION_HEADER: u32 = 0x0a0d_0d0a;
+pub(crate) const PCAPNG_BYTE_ORDER_MAGIC: u32 = 0x1a2b_3c4d;
+
+pub(crate) const BLOCK_INTERFACE_DESCRIPTION: u32 = 0x0000_0001;
+pub(crate) const BLOCK_SIMPLE_PACKET: u32 = 0x0000_0003;
+pub(crate) const BLOCK_ENHANCED_PACKET: u32 = 0x0000_0006;
+
+pub(crate) const OPTION_END: u16 = 0;
+pub(crate) const OPTION_COMMENT: u16 = 1;
+pub(crate) const OPTION_IF_TSRESOL: u16 = 9;
+pub(crate) const OPTION_IF_FCSLEN: u16 = 13;
+
+/// The link-layer header type of the packets of an interface.
+///
+/// Only the wifi related link types can be parsed into [Frame]s.
+/// Other link types are preserved via [LinkType::Unknown].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, IntoPrimitive)]
+#[repr(u16)]
+pub enum LinkType {
+    /// Raw IEEE 802.11 frames (`LINKTYPE_IEEE802_11`).
+    Ieee80211 = 105,
+    /// IEEE 802.11 frames with a radiotap header (`LINKTYPE_IEEE802_11_RADIOTAP`).
+    Radiotap = 127,
+    /// IEEE 802.11 frames with an AVS header (`LINKTYPE_IEEE802_11_AVS`).
+    Avs = 163,
+    /// IEEE 802.11 frames with a Per-Packet Information header (`LINKTYPE_PPI`).
+    Ppi = 192,
+    #[num_enum(catch_all)]
+    Unknown(u16),
+}
+
+/// The file format of a capture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CaptureFormat {
+    Pcap,
+    PcapNg,
+}
+
+/// The resolution of the timestamps of an interface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimestampResolution {
+    /// A tick is `10^-n` seconds.
+    Decimal(u8),
+    /// A tick is `2^-n` seconds.
+    Binary(u8),
+}
+
+impl Default for TimestampResolution {
+    fn default() -> Self {
+        Self::MICROSECONDS
+    }
+}
+
+impl TimestampResolution {
+    pub const MICROSECONDS: Self = TimestampResolution::Decimal(6);
+    pub const NANOSECONDS: Self = TimestampResolution::Decimal(9);
+
+    /// Create the resolution from the value of the pcapng `if_tsresol` option.
+    /// The most significant bit selects the binary resolution, the others are the exponent.
+    pub fn from_option(value: u8) -> Self {
+        if value & 0x80 == 0 {
+            TimestampResolution::Decimal(value)
+        } else {
+            TimestampResolution::Binary(value & 0x7f)
+        }
+    }
+
+    /// The value of the pcapng `if_tsresol` option.
+    pub fn to_option(&self) -> u8 {
+        match *self {
+            TimestampResolution::Decimal(exponent) => exponent & 0x7f,
+            TimestampResolution::Binary(exponent) => 0x80 | (exponent & 0x7f),
+        }
+    }
+
+    /// The number of ticks per second. Saturates for resolutions that don't fit into a `u128`.
+    fn ticks_per_second(&self) -> u128 {
+        match *self {
+            TimestampResolution::Decimal(exponent) => 10_u128
+                .checked_pow(u32::from(exponent))
+                .unwrap_or(u128::MAX),
+            TimestampResolution::Binary(exponent) => {
+                1_u128.checked_shl(u32::from(exponent)).unwrap_or(u128::MAX)
+            }
+        }
+    }
+
+    /// Convert a number of ticks into a duration.
+    pub fn to_duration(&self, ticks: u64) -> Duration {
+        let ticks_per_second = self.ticks_per_second();
+        let ticks = u128::from(ticks);
+        let seconds = ticks / ticks_per_second;
+        let nanoseconds = (ticks % ticks_per_second) * 1_000_000_000 / ticks_per_second;
+
+        Duration::new(seconds as u64, nanoseconds as u32)
+    }
+
+    /// Convert a duration into a number of ticks. Saturates, if it doesn't fit into a `u64`.
+    pub fn to_ticks(&self, duration: Duration) -> u64 {
+        let ticks_per_second = self.ticks_per_second();
+        let ticks = u128::from(duration.as_secs())
+            .saturating_mul(ticks_per_second)
+            .saturating_add(
+                u128::from(duration.subsec_nanos()).saturating_mul(ticks_per_second)
+                    / 1_000_000_000,
+            );
+
+        u64::try_from(ticks).unwrap_or(u64::MAX)
+    }
+}
+
+/// An interface of a capture, which describes how its packets have been captured.
+///
+/// Classic pcap files have a single interface, pcapng files may have several.
+///
+/// Structure of a pcapng interface description block:
+/// - 2 bytes: Link type
+/// - 2 bytes: Reserved
+/// - 4 bytes: Snap length
+/// - Variable: Options, e.g. `if_tsresol` and `if_fcslen`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interface {
+    pub link_type: LinkType,
+    /// The maximum number of captured bytes per packet. `0` means that there's no limit.
+    pub snap_length: u32,
+    pub timestamp_resolution: TimestampResolution,
+    /// The length of the FCS at the end of each packet in bytes, if it's known.
+    pub fcs_length: Option<u8>,
+}
+
+impl Interface {
+    /// An interface with the given link type, the common snap length of 65535 bytes,
+    /// microsecond timestamps and packets without FCS.
+    pub fn new(link_type: LinkType) -> Self {
+        Interface {
+            link_type,
+            snap_length: 65535,
+            timestamp_resolution: TimestampResolution::default(),
+            fcs_length: None,
+        }
+    }
+}
+
+/// A single packet of a capture, as it has been read from the file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PcapPacket {
+    /// The time since the Unix epoch, at which the packet has been captured.
+    /// Simple packet blocks of pcapng files don't have a timestamp.
+    pub timestamp: Option<Duration>,
+    /// The index of the [Interface] that captured the packet.
+    pub interface_id: u32,
+    pub interface: Interface,
+    /// The length of the packet on the wire. It's longer than `data`, if the packet has been
+    /// truncated by the snap length.
+    pub original_length: u32,
+    pub data: Vec<u8>,
+    /// The comments of the packet (pcapng only).
+    pub comments: Vec<String>,
+}
+
+impl PcapPacket {
+    /// Parse the packet into a [Frame].
+    ///
+    /// The link-layer header of the interface is removed beforehand.
+    /// Whether the frame ends with an FCS is taken from the radiotap flags or the PPI 802.11
+    /// common field, if they're present, and from the FCS length of the interface otherwise.
+    pub fn parse(&self) -> Result<CapturedFrame, Error> {
+        let interface_fcs = self.interface.fcs_length == Some(4);
+
+        let (radiotap, frame) = match self.interface.link_type {
+            LinkType::Ieee80211 => (None, parse_frame(&self.data, interface_fcs)?),
+            LinkType::Radiotap => {
+                let (radiotap, input) = parse_radiotap(&self.data)?;
+                let has_fcs = match radiotap.flags {
+                    Some(_) => radiotap.has_fcs(),
+                    None => interface_fcs,
+                };
+                let frame = parse_frame(input, has_fcs)?;
+                (Some(radiotap), frame)
+            }
+            LinkType::Avs => {
+                let (input, _) = parse_avs_header(&self.data)?;
+                (None, parse_frame(input, interface_fcs)?)
+            }
+            LinkType::Ppi => {
+                let (input, (link_type, has_fcs)) = parse_ppi_header(&self.data)?;
+                if u16::try_from(link_type).map(LinkType::from) != Ok(LinkType::Ieee80211) {
+                    return Err(Error::UnhandledProtocol(format!(
+                        "PPI packets with the link type {} aren't supported",
+                        link_type
+                    )));
+                }
+                (None, parse_frame(input, has_fcs.unwrap_or(interface_fcs))?)
+            }
+            LinkType::Unknown(link_type) => {
+                return Err(Error::UnhandledProtocol(format!(
+                    "Packets with the link type {} aren't supported",
+                    link_type
+                )))
+            }
+        };
+
+        Ok(CapturedFrame {
+            timestamp: self.timestamp,
+            interface_id: self.interface_id,
+            radiotap,
+            frame,
+        })
+    }
+}
+
+/// A parsed [Frame] of a capture.
+#[derive(Clone, Debug)]
+pub struct CapturedFrame {
+    /// The time since the Unix epoch, at which the frame has been captured.
+    pub timestamp: Option<Duration>,
+    /// The index of the [Interface] that captured the frame.
+    pub interface_id: u32,
+    /// The radiotap header of frames with the [LinkType::Radiotap] link type.
+    pub radiotap: Option<Radiotap>,
+    pub frame: Frame,
+}
@@ -0,0 +1,303 @@
+use std::io::{ErrorKind, Read};
+
+use nom::number::Endianness;
+
+use super::*;
+use crate::parsers::{
+    parse_enhanced_packet, parse_interface_description, parse_pcap_header,
+    parse_pcap_record_header, parse_simple_packet,
+};
+
+/// The maximum size of a packet record or pcapng block.
+/// Larger sizes are rejected, as they indicate a corrupted file.
+const MAX_RECORD_LENGTH: usize = 16 * 1024 * 1024;
+
+/// Reads packets from a pcap or pcapng capture.
+///
+/// The format and the byte order are detected by the magic number at the start of the file.
+/// Classic pcap files may use microsecond or nanosecond timestamps, pcapng files may consist
+/// of several sections with any number of interfaces. Enhanced and simple packet blocks are
+/// read, all other blocks are skipped.
+///
+/// As an [Iterator], the reader yields the packets parsed into [CapturedFrame]s.
+/// An error of a single packet doesn't end the iteration, only errors of the file itself do.
+/// [PcapReader::next_packet] returns the packets without parsing them.
+///
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::BufReader;
+///
+/// use libwifi::pcap::PcapReader;
+///
+/// let file = BufReader::new(File::open("capture.pcapng").unwrap());
+/// for captured in PcapReader::new(file).unwrap() {
+///     match captured {
+///         Ok(captured) => println!("{:?}: {:?}", captured.timestamp, captured.frame),
+///         Err(error) => println!("Couldn't parse frame: {}", error),
+///     }
+/// }
+/// ```
+pub struct PcapReader<R> {
+    reader: R,
+    format: CaptureFormat,
+    endianness: Endianness,
+    interfaces: Vec<Interface>,
+    /// Set once an error of the file itself occurred, after which nothing is read anymore.
+    failed: bool,
+}
+
+impl<R: Read> PcapReader<R> {
+    /// Create a reader and read the file header.
+    pub fn new(mut reader: R) -> Result<Self, Error> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        let mut pcap_reader = PcapReader {
+            reader,
+            format: CaptureFormat::Pcap,
+            endianness: Endianness::Little,
+            interfaces: Vec::new(),
+            failed: false,
+        };
+
+        let (endianness, resolution) = match (u32::from_le_bytes(magic), u32::from_be_bytes(magic))
+        {
+            (PCAP_MAGIC_MICROSECONDS, _) => (Endianness::Little, TimestampResolution::MICROSECONDS),
+            (_, PCAP_MAGIC_MICROSECONDS) => (Endianness::Big, TimestampResolution::MICROSECONDS),
+            (PCAP_MAGIC_NANOSECONDS, _) => (Endianness::Little, TimestampResolution::NANOSECONDS),
+            (_, PCAP_MAGIC_NANOSECONDS) => (Endianness::Big, TimestampResolution::NANOSECONDS),
+            (PCAPNG_SECTION_HEADER, _) => {
+                let mut length = [0; 4];
+                pcap_reader.reader.read_exact(&mut length)?;
+                pcap_reader.format = CaptureFormat::PcapNg;
+                pcap_reader.read_section_header(length)?;
+                return Ok(pcap_reader);
+            }
+            _ => {
+                return Err(Error::InvalidCapture(format!(
+                    "Unknown magic number {:02x?}",
+                    magic
+                )))
+            }
+        };
+
+        let mut header = [0; 20];
+        pcap_reader.reader.read_exact(&mut header)?;
+        let (_, interface) = parse_pcap_header(&header, endianness, resolution)?;
+        pcap_reader.endianness = endianness;
+        pcap_reader.interfaces.push(interface);
+
+        Ok(pcap_reader)
+    }
+
+    pub fn format(&self) -> CaptureFormat {
+        self.format
+    }
+
+    /// The interfaces of the current section.
+    pub fn interfaces(&self) -> &[Interface] {
+        &self.interfaces
+    }
+
+    /// Read the next packet without parsing it.
+    ///
+    /// Returns `None` at the end of the file.
+    pub fn next_packet(&mut self) -> Result<Option<PcapPacket>, Error> {
+        match self.format {
+            CaptureFormat::Pcap => self.next_pcap_packet(),
+            CaptureFormat::PcapNg => self.next_pcapng_packet(),
+        }
+    }
+
+    fn next_pcap_packet(&mut self) -> Result<Option<PcapPacket>, Error> {
+        let mut header = [0; 16];
+        if !self.read_or_eof(&mut header)? {
+            return Ok(None);
+        }
+        let (_, (seconds, fraction, captured_length, original_length)) =
+            parse_pcap_record_header(&header, self.endianness)?;
+
+        let interface = self.interfaces[0];
+        let data = self.read_vec(captured_length as usize)?;
+        let timestamp = Duration::from_secs(u64::from(seconds))
+            + interface
+                .timestamp_resolution
+                .to_duration(u64::from(fraction));
+
+        Ok(Some(PcapPacket {
+            timestamp: Some(timestamp),
+            interface_id: 0,
+            interface,
+            original_length,
+            data,
+            comments: Vec::new(),
+        }))
+    }
+
+    fn next_pcapng_packet(&mut self) -> Result<Option<PcapPacket>, Error> {
+        loop {
+            let mut header = [0; 8];
+            if !self.read_or_eof(&mut header)? {
+                return Ok(None);
+            }
+
+            let block_type = self.decode_u32(&header[..4]);
+            if block_type == PCAPNG_SECTION_HEADER {
+                self.read_section_header(header[4..].try_into().unwrap())?;
+                continue;
+            }
+
+            let body = self.read_block_body(self.decode_u32(&header[4..]))?;
+            match block_type {
+                BLOCK_INTERFACE_DESCRIPTION => {
+                    let (_, interface) = parse_interface_description(&body, self.endianness)?;
+                    self.interfaces.push(interface);
+                }
+                BLOCK_ENHANCED_PACKET => {
+                    let (_, packet) = parse_enhanced_packet(&body, self.endianness)?;
+                    let interface = self.interface(packet.interface_id)?;
+
+                    return Ok(Some(PcapPacket {
+                        timestamp: Some(
+                            interface.timestamp_resolution.to_duration(packet.timestamp),
+                        ),
+                        interface_id: packet.interface_id,
+                        interface,
+                        original_length: packet.original_length,
+                        data: packet.data.to_vec(),
+                        comments: packet.comments,
+                    }));
+                }
+                BLOCK_SIMPLE_PACKET => {
+                    let interface = self.interface(0)?;
+                    let (_, (original_length, data)) =
+                        parse_simple_packet(&body, self.endianness, interface.snap_length)?;
+
+                    return Ok(Some(PcapPacket {
+                        timestamp: None,
+                        interface_id: 0,
+                        interface,
+                        original_length,
+                        data: data.to_vec(),
+                        comments: Vec::new(),
+                    }));
+                }
+                // Statistics, name resolution and other blocks aren't needed.
+                _ => (),
+            }
+        }
+    }
+
+    /// Read a section header block, whose block type and length have already been read.
+    /// The length can only be decoded after the byte order magic.
+    /// A new section has its own byte order and interfaces.
+    fn read_section_header(&mut self, length: [u8; 4]) -> Result<(), Error> {
+        let mut byte_order_magic = [0; 4];
+        self.reader.read_exact(&mut byte_order_magic)?;
+
+        self.endianness = if u32::from_le_bytes(byte_order_magic) == PCAPNG_BYTE_ORDER_MAGIC {
+            Endianness::Little
+        } else if u32::from_be_bytes(byte_order_magic) == PCAPNG_BYTE_ORDER_MAGIC {
+            Endianness::Big
+        } else {
+            return Err(Error::InvalidCapture(format!(
+                "Unknown byte order magic {:02x?}",
+                byte_order_magic
+            )));
+        };
+        self.interfaces.clear();
+
+        // The byte order magic is part of the body and has already been read.
+        let length = self.decode_u32(&length);
+        self.read_block_body(length.saturating_sub(4))?;
+
+        Ok(())
+    }
+
+    /// Read the rest of a block after its type and length, including the trailing length.
+    /// Returns the block body.
+    fn read_block_body(&mut self, total_length: u32) -> Result<Vec<u8>, Error> {
+        let total_length = total_length as usize;
+        if !(12..=MAX_RECORD_LENGTH).contains(&total_length) || !total_length.is_multiple_of(4) {
+            return Err(Error::InvalidCapture(format!(
+                "Invalid block length {}",
+                total_length
+            )));
+        }
+
+        let mut body = self.read_vec(total_length - 8)?;
+        body.truncate(total_length - 12);
+
+        Ok(body)
+    }
+
+    fn interface(&self, interface_id: u32) -> Result<Interface, Error> {
+        self.interfaces
+            .get(interface_id as usize)
+            .copied()
+            .ok_or_else(|| Error::InvalidCapture(format!("Unknown interface {}", interface_id)))
+    }
+
+    fn decode_u32(&self, bytes: &[u8]) -> u32 {
+        let bytes = bytes.try_into().unwrap();
+        match self.endianness {
+            Endianness::Big => u32::from_be_bytes(bytes),
+            _ => u32::from_le_bytes(bytes),
+        }
+    }
+
+    fn read_vec(&mut self, length: usize) -> Result<Vec<u8>, Error> {
+        if length > MAX_RECORD_LENGTH {
+            return Err(Error::InvalidCapture(format!(
+                "Invalid record length {}",
+                length
+            )));
+        }
+
+        let mut data = vec![0; length];
+        self.reader.read_exact(&mut data)?;
+
+        Ok(data)
+    }
+
+    /// Fill the buffer. Returns `false`, if the end of the file has been reached before the
+    /// first byte.
+    fn read_or_eof(&mut self, buffer: &mut [u8]) -> Result<bool, Error> {
+        let mut read = 0;
+        while read < buffer.len() {
+            match self.reader.read(&mut buffer[read..]) {
+                Ok(0) if read == 0 => return Ok(false),
+                Ok(0) => {
+                    return Err(Error::Incomplete(format!(
+                        "The file ends within a record header ({} of {} bytes)",
+                        read,
+                        buffer.len()
+                    )))
+                }
+                Ok(length) => read += length,
+                Err(error) if error.kind() == ErrorKind::Interrupted => (),
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl<R: Read> Iterator for PcapReader<R> {
+    type Item = Result<CapturedFrame, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        match self.next_packet() {
+            Ok(packet) => packet.map(|packet| packet.parse()),
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
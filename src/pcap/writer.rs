@@ -0,0 +1,199 @@
+use std::io::Write;
+
+use super::*;
+use crate::generators::{
+    enhanced_packet_length, gen_enhanced_packet, gen_interface_description, gen_pcap_header,
+    gen_pcap_record, gen_section_header, interface_description_length, GenCursor, GenResult,
+    PcapNgOptions, PCAP_HEADER_LENGTH, PCAP_RECORD_HEADER_LENGTH, SECTION_HEADER_LENGTH,
+};
+use crate::{serialize_frame, serialize_radiotap_frame};
+
+/// The buffer size, which is used to serialize frames.
+/// It fits the largest possible MPDU, including a radiotap header.
+const FRAME_BUFFER_LENGTH: usize = 16 * 1024;
+
+/// Writes packets to a pcap or pcapng capture, which can be opened by Wireshark.
+///
+/// All files are written in little-endian byte order with a single interface, whose link type
+/// decides how [Frame]s are written. Packets that are longer than the snap length of the
+/// interface are truncated.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libwifi::pcap::{CaptureFormat, Interface, LinkType, PcapReader, PcapWriter};
+///
+/// let mut writer =
+///     PcapWriter::new(Vec::new(), CaptureFormat::PcapNg, Interface::new(LinkType::Ieee80211))
+///         .unwrap();
+/// let ack = [0xd4, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+/// writer
+///     .write_packet(Duration::from_secs(1), &ack, Some("An ACK frame"))
+///     .unwrap();
+///
+/// let file = writer.into_inner();
+/// let packet = PcapReader::new(file.as_slice())
+///     .unwrap()
+///     .next_packet()
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!(packet.data, ack);
+/// assert_eq!(packet.comments, vec!["An ACK frame".to_string()]);
+/// ```
+pub struct PcapWriter<W> {
+    writer: W,
+    format: CaptureFormat,
+    interface: Interface,
+}
+
+impl<W: Write> PcapWriter<W> {
+    /// Create a writer and write the file header.
+    /// For pcapng files, these are the section header and the interface description.
+    ///
+    /// Classic pcap files only support microsecond and nanosecond timestamps.
+    pub fn new(writer: W, format: CaptureFormat, interface: Interface) -> Result<Self, Error> {
+        let mut pcap_writer = PcapWriter {
+            writer,
+            format,
+            interface,
+        };
+
+        match format {
+            CaptureFormat::Pcap => {
+                if interface.timestamp_resolution != TimestampResolution::MICROSECONDS
+                    && interface.timestamp_resolution != TimestampResolution::NANOSECONDS
+                {
+                    return Err(Error::SerializeFailure(format!(
+                        "Pcap files don't support the timestamp resolution {:?}",
+                        interface.timestamp_resolution
+                    )));
+                }
+                pcap_writer.write(PCAP_HEADER_LENGTH, |cursor| {
+                    gen_pcap_header(cursor, &interface)
+                })?;
+            }
+            CaptureFormat::PcapNg => {
+                pcap_writer.write(SECTION_HEADER_LENGTH, gen_section_header)?;
+                pcap_writer.write(interface_description_length(&interface), |cursor| {
+                    gen_interface_description(cursor, &interface)
+                })?;
+            }
+        }
+
+        Ok(pcap_writer)
+    }
+
+    pub fn format(&self) -> CaptureFormat {
+        self.format
+    }
+
+    pub fn interface(&self) -> &Interface {
+        &self.interface
+    }
+
+    /// Write a packet, which already contains the link-layer header of the interface.
+    ///
+    /// The timestamp is the time since the Unix epoch.
+    /// Comments are only written to pcapng files, pcap files don't support them.
+    pub fn write_packet(
+        &mut self,
+        timestamp: Duration,
+        data: &[u8],
+        comment: Option<&str>,
+    ) -> Result<(), Error> {
+        let original_length = data.len() as u32;
+        let data = match self.interface.snap_length as usize {
+            0 => data,
+            snap_length => &data[..data.len().min(snap_length)],
+        };
+        let resolution = self.interface.timestamp_resolution;
+
+        match self.format {
+            CaptureFormat::Pcap => self.write(PCAP_RECORD_HEADER_LENGTH + data.len(), |cursor| {
+                gen_pcap_record(cursor, timestamp, resolution, original_length, data)
+            }),
+            CaptureFormat::PcapNg => {
+                let mut options: PcapNgOptions = Vec::new();
+                if let Some(comment) = comment {
+                    if comment.len() > usize::from(u16::MAX) {
+                        return Err(Error::SerializeFailure(format!(
+                            "The comment is too long ({} bytes)",
+                            comment.len()
+                        )));
+                    }
+                    options.push((OPTION_COMMENT, comment.as_bytes().to_vec()));
+                }
+
+                let ticks = resolution.to_ticks(timestamp);
+                self.write(enhanced_packet_length(data.len(), &options), |cursor| {
+                    gen_enhanced_packet(cursor, 0, ticks, original_length, data, &options)
+                })
+            }
+        }
+    }
+
+    /// Serialize a [Frame] and write it as a packet.
+    ///
+    /// For the [LinkType::Radiotap] link type, the frame is prepended by the given radiotap
+    /// header or by an empty one. For [LinkType::Ieee80211], a radiotap header can't be
+    /// written. Other link types aren't supported.
+    ///
+    /// Serialized frames don't have an FCS. Hence, they can't be written to interfaces with
+    /// an FCS length or with a radiotap header, whose flags announce an FCS.
+    pub fn write_frame(
+        &mut self,
+        timestamp: Duration,
+        frame: &Frame,
+        radiotap: Option<&Radiotap>,
+        comment: Option<&str>,
+    ) -> Result<(), Error> {
+        let interface_fcs = self.interface.fcs_length.is_some_and(|length| length > 0);
+        if interface_fcs || radiotap.is_some_and(Radiotap::has_fcs) {
+            return Err(Error::SerializeFailure(
+                "Frames can't be written with an FCS, as it isn't computed".to_string(),
+            ));
+        }
+
+        let mut buffer = vec![0; FRAME_BUFFER_LENGTH];
+        let length = match (self.interface.link_type, radiotap) {
+            (LinkType::Ieee80211, None) => serialize_frame(&mut buffer, frame)?,
+            (LinkType::Radiotap, radiotap) => serialize_radiotap_frame(
+                &mut buffer,
+                radiotap.unwrap_or(&Radiotap::default()),
+                frame,
+            )?,
+            (link_type, _) => {
+                return Err(Error::UnhandledProtocol(format!(
+                    "Frames can't be written with the link type {:?} and radiotap header {:?}",
+                    link_type,
+                    radiotap.is_some()
+                )))
+            }
+        };
+
+        self.write_packet(timestamp, &buffer[..length], comment)
+    }
+
+    pub fn flush(&mut self) -> Result<(), Error> {
+        Ok(self.writer.flush()?)
+    }
+
+    /// Flush the writer and return it.
+    pub fn into_inner(mut self) -> W {
+        // Errors would be returned by the writer itself on the next write or flush.
+        let _ = self.writer.flush();
+        self.writer
+    }
+
+    /// Serialize a record with the given length and write it.
+    fn write<F>(&mut self, length: usize, generator: F) -> Result<(), Error>
+    where
+        F: for<'a> FnOnce(GenCursor<'a>) -> GenResult<'a>,
+    {
+        let mut buffer = vec![0; length];
+        generator((buffer.as_mut_slice(), 0))?;
+        self.writer.write_all(&buffer)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,492 @@
+use bitflags::bitflags;
+
+/// The Channel field of a radiotap header.
+///
+/// Structure:
+/// - 2 bytes: Frequency in MHz
+/// - 2 bytes: Channel flags
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Channel {
+    pub frequency: u16,
+    pub flags: ChannelFlags,
+}
+
+impl Channel {
+    /// The channel number of the frequency in the 2.4 GHz, 4.9 GHz, 5 GHz or 6 GHz band.
+    pub fn number(&self) -> Option<u8> {
+        frequency_to_channel(self.frequency)
+    }
+}
+
+/// Convert a frequency in MHz to its channel number.
+pub fn frequency_to_channel(frequency: u16) -> Option<u8> {
+    let channel = match frequency {
+        2484 => 14,
+        2412..=2472 => (frequency - 2407) / 5,
+        4910..=4980 => (frequency - 4000) / 5,
+        5000..=5895 => (frequency - 5000) / 5,
+        5935 => 2,
+        5955..=7115 => (frequency - 5950) / 5,
+        _ => return None,
+    };
+
+    u8::try_from(channel).ok()
+}
+
+bitflags! {
+    /// The flags of the [Channel] field.
+    pub struct ChannelFlags: u16 {
+        const TURBO             = 0x0010;
+        /// Complementary Code Keying (802.11b).
+        const CCK               = 0x0020;
+        /// Orthogonal Frequency-Division Multiplexing (802.11a/g).
+        const OFDM              = 0x0040;
+        const SPECTRUM_2GHZ     = 0x0080;
+        const SPECTRUM_5GHZ     = 0x0100;
+        /// Only passive scanning is allowed.
+        const PASSIVE           = 0x0200;
+        /// Dynamic CCK-OFDM.
+        const DYNAMIC           = 0x0400;
+        /// Gaussian Frequency Shift Keying.
+        const GFSK              = 0x0800;
+        const GSM               = 0x1000;
+        const STATIC_TURBO      = 0x2000;
+        const HALF_RATE         = 0x4000;
+        const QUARTER_RATE      = 0x8000;
+    }
+}
+
+/// The extended channel field (XChannel) of a radiotap header.
+///
+/// Structure:
+/// - 4 bytes: Flags
+/// - 2 bytes: Frequency in MHz
+/// - 1 byte: Channel number
+/// - 1 byte: Maximum transmit power
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct XChannel {
+    pub flags: u32,
+    pub frequency: u16,
+    pub channel: u8,
+    pub max_power: u8,
+}
+
+bitflags! {
+    /// Which information of the [Mcs] field is known.
+    pub struct McsKnown: u8 {
+        const BANDWIDTH         = 0x01;
+        const MCS_INDEX         = 0x02;
+        const GUARD_INTERVAL    = 0x04;
+        const HT_FORMAT         = 0x08;
+        const FEC_TYPE          = 0x10;
+        const STBC              = 0x20;
+        const NESS              = 0x40;
+        /// Bit 1 of the number of extension spatial streams.
+        const NESS_BIT_1        = 0x80;
+    }
+}
+
+/// The bandwidth of a HT transmission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum McsBandwidth {
+    Bandwidth20,
+    Bandwidth40,
+    /// The lower 20 MHz of a 40 MHz channel.
+    Bandwidth20Lower,
+    /// The upper 20 MHz of a 40 MHz channel.
+    Bandwidth20Upper,
+}
+
+/// The MCS field of a radiotap header, which describes a HT (802.11n) transmission.
+///
+/// Structure:
+/// - 1 byte: Known information ([McsKnown])
+/// - 1 byte: Flags
+///   - **bit_0-1**: Bandwidth
+///   - **bit_2**: Short guard interval
+///   - **bit_3**: Greenfield format
+///   - **bit_4**: LDPC FEC type
+///   - **bit_5-6**: Number of STBC streams
+///   - **bit_7**: Bit 0 of the number of extension spatial streams
+/// - 1 byte: MCS index
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mcs {
+    pub known: McsKnown,
+    pub flags: u8,
+    pub index: u8,
+}
+
+impl Mcs {
+    /// Describe a HT transmission with the given MCS index, bandwidth and guard interval,
+    /// e.g. for frame injection.
+    pub fn new(index: u8, bandwidth: McsBandwidth, short_gi: bool) -> Self {
+        let bandwidth = match bandwidth {
+            McsBandwidth::Bandwidth20 => 0,
+            McsBandwidth::Bandwidth40 => 1,
+            McsBandwidth::Bandwidth20Lower => 2,
+            McsBandwidth::Bandwidth20Upper => 3,
+        };
+
+        Mcs {
+            known: McsKnown::BANDWIDTH | McsKnown::MCS_INDEX | McsKnown::GUARD_INTERVAL,
+            flags: bandwidth | ((short_gi as u8) << 2),
+            index,
+        }
+    }
+
+    /// The MCS index, if it's known.
+    pub fn mcs_index(&self) -> Option<u8> {
+        self.known
+            .contains(McsKnown::MCS_INDEX)
+            .then_some(self.index)
+    }
+
+    pub fn bandwidth(&self) -> Option<McsBandwidth> {
+        let bandwidth = match self.flags & 0b11 {
+            0 => McsBandwidth::Bandwidth20,
+            1 => McsBandwidth::Bandwidth40,
+            2 => McsBandwidth::Bandwidth20Lower,
+            _ => McsBandwidth::Bandwidth20Upper,
+        };
+        self.known
+            .contains(McsKnown::BANDWIDTH)
+            .then_some(bandwidth)
+    }
+
+    /// Whether the short guard interval is used.
+    pub fn short_gi(&self) -> Option<bool> {
+        self.flag(McsKnown::GUARD_INTERVAL, 0x04)
+    }
+
+    /// Whether the greenfield format is used instead of the mixed format.
+    pub fn greenfield(&self) -> Option<bool> {
+        self.flag(McsKnown::HT_FORMAT, 0x08)
+    }
+
+    /// Whether LDPC is used instead of BCC.
+    pub fn ldpc(&self) -> Option<bool> {
+        self.flag(McsKnown::FEC_TYPE, 0x10)
+    }
+
+    /// The number of STBC streams (0-3).
+    pub fn stbc_streams(&self) -> Option<u8> {
+        self.known
+            .contains(McsKnown::STBC)
+            .then_some((self.flags >> 5) & 0b11)
+    }
+
+    fn flag(&self, known: McsKnown, mask: u8) -> Option<bool> {
+        self.known.contains(known).then_some(self.flags & mask != 0)
+    }
+}
+
+bitflags! {
+    /// The flags of the [AmpduStatus] field.
+    pub struct AmpduFlags: u16 {
+        /// Driver reports 0-length subframes.
+        const REPORT_ZERO_LENGTH    = 0x0001;
+        /// The frame is a 0-length subframe.
+        const IS_ZERO_LENGTH        = 0x0002;
+        /// The last subframe flag is known.
+        const LAST_KNOWN            = 0x0004;
+        /// The frame is the last subframe of this A-MPDU.
+        const IS_LAST               = 0x0008;
+        /// The delimiter CRC check failed.
+        const DELIMITER_CRC_ERROR   = 0x0010;
+        /// The delimiter CRC value is known.
+        const DELIMITER_CRC_KNOWN   = 0x0020;
+        /// The EOF bit of the delimiter.
+        const EOF                   = 0x0040;
+        /// The EOF value is known.
+        const EOF_KNOWN             = 0x0080;
+    }
+}
+
+/// The A-MPDU status field of a radiotap header.
+/// All frames with the same reference number were received in the same A-MPDU.
+///
+/// Structure:
+/// - 4 bytes: Reference number
+/// - 2 bytes: Flags ([AmpduFlags])
+/// - 1 byte: Delimiter CRC value
+/// - 1 byte: Reserved
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AmpduStatus {
+    pub reference: u32,
+    pub flags: AmpduFlags,
+    pub delimiter_crc: u8,
+}
+
+impl AmpduStatus {
+    /// Whether the frame is the last subframe of its A-MPDU, if that's known.
+    pub fn is_last(&self) -> Option<bool> {
+        self.flags
+            .contains(AmpduFlags::LAST_KNOWN)
+            .then_some(self.flags.contains(AmpduFlags::IS_LAST))
+    }
+}
+
+bitflags! {
+    /// Which information of the [Vht] field is known.
+    pub struct VhtKnown: u16 {
+        const STBC                  = 0x0001;
+        const TXOP_PS_NOT_ALLOWED   = 0x0002;
+        const GUARD_INTERVAL        = 0x0004;
+        const SHORT_GI_NSYM         = 0x0008;
+        const LDPC_EXTRA_SYMBOL     = 0x0010;
+        const BEAMFORMED            = 0x0020;
+        const BANDWIDTH             = 0x0040;
+        const GROUP_ID              = 0x0080;
+        const PARTIAL_AID           = 0x0100;
+    }
+}
+
+bitflags! {
+    /// The flags of the [Vht] field.
+    pub struct VhtFlags: u8 {
+        const STBC                  = 0x01;
+        const TXOP_PS_NOT_ALLOWED   = 0x02;
+        const SHORT_GI              = 0x04;
+        /// The short GI NSYM disambiguation bit.
+        const SHORT_GI_NSYM         = 0x08;
+        const LDPC_EXTRA_SYMBOL     = 0x10;
+        const BEAMFORMED            = 0x20;
+    }
+}
+
+/// The VHT field of a radiotap header, which describes a VHT (802.11ac) transmission.
+///
+/// Structure:
+/// - 2 bytes: Known information ([VhtKnown])
+/// - 1 byte: Flags ([VhtFlags])
+/// - 1 byte: Bandwidth
+/// - 4 bytes: MCS (upper 4 bits) and NSS (lower 4 bits) of up to four users
+/// - 1 byte: Coding, a bit per user. Set for LDPC.
+/// - 1 byte: Group ID
+/// - 2 bytes: Partial AID
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Vht {
+    pub known: VhtKnown,
+    pub flags: VhtFlags,
+    pub bandwidth: u8,
+    pub mcs_nss: [u8; 4],
+    pub coding: u8,
+    pub group_id: u8,
+    pub partial_aid: u16,
+}
+
+/// The bandwidth of a VHT transmission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VhtBandwidth {
+    Bandwidth20,
+    Bandwidth40,
+    Bandwidth80,
+    Bandwidth160,
+}
+
+/// The transmission parameters of a single user of a [Vht] transmission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VhtUser {
+    pub mcs: u8,
+    /// The number of spatial streams.
+    pub nss: u8,
+    /// Whether LDPC is used instead of BCC.
+    pub ldpc: bool,
+}
+
+impl Vht {
+    /// Describe a single user VHT transmission with the given MCS, number of spatial streams,
+    /// bandwidth and guard interval, e.g. for frame injection.
+    pub fn new(mcs: u8, nss: u8, bandwidth: VhtBandwidth, short_gi: bool) -> Self {
+        let bandwidth = match bandwidth {
+            VhtBandwidth::Bandwidth20 => 0,
+            VhtBandwidth::Bandwidth40 => 1,
+            VhtBandwidth::Bandwidth80 => 4,
+            VhtBandwidth::Bandwidth160 => 11,
+        };
+        let mut flags = VhtFlags::empty();
+        flags.set(VhtFlags::SHORT_GI, short_gi);
+
+        Vht {
+            known: VhtKnown::GUARD_INTERVAL | VhtKnown::BANDWIDTH,
+            flags,
+            bandwidth,
+            mcs_nss: [(mcs << 4) | (nss & 0x0f), 0, 0, 0],
+            coding: 0,
+            group_id: 0,
+            partial_aid: 0,
+        }
+    }
+
+    /// The bandwidth in MHz, if it's known.
+    pub fn bandwidth_mhz(&self) -> Option<u16> {
+        if !self.known.contains(VhtKnown::BANDWIDTH) {
+            return None;
+        }
+
+        match self.bandwidth {
+            0 => Some(20),
+            1..=3 => Some(40),
+            4..=10 => Some(80),
+            11..=25 => Some(160),
+            _ => None,
+        }
+    }
+
+    /// Whether the short guard interval is used.
+    pub fn short_gi(&self) -> Option<bool> {
+        self.known
+            .contains(VhtKnown::GUARD_INTERVAL)
+            .then_some(self.flags.contains(VhtFlags::SHORT_GI))
+    }
+
+    /// The users of this transmission. Users without spatial streams are skipped.
+    pub fn users(&self) -> impl Iterator<Item = VhtUser> + '_ {
+        self.mcs_nss
+            .iter()
+            .enumerate()
+            .filter(|(_, mcs_nss)| *mcs_nss & 0x0f != 0)
+            .map(|(index, mcs_nss)| VhtUser {
+                mcs: mcs_nss >> 4,
+                nss: mcs_nss & 0x0f,
+                ldpc: self.coding & (1 << index) != 0,
+            })
+    }
+}
+
+/// The PPDU format of a [He] transmission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HePpduFormat {
+    SingleUser,
+    ExtendedRangeSingleUser,
+    MultiUser,
+    TriggerBased,
+}
+
+/// The guard interval of a [He] transmission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HeGuardInterval {
+    Gi0_8,
+    Gi1_6,
+    Gi3_2,
+    Unknown(u8),
+}
+
+/// The HE field of a radiotap header, which describes a HE (802.11ax) transmission.
+///
+/// The field consists of six 16 bit words. `data1` and `data2` mark which information of the
+/// other words is known. The accessor methods only return information that's known.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct He {
+    pub data: [u16; 6],
+}
+
+impl He {
+    pub fn ppdu_format(&self) -> HePpduFormat {
+        match self.data[0] & 0b11 {
+            0 => HePpduFormat::SingleUser,
+            1 => HePpduFormat::ExtendedRangeSingleUser,
+            2 => HePpduFormat::MultiUser,
+            _ => HePpduFormat::TriggerBased,
+        }
+    }
+
+    pub fn bss_color(&self) -> Option<u8> {
+        self.known(0, 0x0004)
+            .then_some((self.data[2] & 0x003f) as u8)
+    }
+
+    pub fn mcs(&self) -> Option<u8> {
+        self.known(0, 0x0020)
+            .then_some(((self.data[2] & 0x0f00) >> 8) as u8)
+    }
+
+    /// Whether dual carrier modulation is used.
+    pub fn dcm(&self) -> Option<bool> {
+        self.known(0, 0x0040).then_some(self.data[2] & 0x1000 != 0)
+    }
+
+    /// Whether LDPC is used instead of BCC.
+    pub fn ldpc(&self) -> Option<bool> {
+        self.known(0, 0x0080).then_some(self.data[2] & 0x2000 != 0)
+    }
+
+    pub fn stbc(&self) -> Option<bool> {
+        self.known(0, 0x0200).then_some(self.data[2] & 0x8000 != 0)
+    }
+
+    /// The bandwidth or RU allocation.
+    /// `0-3` are 20, 40, 80 and 160 (or 80+80) MHz, `4-15` are resource units.
+    pub fn bandwidth_ru_allocation(&self) -> Option<u8> {
+        self.known(0, 0x4000)
+            .then_some((self.data[4] & 0x000f) as u8)
+    }
+
+    pub fn guard_interval(&self) -> Option<HeGuardInterval> {
+        let guard_interval = match (self.data[4] & 0x0030) >> 4 {
+            0 => HeGuardInterval::Gi0_8,
+            1 => HeGuardInterval::Gi1_6,
+            2 => HeGuardInterval::Gi3_2,
+            value => HeGuardInterval::Unknown(value as u8),
+        };
+        self.known(1, 0x0002).then_some(guard_interval)
+    }
+
+    /// The number of space-time streams.
+    pub fn nsts(&self) -> u8 {
+        (self.data[5] & 0x000f) as u8
+    }
+
+    fn known(&self, word: usize, mask: u16) -> bool {
+        self.data[word] & mask != 0
+    }
+}
+
+/// The unit of a [Timestamp].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimestampUnit {
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+    Unknown(u8),
+}
+
+/// The timestamp field of a radiotap header.
+///
+/// Structure:
+/// - 8 bytes: Timestamp
+/// - 2 bytes: Accuracy
+/// - 1 byte: Unit (lower 4 bits) and sampling position (upper 4 bits)
+/// - 1 byte: Flags
+///   - **bit_0**: The timestamp is a 32 bit counter
+///   - **bit_1**: The accuracy is known
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Timestamp {
+    pub timestamp: u64,
+    pub accuracy: u16,
+    pub unit_position: u8,
+    pub flags: u8,
+}
+
+impl Timestamp {
+    pub fn unit(&self) -> TimestampUnit {
+        match self.unit_position & 0x0f {
+            0 => TimestampUnit::Milliseconds,
+            1 => TimestampUnit::Microseconds,
+            2 => TimestampUnit::Nanoseconds,
+            unit => TimestampUnit::Unknown(unit),
+        }
+    }
+
+    /// Where in the PPDU the timestamp was taken.
+    /// `0` is the first bit of the MPDU, `1` the acquisition of the signal, `2` the end of
+    /// the PPDU, `3` the end of the MPDU and `15` means that it's unknown.
+    pub fn sampling_position(&self) -> u8 {
+        self.unit_position >> 4
+    }
+
+    /// The accuracy in units of the timestamp, if it's known.
+    pub fn accuracy(&self) -> Option<u16> {
+        (self.flags & 0x02 != 0).then_some(self.accuracy)
+    }
+}
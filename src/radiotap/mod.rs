@@ -0,0 +1,242 @@
+//! Radiotap headers, which are prepended to IEEE 802.11 frames by capture and injection
+//! interfaces.
+//!
+//! A radiotap header consists of a fixed part (version, padding, length), a list of presence
+//! bitmaps and the data of all fields that are marked as present.
+//! Each field is aligned to its natural boundary, relative to the start of the header.
+//!
+//! See <https://www.radiotap.org> for the definition of all fields.
+use bitflags::bitflags;
+
+mod fields;
+
+pub use fields::*;
+
+/// The length of the fixed part of a radiotap header (version, padding and length).
+pub(crate) const FIXED_HEADER_LENGTH: usize = 4;
+
+/// Presence bit: The next presence word starts (or resumes) the default radiotap namespace.
+pub(crate) const PRESENT_RADIOTAP_NAMESPACE: u32 = 1 << 29;
+/// Presence bit: A vendor namespace follows, whose presence words come next.
+pub(crate) const PRESENT_VENDOR_NAMESPACE: u32 = 1 << 30;
+/// Presence bit: Another presence word follows.
+pub(crate) const PRESENT_EXT: u32 = 1 << 31;
+
+// Bit numbers of the fields in the default radiotap namespace.
+pub(crate) const FIELD_TSFT: u32 = 0;
+pub(crate) const FIELD_FLAGS: u32 = 1;
+pub(crate) const FIELD_RATE: u32 = 2;
+pub(crate) const FIELD_CHANNEL: u32 = 3;
+pub(crate) const FIELD_FHSS: u32 = 4;
+pub(crate) const FIELD_ANTENNA_SIGNAL: u32 = 5;
+pub(crate) const FIELD_ANTENNA_NOISE: u32 = 6;
+pub(crate) const FIELD_LOCK_QUALITY: u32 = 7;
+pub(crate) const FIELD_TX_ATTENUATION: u32 = 8;
+pub(crate) const FIELD_DB_TX_ATTENUATION: u32 = 9;
+pub(crate) const FIELD_DBM_TX_POWER: u32 = 10;
+pub(crate) const FIELD_ANTENNA: u32 = 11;
+pub(crate) const FIELD_DB_ANTENNA_SIGNAL: u32 = 12;
+pub(crate) const FIELD_DB_ANTENNA_NOISE: u32 = 13;
+pub(crate) const FIELD_RX_FLAGS: u32 = 14;
+pub(crate) const FIELD_TX_FLAGS: u32 = 15;
+pub(crate) const FIELD_RTS_RETRIES: u32 = 16;
+pub(crate) const FIELD_DATA_RETRIES: u32 = 17;
+pub(crate) const FIELD_XCHANNEL: u32 = 18;
+pub(crate) const FIELD_MCS: u32 = 19;
+pub(crate) const FIELD_AMPDU_STATUS: u32 = 20;
+pub(crate) const FIELD_VHT: u32 = 21;
+pub(crate) const FIELD_TIMESTAMP: u32 = 22;
+pub(crate) const FIELD_HE: u32 = 23;
+pub(crate) const FIELD_HE_MU: u32 = 24;
+pub(crate) const FIELD_HE_MU_OTHER_USER: u32 = 25;
+pub(crate) const FIELD_ZERO_LENGTH_PSDU: u32 = 26;
+pub(crate) const FIELD_LSIG: u32 = 27;
+
+/// The alignment and size of a field in the default radiotap namespace.
+///
+/// Returns `None` for unknown fields. Their size isn't known, which is why no field after them
+/// can be read.
+pub(crate) fn field_layout(field: u32) -> Option<(usize, usize)> {
+    let layout = match field {
+        FIELD_TSFT => (8, 8),
+        FIELD_FLAGS | FIELD_RATE => (1, 1),
+        FIELD_CHANNEL => (2, 4),
+        FIELD_FHSS => (1, 2),
+        FIELD_ANTENNA_SIGNAL | FIELD_ANTENNA_NOISE => (1, 1),
+        FIELD_LOCK_QUALITY | FIELD_TX_ATTENUATION | FIELD_DB_TX_ATTENUATION => (2, 2),
+        FIELD_DBM_TX_POWER | FIELD_ANTENNA | FIELD_DB_ANTENNA_SIGNAL | FIELD_DB_ANTENNA_NOISE => {
+            (1, 1)
+        }
+        FIELD_RX_FLAGS | FIELD_TX_FLAGS => (2, 2),
+        FIELD_RTS_RETRIES | FIELD_DATA_RETRIES => (1, 1),
+        FIELD_XCHANNEL => (4, 8),
+        FIELD_MCS => (1, 3),
+        FIELD_AMPDU_STATUS => (4, 8),
+        FIELD_VHT => (2, 12),
+        FIELD_TIMESTAMP => (8, 12),
+        FIELD_HE | FIELD_HE_MU => (2, 12),
+        FIELD_HE_MU_OTHER_USER => (2, 6),
+        FIELD_ZERO_LENGTH_PSDU => (1, 1),
+        FIELD_LSIG => (2, 4),
+        _ => return None,
+    };
+
+    Some(layout)
+}
+
+/// A parsed radiotap header.
+///
+/// All fields of the default radiotap namespace, which have a typed representation, are
+/// available as `Option`s. Fields without one (e.g. FHSS or HE-MU) are skipped.
+///
+/// Some drivers report the signal of each antenna in additional radiotap namespaces.
+/// The top-level antenna fields contain the first reported values, which is usually the
+/// combined signal, while [Radiotap::antennas] contains the values of each namespace.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Radiotap {
+    /// The version of the radiotap header. Always `0`.
+    pub version: u8,
+    /// The length of the whole radiotap header in bytes.
+    pub length: u16,
+    /// All presence bitmaps as they have been received.
+    pub present: Vec<u32>,
+    /// The value of the TSF timer in microseconds, when the first bit of the MPDU arrived.
+    pub tsft: Option<u64>,
+    pub flags: Option<RadiotapFlags>,
+    /// The TX/RX data rate in 500 kbps units.
+    pub rate: Option<u8>,
+    pub channel: Option<Channel>,
+    /// The RF signal power at the antenna in dBm.
+    pub antenna_signal: Option<i8>,
+    /// The RF noise power at the antenna in dBm.
+    pub antenna_noise: Option<i8>,
+    /// The quality of the Barker code lock.
+    pub lock_quality: Option<u16>,
+    /// The transmit power as unitless distance from the maximum power.
+    pub tx_attenuation: Option<u16>,
+    /// The transmit power as dB distance from the maximum power.
+    pub db_tx_attenuation: Option<u16>,
+    /// The transmit power in dBm.
+    pub dbm_tx_power: Option<i8>,
+    /// The index of the antenna, which was used to transmit or receive the frame.
+    pub antenna: Option<u8>,
+    /// The RF signal power at the antenna in dB from an arbitrary, fixed reference.
+    pub db_antenna_signal: Option<u8>,
+    /// The RF noise power at the antenna in dB from an arbitrary, fixed reference.
+    pub db_antenna_noise: Option<u8>,
+    pub rx_flags: Option<RxFlags>,
+    pub tx_flags: Option<TxFlags>,
+    /// The number of RTS retries of a transmitted frame.
+    pub rts_retries: Option<u8>,
+    /// The number of data retries of a transmitted frame.
+    pub data_retries: Option<u8>,
+    pub xchannel: Option<XChannel>,
+    pub mcs: Option<Mcs>,
+    pub ampdu_status: Option<AmpduStatus>,
+    pub vht: Option<Vht>,
+    pub timestamp: Option<Timestamp>,
+    pub he: Option<He>,
+    /// The antenna fields of each radiotap namespace, which contains any of them.
+    pub antennas: Vec<AntennaInfo>,
+    /// All vendor namespaces. Their data isn't interpreted.
+    pub vendor_namespaces: Vec<VendorNamespace>,
+}
+
+impl Radiotap {
+    /// Whether the frame after the radiotap header ends with a frame check sequence.
+    pub fn has_fcs(&self) -> bool {
+        self.flags
+            .is_some_and(|flags| flags.contains(RadiotapFlags::FCS))
+    }
+
+    /// The data rate in kbps.
+    pub fn rate_kbps(&self) -> Option<u32> {
+        self.rate.map(|rate| u32::from(rate) * 500)
+    }
+}
+
+bitflags! {
+    /// Properties of transmitted and received frames.
+    pub struct RadiotapFlags: u8 {
+        /// The frame was sent/received during the contention free period.
+        const CFP               = 0x01;
+        const SHORT_PREAMBLE    = 0x02;
+        /// The frame was sent/received with WEP encryption.
+        const WEP               = 0x04;
+        /// The frame was sent/received with fragmentation.
+        const FRAGMENTATION     = 0x08;
+        /// The frame includes the FCS.
+        const FCS               = 0x10;
+        /// The frame has padding between the 802.11 header and the payload (to a 32-bit
+        /// boundary).
+        const DATA_PAD          = 0x20;
+        /// The frame failed the FCS check.
+        const BAD_FCS           = 0x40;
+        /// The frame was sent/received with HT short guard interval.
+        const SHORT_GI          = 0x80;
+    }
+}
+
+bitflags! {
+    /// Properties of received frames.
+    pub struct RxFlags: u16 {
+        /// The PLCP CRC check failed.
+        const BAD_PLCP          = 0x0002;
+    }
+}
+
+bitflags! {
+    /// Properties of transmitted frames, or how frames should be transmitted on injection.
+    pub struct TxFlags: u16 {
+        /// The transmission failed due to excessive retries.
+        const FAIL              = 0x0001;
+        /// The transmission used CTS-to-self protection.
+        const CTS               = 0x0002;
+        /// The transmission used RTS/CTS handshake.
+        const RTS               = 0x0004;
+        /// The transmission shall not expect an ACK frame and not retry when no ACK is received.
+        const NO_ACK            = 0x0008;
+        /// The transmitted frame includes a pre-configured sequence number, which must not be
+        /// overwritten by the driver.
+        const NO_SEQNO          = 0x0010;
+        /// The transmitted frame should not be reordered relative to other frames that have
+        /// this flag set.
+        const ORDER             = 0x0020;
+    }
+}
+
+/// The antenna fields of a single radiotap namespace.
+///
+/// Drivers with multiple antennas often report the signal of each antenna in its own
+/// namespace.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AntennaInfo {
+    /// The index of the antenna.
+    pub antenna: Option<u8>,
+    /// The RF signal power at the antenna in dBm.
+    pub signal: Option<i8>,
+    /// The RF noise power at the antenna in dBm.
+    pub noise: Option<i8>,
+}
+
+impl AntennaInfo {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.antenna.is_none() && self.signal.is_none() && self.noise.is_none()
+    }
+}
+
+/// A vendor namespace of a radiotap header.
+///
+/// Structure:
+/// - 3 bytes: OUI
+/// - 1 byte: Sub namespace
+/// - 2 bytes: Skip length, the length of the vendor data
+/// - Variable: Vendor data
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct VendorNamespace {
+    pub oui: [u8; 3],
+    pub sub_namespace: u8,
+    /// The presence bitmaps of this namespace. Their meaning is defined by the vendor.
+    pub present: Vec<u32>,
+    pub data: Vec<u8>,
+}
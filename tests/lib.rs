@@ -1,4 +1,7 @@
 use libwifi::frame::components::MacAddress;
+use libwifi::frame::Frame;
+use libwifi::{parse_frame, serialize_frame};
+use pretty_hex::pretty_hex;
 
 mod parsing_tests;
 mod serializing_tests;
@@ -15,3 +18,51 @@ fn compare_byte_slice(a: &[u8], b: &[u8]) -> bool {
         a.iter().zip(b.iter()).all(|(a, b)| *a == *b)
     }
 }
+
+/// Parse a captured frame, serialize it again and ensure that both byte representations match.
+fn assert_round_trip(payload: &[u8]) {
+    let frame = parse_frame(payload, false).expect("Payload should be valid");
+    let mut buffer = [0_u8; 2304];
+    let bytes_written =
+        serialize_frame(buffer.as_mut_slice(), &frame).expect("Couldn't serialize frame!");
+    let frame_raw = &buffer[..bytes_written];
+    println!("{}", pretty_hex(&frame_raw));
+
+    if !compare_byte_slice(frame_raw, payload) {
+        panic!("Frame doesn't match ground truth!");
+    }
+}
+
+/// Build a raw beacon frame with the given elements as body.
+fn beacon_with_elements(elements: &[u8]) -> Vec<u8> {
+    let mut payload = vec![
+        128, 0, // FrameControl
+        0, 0, // Duration id
+        255, 255, 255, 255, 255, 255, // First address
+        248, 50, 228, 173, 71, 184, // Second address
+        248, 50, 228, 173, 71, 184, // Third address
+        96, 119, // SequenceControl
+        151, 161, 39, 206, 165, 0, 0, 0, // Timestamp
+        100, 0, // Beacon interval
+        17, 4, // Capability info
+    ];
+    payload.extend_from_slice(elements);
+
+    payload
+}
+
+/// Build a raw beacon frame, whose only element is the SSID "Test".
+fn test_beacon() -> Vec<u8> {
+    beacon_with_elements(&[0, 4, 84, 101, 115, 116])
+}
+
+/// Ensure that the frame is the beacon of [test_beacon].
+fn assert_test_beacon(frame: &Frame) {
+    match frame {
+        Frame::Beacon(beacon) => {
+            assert_eq!(beacon.station_info.ssid(), Some("Test".to_string()));
+            assert_eq!(beacon.station_info.elements.len(), 1);
+        }
+        _ => panic!("Expected a beacon frame"),
+    }
+}
@@ -0,0 +1,986 @@
+use libwifi::error::Error;
+use libwifi::frame::components::*;
+use libwifi::frame::*;
+use libwifi::parse_frame;
+
+use crate::beacon_with_elements;
+
+fn parse_station_info(elements: &[u8]) -> StationInfo {
+    let payload = beacon_with_elements(elements);
+    match parse_frame(&payload, false).expect("Payload should be valid") {
+        Frame::Beacon(beacon) => beacon.station_info,
+        _ => panic!("Expected a beacon frame"),
+    }
+}
+
+#[test]
+fn test_wpa2_personal_rsn() {
+    let station_info = parse_station_info(&[
+        48, 20, // RSN header
+        1, 0, // Version
+        0, 15, 172, 4, // Group cipher: CCMP-128
+        1, 0, 0, 15, 172, 4, // Pairwise ciphers: CCMP-128
+        1, 0, 0, 15, 172, 2, // AKM suites: PSK
+        12, 0, // RSN capabilities
+    ]);
+
+    let rsn = station_info
+        .rsn_information()
+        .expect("RSN should be parsed");
+    assert_eq!(rsn.version, 1);
+    assert_eq!(rsn.group_cipher, CipherSuite::Ccmp128);
+    assert_eq!(rsn.pairwise_ciphers, vec![CipherSuite::Ccmp128]);
+    assert_eq!(rsn.akm_suites, vec![AkmSuite::Psk]);
+    assert_eq!(rsn.capabilities, RsnCapabilities::PTKSA_REPLAY_COUNTER);
+    assert!(!rsn.mfp_capable());
+    assert!(rsn.pmkids.is_empty());
+    assert_eq!(rsn.group_management_cipher, None);
+    assert_eq!(station_info.elements.len(), 1);
+}
+
+#[test]
+fn test_wpa3_transition_rsn() {
+    let station_info = parse_station_info(&[
+        48, 54, // RSN header
+        1, 0, // Version
+        0, 15, 172, 4, // Group cipher: CCMP-128
+        2, 0, 0, 15, 172, 4, 0, 15, 172, 8, // Pairwise ciphers: CCMP-128, GCMP-128
+        3, 0, 0, 15, 172, 2, 0, 15, 172, 8, 0, 15, 172, 9, // AKM suites: PSK, SAE, FT-SAE
+        192, 0, // RSN capabilities: MFP required and capable
+        1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, // PMKIDs
+        0, 15, 172, 6, // Group management cipher: BIP-CMAC-128
+    ]);
+
+    let rsn = station_info
+        .rsn_information()
+        .expect("RSN should be parsed");
+    assert_eq!(
+        rsn.pairwise_ciphers,
+        vec![CipherSuite::Ccmp128, CipherSuite::Gcmp128]
+    );
+    assert_eq!(
+        rsn.akm_suites,
+        vec![AkmSuite::Psk, AkmSuite::Sae, AkmSuite::FtSae]
+    );
+    assert!(rsn.akm_suites[2].is_fast_transition());
+    assert!(rsn.mfp_required());
+    assert!(rsn.mfp_capable());
+    assert_eq!(
+        rsn.pmkids,
+        vec![[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]]
+    );
+    assert_eq!(rsn.group_management_cipher, Some(CipherSuite::BipCmac128));
+}
+
+#[test]
+fn test_truncated_rsn_uses_defaults() {
+    let station_info = parse_station_info(&[
+        48, 6, // RSN header
+        1, 0, // Version
+        0, 15, 172, 2, // Group cipher: TKIP
+    ]);
+
+    let rsn = station_info
+        .rsn_information()
+        .expect("RSN should be parsed");
+    assert_eq!(rsn.group_cipher, CipherSuite::Tkip);
+    assert_eq!(rsn.pairwise_ciphers, vec![CipherSuite::Ccmp128]);
+    assert_eq!(rsn.akm_suites, vec![AkmSuite::Ieee8021x]);
+}
+
+#[test]
+fn test_vendor_rsn_suites() {
+    let station_info = parse_station_info(&[
+        48, 20, // RSN header
+        1, 0, // Version
+        0, 15, 172, 4, // Group cipher: CCMP-128
+        1, 0, 0, 15, 172, 4, // Pairwise ciphers: CCMP-128
+        1, 0, 0, 16, 24, 1, // AKM suites: Vendor specific
+        0, 0, // RSN capabilities
+    ]);
+
+    let rsn = station_info
+        .rsn_information()
+        .expect("RSN should be parsed");
+    assert_eq!(
+        rsn.akm_suites,
+        vec![AkmSuite::Other {
+            oui: [0, 16, 24],
+            suite_type: 1
+        }]
+    );
+}
+
+#[test]
+fn test_malformed_rsn_is_kept() {
+    let station_info = parse_station_info(&[
+        48, 8, // RSN header
+        1, 0, // Version
+        0, 15, 172, 4, // Group cipher: CCMP-128
+        2, 0, // Pairwise cipher count, but no ciphers
+    ]);
+
+    assert!(station_info.rsn_information().is_none());
+    assert_eq!(station_info.elements.len(), 1);
+}
+
+#[test]
+fn test_wpa_tkip() {
+    let station_info = parse_station_info(&[
+        221, 22, // Vendor specific header
+        0, 80, 242, 1, // Microsoft OUI, WPA
+        1, 0, // Version
+        0, 80, 242, 2, // Group cipher: TKIP
+        1, 0, 0, 80, 242, 2, // Pairwise ciphers: TKIP
+        1, 0, 0, 80, 242, 2, // AKM suites: PSK
+    ]);
+
+    let wpa = station_info
+        .wpa_information()
+        .expect("WPA should be parsed");
+    assert_eq!(wpa.version, 1);
+    assert_eq!(wpa.group_cipher, CipherSuite::Tkip);
+    assert_eq!(wpa.pairwise_ciphers, vec![CipherSuite::Tkip]);
+    assert_eq!(wpa.akm_suites, vec![AkmSuite::Psk]);
+    assert_eq!(wpa.capabilities, None);
+    assert!(wpa.uses_tkip());
+    assert_eq!(station_info.elements.len(), 1);
+}
+
+#[test]
+fn test_wmm_parameter() {
+    let station_info = parse_station_info(&[
+        221, 24, // Vendor specific header
+        0, 80, 242, 2, // Microsoft OUI, WMM
+        1, 1, // Parameter subtype, version
+        132, 0, // QoS info, reserved
+        3, 164, 0, 0, // Best effort
+        39, 164, 0, 0, // Background
+        66, 67, 94, 0, // Video
+        98, 50, 47, 0, // Voice
+    ]);
+
+    let Some(Wmm::Parameter(wmm)) = station_info.wmm() else {
+        panic!("WMM parameter element should be parsed");
+    };
+    assert_eq!(wmm.qos_info, 132);
+
+    let best_effort = wmm.ac_parameter(AccessCategory::BestEffort).unwrap();
+    assert_eq!(best_effort.aifsn, 3);
+    assert_eq!(best_effort.cw_min(), 15);
+    assert_eq!(best_effort.cw_max(), 1023);
+    assert_eq!(best_effort.txop_limit, 0);
+
+    let video = wmm.ac_parameter(AccessCategory::Video).unwrap();
+    assert_eq!(video.aifsn, 2);
+    assert_eq!(video.ecw_min, 3);
+    assert_eq!(video.ecw_max, 4);
+    assert_eq!(video.txop_limit_us(), 3008);
+
+    let voice = wmm.ac_parameter(AccessCategory::Voice).unwrap();
+    assert!(!voice.admission_control_mandatory);
+    assert_eq!(voice.cw_min(), 3);
+    assert_eq!(voice.txop_limit, 47);
+
+    // Out of range exponents are masked to the 4 bit field.
+    let mut invalid = voice.clone();
+    invalid.ecw_min = 0x13;
+    invalid.ecw_max = 0xff;
+    assert_eq!(invalid.cw_min(), 7);
+    assert_eq!(invalid.cw_max(), 32767);
+}
+
+#[test]
+fn test_wmm_information() {
+    let station_info = parse_station_info(&[
+        221, 7, // Vendor specific header
+        0, 80, 242, 2, // Microsoft OUI, WMM
+        0, 1, 15, // Information subtype, version, QoS info
+    ]);
+
+    assert_eq!(
+        station_info.wmm(),
+        Some(Wmm::Information(WmmInformation {
+            version: 1,
+            qos_info: 15
+        }))
+    );
+}
+
+#[test]
+fn test_unknown_microsoft_element_is_kept() {
+    let station_info = parse_station_info(&[
+        221, 14, // Vendor specific header
+        0, 80, 242, 4, // Microsoft OUI, WPS
+        16, 74, 0, 1, 16, 16, 68, 0, 1, 2, // WPS attributes
+    ]);
+
+    assert!(station_info.wpa_information().is_none());
+    assert!(station_info.wmm().is_none());
+    assert_eq!(station_info.elements.len(), 1);
+}
+
+#[test]
+fn test_ht_capabilities() {
+    let station_info = parse_station_info(&[
+        45, 26, // HT capabilities header
+        189, 25, // HT capability info
+        23, // A-MPDU parameters
+        255, 255, 255, 0, 0, 0, 0, 0, 0, 0, // Rx MCS bitmask
+        44, 1, // Rx highest supported data rate
+        1, 0, 0, 0, // Tx MCS set
+        0, 0, // Extended HT capabilities
+        0, 0, 0, 0, // TxBF capabilities
+        0, // ASEL capabilities
+    ]);
+
+    let ht = station_info
+        .ht_capabilities()
+        .expect("HT capabilities should be parsed");
+    type Info = HtCapabilityInfo;
+    assert_eq!(ht.ht_capability_info.bits(), 0x19bd);
+    assert!(ht
+        .ht_capability_info
+        .contains(Info::LDPC_CODING | Info::GREENFIELD | Info::SHORT_GI_20MHZ | Info::TX_STBC));
+    assert_eq!(ht.ht_capability_info.sm_power_save(), 3);
+    assert_eq!(ht.ht_capability_info.rx_stbc_streams(), 1);
+    assert!(!ht.ht_capability_info.contains(Info::CHANNEL_WIDTH_40MHZ));
+
+    assert_eq!(ht.ampdu_parameters.max_ampdu_length_exponent, 3);
+    assert_eq!(ht.ampdu_parameters.max_ampdu_length(), 65535);
+    assert_eq!(ht.ampdu_parameters.min_mpdu_start_spacing, 5);
+
+    let mcs = ht.supported_mcs_set;
+    assert!(mcs.supports_mcs(0));
+    assert!(mcs.supports_mcs(23));
+    assert!(!mcs.supports_mcs(24));
+    assert_eq!(mcs.rx_highest_data_rate, 300);
+    assert!(mcs.tx_mcs_set_defined);
+    assert!(!mcs.tx_rx_mcs_set_not_equal);
+
+    assert!(ht.extended_capabilities.is_empty());
+    assert!(ht.txbf_capabilities.is_empty());
+    assert!(ht.asel_capabilities.is_empty());
+}
+
+#[test]
+fn test_ht_operation() {
+    let station_info = parse_station_info(&[
+        61, 22, // HT operation header
+        36, // Primary channel
+        13, // Secondary channel above, any channel width, RIFS
+        22, 0, // Nongreenfield STAs present, OBSS non-HT STAs present, 20 MHz protection
+        0, 1, // STBC beacon
+        255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // Basic MCS set
+    ]);
+
+    let ht = station_info
+        .ht_operation()
+        .expect("HT operation should be parsed");
+    assert_eq!(ht.primary_channel, 36);
+    assert_eq!(ht.secondary_channel_offset, SecondaryChannelOffset::Above);
+    assert!(ht.sta_channel_width);
+    assert!(ht.rifs_mode);
+    assert_eq!(ht.ht_protection, HtProtection::TwentyMhzProtection);
+    assert!(ht.nongreenfield_stas_present);
+    assert!(ht.obss_non_ht_stas_present);
+    assert!(!ht.dual_beacon);
+    assert!(ht.stbc_beacon);
+    assert!(ht.basic_mcs_set.supports_mcs(7));
+    assert!(!ht.basic_mcs_set.supports_mcs(8));
+}
+
+#[test]
+fn test_vht_capabilities() {
+    let station_info = parse_station_info(&[
+        191, 12, // VHT capabilities header
+        178, 97, 128, 51, // VHT capabilities info
+        254, 255, 134, 1, // Rx MCS map, Rx highest data rate
+        254, 255, 134, 1, // Tx MCS map, Tx highest data rate
+    ]);
+
+    let vht = station_info
+        .vht_capabilities()
+        .expect("VHT capabilities should be parsed");
+    let info = vht.vht_capability_info;
+    assert_eq!(info.bits(), 0x338061b2);
+    assert_eq!(info.max_mpdu_length(), 11454);
+    assert_eq!(info.supported_channel_width_set(), 0);
+    assert!(!info.supports_160mhz());
+    assert!(info.contains(VhtCapabilityInfo::RX_LDPC | VhtCapabilityInfo::SHORT_GI_80MHZ));
+    assert!(!info.contains(VhtCapabilityInfo::SU_BEAMFORMER));
+    assert!(!info.contains(VhtCapabilityInfo::MU_BEAMFORMEE));
+    assert_eq!(info.rx_stbc_streams(), 1);
+    assert_eq!(info.beamformee_sts(), 3);
+    assert_eq!(info.max_ampdu_length(), 1048575);
+
+    let mcs_set = vht.supported_mcs_set;
+    assert_eq!(mcs_set.rx_mcs_map.max_mcs(1), Some(9));
+    assert_eq!(mcs_set.rx_mcs_map.max_mcs(2), None);
+    assert_eq!(mcs_set.rx_mcs_map.max_spatial_streams(), 1);
+    assert_eq!(mcs_set.rx_highest_data_rate, 390);
+    assert_eq!(mcs_set.max_nsts_total, 0);
+    assert!(!mcs_set.extended_nss_bw_capable);
+}
+
+#[test]
+fn test_vht_operation() {
+    let station_info = parse_station_info(&[
+        192, 5, // VHT operation header
+        1, 42, 50, // Channel width, center frequency segments
+        252, 255, // Basic MCS set
+    ]);
+
+    let vht = station_info
+        .vht_operation()
+        .expect("VHT operation should be parsed");
+    assert_eq!(vht.channel_width, VhtChannelWidth::EightyMhzOrMore);
+    assert_eq!(vht.channel_center_frequency_segment_0, 42);
+    assert_eq!(vht.channel_center_frequency_segment_1, 50);
+    assert!(vht.is_160mhz());
+    assert!(!vht.is_80_plus_80mhz());
+    assert_eq!(vht.basic_mcs_set.max_mcs(1), Some(7));
+    assert_eq!(vht.basic_mcs_set.max_spatial_streams(), 1);
+}
+
+#[test]
+fn test_he_capabilities() {
+    let station_info = parse_station_info(&[
+        255, 32, 35, // HE capabilities header
+        9, 0, 8, 18, 0, 16, // MAC capabilities
+        12, 32, 14, 64, 9, 0, 128, 12, 0, 0, 0, // PHY capabilities
+        250, 255, 250, 255, // HE-MCS map <= 80 MHz
+        250, 255, 250, 255, // HE-MCS map 160 MHz
+        57, 28, 199, 113, 28, 7, // PPE thresholds
+    ]);
+
+    let he = station_info
+        .he_capabilities()
+        .expect("HE capabilities should be parsed");
+    assert!(he.htc_he_support());
+    assert!(!he.twt_requester_support());
+    assert!(he.supports_80mhz());
+    assert!(he.supports_160mhz());
+    assert!(!he.supports_80_plus_80mhz());
+    assert_eq!(he.mcs_nss_80mhz.rx_mcs_map.max_mcs(1), Some(11));
+    assert_eq!(he.mcs_nss_80mhz.rx_mcs_map.max_spatial_streams(), 2);
+    assert!(he.mcs_nss_160mhz.is_some());
+    assert!(he.mcs_nss_80_plus_80mhz.is_none());
+    assert!(he.ppe_thresholds_present());
+    assert_eq!(he.ppe_thresholds, vec![57, 28, 199, 113, 28, 7]);
+}
+
+#[test]
+fn test_he_operation() {
+    let station_info = parse_station_info(&[
+        255, 12, 36, // HE operation header
+        244, 63, 2,  // HE operation parameters
+        21, // BSS color information
+        252, 255, // Basic HE-MCS and NSS set
+        37, 15, 39, 47, 6, // 6 GHz operation information
+    ]);
+
+    let he = station_info
+        .he_operation()
+        .expect("HE operation should be parsed");
+    assert_eq!(he.default_pe_duration, 4);
+    assert!(!he.twt_required);
+    assert_eq!(he.txop_duration_rts_threshold, 1023);
+    assert_eq!(he.bss_color, 21);
+    assert!(!he.bss_color_disabled);
+    assert_eq!(he.basic_mcs_set.max_mcs(1), Some(7));
+    assert!(he.vht_operation_info.is_none());
+    assert!(he.max_cohosted_bssid_indicator.is_none());
+
+    let six_ghz = he
+        .six_ghz_operation_info
+        .expect("6 GHz info should be parsed");
+    assert_eq!(six_ghz.primary_channel, 37);
+    assert_eq!(six_ghz.channel_width, 3);
+    assert!(six_ghz.duplicate_beacon);
+    assert_eq!(six_ghz.regulatory_info, 1);
+    assert_eq!(six_ghz.channel_center_frequency_segment_1, 47);
+    assert_eq!(six_ghz.minimum_rate, 6);
+}
+
+#[test]
+fn test_eht_elements() {
+    let station_info = parse_station_info(&[
+        255, 21, 108, // EHT capabilities header
+        3, 0, // MAC capabilities
+        2, 0, 0, 0, 0, 0, 0, 0, 0, // PHY capabilities
+        68, 68, 68, 68, 68, 68, 68, 68, 68, // EHT-MCS maps
+        255, 11, 106, // EHT operation header
+        3,   // EHT operation parameters
+        68, 68, 68, 68, // Basic EHT-MCS and NSS set
+        4, 47, 31, 2, 0, // EHT operation information
+    ]);
+
+    let eht = station_info
+        .eht_capabilities()
+        .expect("EHT capabilities should be parsed");
+    assert!(eht.epcs_priority_access_support());
+    assert!(eht.om_control_support());
+    assert!(eht.supports_320mhz());
+    assert!(!eht.ppe_thresholds_present());
+    assert_eq!(eht.mcs_nss_and_ppe_thresholds.len(), 9);
+
+    let eht = station_info
+        .eht_operation()
+        .expect("EHT operation should be parsed");
+    let info = eht
+        .operation_info
+        .expect("EHT operation info should be parsed");
+    assert_eq!(info.channel_width_mhz(), Some(320));
+    assert_eq!(info.channel_center_frequency_segment_0, 47);
+    assert_eq!(info.disabled_subchannel_bitmap, Some(2));
+}
+
+#[test]
+fn test_unhandled_extension_element() {
+    let station_info = parse_station_info(&[
+        255, 14, 38, // MU EDCA parameter set header
+        0, 3, 164, 8, 39, 164, 8, 66, 67, 8, 98, 50, 8, // MU EDCA parameters
+    ]);
+
+    let extensions: Vec<_> = station_info.raw_extensions().collect();
+    assert_eq!(extensions.len(), 1);
+    assert_eq!(extensions[0].0, ExtensionId::MuEdcaParameterSet);
+    assert_eq!(extensions[0].1.len(), 13);
+}
+
+/// A beacon of an AP MLD, which reports a second link with a per-STA profile.
+const MULTI_LINK_BEACON_ELEMENTS: &[u8] = &[
+    0, 4, 84, 101, 115, 116, // SSID
+    1, 4, 130, 132, 139, 150, // Supported rates
+    3, 1, 6, // DS parameter set
+    48, 20, 1, 0, 0, 15, 172, 4, 1, 0, 0, 15, 172, 4, 1, 0, 0, 15, 172, 8, 192, 0, // RSN
+    255, 41, 107, // Multi-Link header
+    48, 1, // Control: Basic, link ID, change count and MLD capabilities present
+    11, 2, 17, 34, 51, 68, 85, 0, 3, 1, 0, // Common info
+    0, 25, // Per-STA profile header
+    113, 9, // STA control: Link 1, complete profile
+    12, 248, 50, 228, 173, 71, 185, 100, 0, 0, 1, 2, // STA info
+    17, 4, // Capability info
+    3, 1, 36, // DS parameter set
+    255, 4, 56, 1, 221, 0, // Non-Inheritance: Vendor specific
+    221, 7, 0, 80, 242, 2, 0, 1, 0, // WMM information
+];
+
+#[test]
+fn test_basic_multi_link() {
+    let station_info = parse_station_info(MULTI_LINK_BEACON_ELEMENTS);
+
+    let Some(MultiLink::Basic(multi_link)) = station_info.multi_link() else {
+        panic!("Multi-Link element should be parsed");
+    };
+    assert_eq!(
+        multi_link.mld_mac_address,
+        MacAddress([2, 17, 34, 51, 68, 85])
+    );
+    assert_eq!(multi_link.link_id, Some(0));
+    assert_eq!(multi_link.bss_parameters_change_count, Some(3));
+    assert_eq!(multi_link.eml_capabilities, None);
+    assert_eq!(
+        multi_link
+            .mld_capabilities
+            .map(|caps| caps.max_simultaneous_links()),
+        Some(1)
+    );
+
+    let profile = &multi_link.per_sta_profiles[0];
+    assert_eq!(profile.link_id, 1);
+    assert!(profile.complete_profile);
+    assert_eq!(profile.beacon_interval, Some(100));
+    assert_eq!(
+        profile.dtim_info,
+        Some(DtimInfo {
+            dtim_count: 0,
+            dtim_period: 1
+        })
+    );
+    assert_eq!(profile.tsf_offset, None);
+    assert_eq!(profile.bss_parameters_change_count, Some(2));
+    assert_eq!(profile.sta_profile.len(), 11);
+
+    let links: Vec<_> = multi_link.link_addresses().collect();
+    assert_eq!(links, vec![(1, MacAddress([248, 50, 228, 173, 71, 185]))]);
+}
+
+#[test]
+fn test_multi_link_profile_inheritance() {
+    let station_info = parse_station_info(MULTI_LINK_BEACON_ELEMENTS);
+    let multi_link = station_info.multi_link().unwrap();
+
+    // Beacons only have the capability info as fixed field in the STA profile.
+    let link = multi_link.per_sta_profiles()[0]
+        .station_info(&station_info, 2)
+        .expect("Profile should be valid");
+
+    // Inherited from the reporting frame
+    assert_eq!(link.ssid(), Some("Test".to_string()));
+    assert_eq!(link.supported_rates().len(), 4);
+    assert_eq!(link.rsn_information(), station_info.rsn_information());
+    // Overridden by the profile
+    assert_eq!(
+        link.element(ManagementInfoId::DsParameterSet),
+        Some(&[36][..])
+    );
+    assert_eq!(link.elements.len(), 4);
+    // Listed in the Non-Inheritance element
+    assert!(link.wmm().is_none());
+    // Never inherited
+    assert!(link.multi_link().is_none());
+}
+
+#[test]
+fn test_probe_request_multi_link() {
+    let station_info = parse_station_info(&[
+        255, 9, 107, // Multi-Link header
+        17, 0, // Control: Probe request, AP MLD ID present
+        2, 0, // Common info
+        0, 2, 17, 0, // Per-STA profile: Link 1, complete profile requested
+    ]);
+
+    let Some(MultiLink::ProbeRequest(multi_link)) = station_info.multi_link() else {
+        panic!("Multi-Link element should be parsed");
+    };
+    assert_eq!(multi_link.ap_mld_id, Some(0));
+    assert_eq!(multi_link.per_sta_profiles.len(), 1);
+    assert_eq!(multi_link.per_sta_profiles[0].link_id, 1);
+    assert!(multi_link.per_sta_profiles[0].complete_profile);
+    assert!(multi_link.per_sta_profiles[0].sta_mac_address.is_none());
+}
+
+#[test]
+fn test_unhandled_multi_link_variant_is_kept() {
+    let station_info = parse_station_info(&[
+        255, 4, 107, // Multi-Link header
+        2, 0, // Control: Reconfiguration
+        1, // Common info
+    ]);
+
+    assert!(station_info.multi_link().is_none());
+    let extensions: Vec<_> = station_info.raw_extensions().collect();
+    assert_eq!(extensions, vec![(ExtensionId::MultiLink, &[2, 0, 1][..])]);
+}
+
+#[test]
+fn test_unknown_elements_are_kept() {
+    let station_info = parse_station_info(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        47, 1, 0, // ERP (legacy id)
+        127, 8, 4, 0, 8, 0, 0, 0, 0, 64, // Extended capabilities
+        254, 3, 1, 2, 3, // Unassigned id
+    ]);
+
+    assert_eq!(
+        station_info.elements[1..],
+        [
+            (ManagementInfoId::Unknown(47), vec![0]),
+            (
+                ManagementInfoId::Unknown(127),
+                vec![4, 0, 8, 0, 0, 0, 0, 64]
+            ),
+            (ManagementInfoId::Unknown(254), vec![1, 2, 3]),
+        ]
+    );
+    assert_eq!(u8::from(station_info.elements[2].0), 127);
+}
+
+#[test]
+fn test_visible_ssid() {
+    let station_info = parse_station_info(&[0, 4, 84, 101, 115, 116]);
+
+    assert_eq!(station_info.ssid_bytes(), Some(&b"Test"[..]));
+    assert_eq!(station_info.ssid(), Some("Test".to_string()));
+    assert_eq!(station_info.hidden_ssid(), None);
+    assert!(!station_info.is_wildcard_ssid());
+}
+
+#[test]
+fn test_empty_hidden_ssid() {
+    let station_info = parse_station_info(&[
+        0, 0, // Empty SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+    ]);
+
+    assert_eq!(station_info.ssid_bytes(), Some(&[][..]));
+    assert_eq!(station_info.hidden_ssid(), Some(HiddenSsid::Empty));
+    assert!(station_info.is_wildcard_ssid());
+}
+
+#[test]
+fn test_nul_padded_hidden_ssid() {
+    let station_info = parse_station_info(&[
+        0, 7, 0, 0, 0, 0, 0, 0, 0, // NUL-padded SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+    ]);
+
+    assert_eq!(station_info.ssid_bytes(), Some(&[0; 7][..]));
+    assert_eq!(station_info.ssid(), Some("       ".to_string()));
+    assert_eq!(station_info.hidden_ssid(), Some(HiddenSsid::NulPadded(7)));
+    assert!(!station_info.is_wildcard_ssid());
+}
+
+#[test]
+fn test_non_utf8_ssid() {
+    let station_info = parse_station_info(&[
+        0, 5, 84, 101, 255, 115, 116, // SSID with an invalid UTF-8 byte
+        1, 4, 130, 132, 139, 150, // Supported rates
+    ]);
+
+    assert_eq!(
+        station_info.ssid_bytes(),
+        Some(&[84, 101, 255, 115, 116][..])
+    );
+    assert_eq!(station_info.ssid(), Some("Te\u{FFFD}st".to_string()));
+    assert_eq!(station_info.hidden_ssid(), None);
+}
+
+#[test]
+fn test_supported_and_extended_rates_are_merged() {
+    let station_info = parse_station_info(&[
+        1, 8, 130, 132, 139, 150, 12, 18, 152, 36, // Supported rates
+        3, 1, 6, // DS parameter set
+        50, 7, 176, 72, 96, 108, 255, 254, 251, // Extended supported rates
+    ]);
+
+    let rates = station_info.supported_rates();
+    assert_eq!(rates.len(), 15);
+
+    let basic: Vec<_> = rates
+        .iter()
+        .filter(|rate| rate.is_basic())
+        .filter_map(|rate| rate.kbps())
+        .collect();
+    assert_eq!(basic, vec![1000, 2000, 5500, 11000, 12000, 24000]);
+
+    let all: Vec<_> = rates.iter().filter_map(|rate| rate.kbps()).collect();
+    assert_eq!(
+        all,
+        vec![1000, 2000, 5500, 11000, 6000, 9000, 12000, 18000, 24000, 36000, 48000, 54000]
+    );
+
+    let selectors: Vec<_> = rates.iter().filter_map(|rate| rate.selector()).collect();
+    assert_eq!(
+        selectors,
+        vec![
+            BssMembershipSelector::HtPhy,
+            BssMembershipSelector::VhtPhy,
+            BssMembershipSelector::SaeHashToElementOnly,
+        ]
+    );
+    assert!(!rates[12].is_basic());
+}
+
+#[test]
+fn test_rate_conversions() {
+    // 6 Mbps with and without the basic rate bit
+    assert_eq!(SupportedRate(0x0c).kbps(), Some(6000));
+    assert_eq!(SupportedRate(0x8c).kbps(), Some(6000));
+    assert!(!SupportedRate(0x0c).is_basic());
+    assert!(SupportedRate(0x8c).is_basic());
+    assert_eq!(u32::try_from(SupportedRate(0x8c)).unwrap(), 6000);
+
+    // Uncommon multiples of 500 kbps
+    assert_eq!(SupportedRate::new(7500, true).unwrap(), SupportedRate(0x8f));
+    assert_eq!(SupportedRate::try_from(6000).unwrap(), SupportedRate(0x0c));
+
+    assert!(SupportedRate::new(6100, false).is_err());
+    assert!(SupportedRate::new(63500, false).is_err());
+    assert!(u32::try_from(SupportedRate(0xff)).is_err());
+    assert_eq!(
+        SupportedRate::from_selector(BssMembershipSelector::SaeHashToElementOnly),
+        SupportedRate(0xfb)
+    );
+}
+
+#[test]
+fn test_ds_tim_country_and_erp() {
+    let station_info = parse_station_info(&[
+        3, 1, 11, // DS parameter set
+        5, 5, 0, 3, 3, 34, 128, // TIM: Offset 2 octets, AIDs 17, 21 and 31 buffered
+        7, 13, 68, 69, 32, // Country: DE, any environment
+        1, 13, 20, // Subband: Channels 1-13, 20 dBm
+        201, 17, 0, // Operating class 17
+        36, 4, 23, // Subband: Channels 36-48, 23 dBm
+        0,  // Padding
+        42, 1, 3, // ERP
+        50, 4, 12, 18, 24, 96, // Extended supported rates
+    ]);
+
+    let ds = station_info
+        .ds_parameter_set()
+        .expect("DS should be parsed");
+    assert_eq!(ds.current_channel, 11);
+
+    let tim = station_info.tim().expect("TIM should be parsed");
+    assert_eq!(tim.dtim_count, 0);
+    assert_eq!(tim.dtim_period, 3);
+    assert!(tim.is_dtim());
+    assert!(tim.group_addressed_traffic);
+    assert_eq!(tim.bitmap_offset, 1);
+    assert_eq!(tim.buffered_aids(), vec![17, 21, 31]);
+    assert!(tim.is_aid_buffered(21));
+    assert!(!tim.is_aid_buffered(1));
+    assert!(!tim.is_aid_buffered(22));
+    assert!(!tim.is_aid_buffered(2007));
+
+    let country = station_info.country().expect("Country should be parsed");
+    assert_eq!(country.country_code(), "DE");
+    assert_eq!(country.environment, b' ');
+    assert_eq!(country.triplets.len(), 3);
+    let subbands: Vec<_> = country.subbands().collect();
+    assert_eq!(
+        subbands[0].channels().collect::<Vec<_>>(),
+        (1..=13).collect::<Vec<_>>()
+    );
+    assert_eq!(subbands[0].max_transmit_power, 20);
+    assert_eq!(
+        subbands[1].channels().collect::<Vec<_>>(),
+        vec![36, 40, 44, 48]
+    );
+    let operating_classes: Vec<_> = country.operating_classes().collect();
+    assert_eq!(operating_classes.len(), 1);
+    assert_eq!(operating_classes[0].operating_class, 17);
+
+    let erp = station_info
+        .erp_information()
+        .expect("ERP should be parsed");
+    assert_eq!(
+        erp,
+        ErpInformation::NON_ERP_PRESENT | ErpInformation::USE_PROTECTION
+    );
+}
+
+#[test]
+fn test_tim_from_buffered_aids() {
+    let tim = Tim::new(2, 3, false, &[17, 21, 31]);
+    assert_eq!(tim.bitmap_offset, 1);
+    assert_eq!(tim.partial_virtual_bitmap, vec![34, 128]);
+    assert_eq!(tim.buffered_aids(), vec![17, 21, 31]);
+
+    let empty = Tim::new(0, 1, true, &[]);
+    assert_eq!(empty.bitmap_offset, 0);
+    assert_eq!(empty.partial_virtual_bitmap, vec![0]);
+    assert!(empty.buffered_aids().is_empty());
+
+    let last = Tim::new(0, 1, false, &[2007]);
+    assert_eq!(last.bitmap_offset, 125);
+    assert_eq!(last.partial_virtual_bitmap, vec![128]);
+    assert!(last.is_aid_buffered(2007));
+}
+
+#[test]
+fn test_fragmented_elements_are_reassembled() {
+    let mut elements = vec![221, 255]; // Vendor specific, first fragment
+    elements.extend((0..255).map(|byte| byte as u8));
+    elements.extend([242, 45]); // Fragment
+    elements.extend((0..45).map(|byte| byte as u8));
+    elements.extend([221, 255]); // Vendor specific with exactly 255 bytes
+    elements.extend([1; 255]);
+    elements.extend([242, 0]); // Empty fragment, which isn't taken
+    elements.extend([242, 2, 9, 9]); // Fragment without preceding element
+    elements.extend([50, 4, 12, 18, 24, 96]); // Extended supported rates
+
+    let station_info = parse_station_info(&elements);
+
+    let ids: Vec<_> = station_info.elements.iter().map(|(id, _)| *id).collect();
+    assert_eq!(
+        ids,
+        vec![
+            ManagementInfoId::VendorSpecific,
+            ManagementInfoId::VendorSpecific,
+            ManagementInfoId::Fragment,
+            ManagementInfoId::Fragment,
+            ManagementInfoId::ExtSupportedRates,
+        ]
+    );
+    let expected: Vec<u8> = (0..255).chain(0..45).map(|byte| byte as u8).collect();
+    assert_eq!(station_info.elements[0].1, expected);
+    assert_eq!(station_info.elements[1].1, vec![1; 255]);
+    assert!(station_info.elements[2].1.is_empty());
+    assert_eq!(station_info.elements[3].1, vec![9, 9]);
+}
+
+#[test]
+fn test_builtin_vendor_elements() {
+    let station_info = parse_station_info(&[
+        221, 22, 0, 80, 242, 4, // WPS
+        16, 74, 0, 1, 16, // Version 1.0
+        16, 68, 0, 1, 2, // Configured
+        16, 17, 0, 4, 84, 101, 115, 116, // Device name
+        221, 14, 80, 111, 154, 9, // P2P
+        2, 2, 0, 37, 0, // Capability
+        3, 2, 0, 1, 2, // Device ID (truncated)
+        221, 17, 80, 111, 154, 28, // OWE transition mode
+        2, 0, 0, 0, 0, 1, // BSSID
+        4, 79, 87, 69, 33, // SSID
+        81, 6, // Operating class and channel
+        221, 9, 0, 16, 24, 2, 0, 0, 28, 0, 0, // Broadcom
+        221, 7, 0, 80, 242, 2, 0, 1, 0, // WMM information
+        221, 5, 0, 11, 134, 1, 4, // Unknown vendor
+        3, 1, 6, // DS parameter set
+    ]);
+
+    let registry = VendorRegistry::default();
+    let elements: Vec<_> = station_info.vendor_elements(&registry).collect();
+    assert_eq!(elements.len(), 5);
+
+    let VendorElement::Wps(wps) = &elements[0] else {
+        panic!("Expected a WPS element");
+    };
+    assert_eq!(wps.attributes.len(), 3);
+    assert_eq!(wps.wps_state(), Some(2));
+    assert_eq!(wps.device_name(), Some("Test".to_string()));
+    assert_eq!(wps.manufacturer(), None);
+
+    let VendorElement::P2p(p2p) = &elements[1] else {
+        panic!("Expected a P2P element");
+    };
+    assert_eq!(p2p.capability(), Some((37, 0)));
+    assert_eq!(p2p.attribute(3), Some(&[1, 2][..]));
+
+    let VendorElement::OweTransitionMode(owe) = &elements[2] else {
+        panic!("Expected an OWE transition mode element");
+    };
+    assert_eq!(owe.bssid, MacAddress([2, 0, 0, 0, 0, 1]));
+    assert_eq!(owe.ssid, b"OWE!");
+    assert_eq!(owe.operating_class_and_channel, Some((81, 6)));
+
+    assert!(
+        matches!(&elements[3], VendorElement::Broadcom(broadcom) if broadcom.data == [0, 0, 28, 0, 0])
+    );
+    assert!(matches!(elements[4], VendorElement::Wmm(_)));
+    assert_eq!(elements[4].vendor_id(), VendorId::WMM);
+
+    // Unknown elements are still available as raw elements.
+    let raw: Vec<_> = station_info.raw_vendor_elements().collect();
+    assert_eq!(raw.len(), 6);
+    assert_eq!(raw[5], (VendorId::new([0, 11, 134], 1), &[4][..]));
+}
+
+#[derive(Debug, PartialEq)]
+struct InHouseElement {
+    version: u8,
+    flags: u16,
+}
+
+fn decode_in_house(body: &[u8]) -> Result<VendorElement, libwifi::error::Error> {
+    match body {
+        [version, flags_0, flags_1] => Ok(VendorElement::custom(
+            IN_HOUSE_ID,
+            InHouseElement {
+                version: *version,
+                flags: u16::from_le_bytes([*flags_0, *flags_1]),
+            },
+        )),
+        _ => Err(libwifi::error::Error::Incomplete(
+            "Expected three bytes".to_string(),
+        )),
+    }
+}
+
+const IN_HOUSE_ID: VendorId = VendorId::new([0x02, 0x12, 0x34], 7);
+
+#[test]
+fn test_custom_vendor_decoder() {
+    let station_info = parse_station_info(&[
+        221, 7, 2, 18, 52, 7, 1, 3, 0, // In-house element
+        221, 6, 2, 18, 52, 7, 1, 3, // In-house element with invalid length
+        221, 7, 0, 80, 242, 2, 0, 1, 0, // WMM information
+        3, 1, 6, // DS parameter set
+    ]);
+
+    // Without the custom decoder, only the WMM element is decoded.
+    let elements: Vec<_> = station_info
+        .vendor_elements(&VendorRegistry::default())
+        .collect();
+    assert_eq!(elements.len(), 1);
+
+    let mut registry = VendorRegistry::default();
+    registry.register_decoder(IN_HOUSE_ID, decode_in_house);
+    let elements: Vec<_> = station_info.vendor_elements(&registry).collect();
+    assert_eq!(elements.len(), 2);
+    assert_eq!(elements[0].vendor_id(), IN_HOUSE_ID);
+    assert_eq!(
+        elements[0].downcast_ref::<InHouseElement>(),
+        Some(&InHouseElement {
+            version: 1,
+            flags: 3
+        })
+    );
+    assert!(elements[1].downcast_ref::<InHouseElement>().is_none());
+
+    // Built-in decoders can be replaced or left out.
+    let elements: Vec<_> = station_info
+        .vendor_elements(&VendorRegistry::empty())
+        .collect();
+    assert!(elements.is_empty());
+}
+
+#[test]
+fn test_short_trailing_elements_are_kept() {
+    let station_info = parse_station_info(&[
+        1, 1, 130, // Supported rates
+        42, 1, 4, // ERP information
+        0, 0, // Empty SSID
+    ]);
+
+    assert_eq!(station_info.elements.len(), 3);
+    assert_eq!(station_info.ssid(), Some(String::new()));
+    assert_eq!(
+        station_info.erp_information(),
+        Some(ErpInformation::BARKER_PREAMBLE_MODE)
+    );
+}
+
+#[test]
+fn test_truncated_element() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        1, 8, 130, 132, 139, // Supported rates, 5 bytes are missing
+    ]);
+
+    match parse_frame(&payload, false) {
+        Err(Error::TruncatedElement(ManagementInfoId::SupportedRates, 5)) => (),
+        result => panic!("Expected a truncated element error, got {result:?}"),
+    }
+
+    // An element id without its length byte.
+    let payload = beacon_with_elements(&[0, 4, 84, 101, 115, 116, 3]);
+    match parse_frame(&payload, false) {
+        Err(Error::TruncatedElement(ManagementInfoId::DsParameterSet, 1)) => (),
+        result => panic!("Expected a truncated element error, got {result:?}"),
+    }
+}
+
+#[test]
+fn test_frame_with_fcs() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        3, 1, 6, // DS parameter set
+    ]);
+    let mut payload_with_fcs = payload.clone();
+    payload_with_fcs.extend_from_slice(&[0x3d, 0x12, 0x8e, 0xa1]);
+
+    let Frame::Beacon(without_fcs) = parse_frame(&payload, false).unwrap() else {
+        panic!("Expected a beacon frame");
+    };
+    let Frame::Beacon(with_fcs) = parse_frame(&payload_with_fcs, true).unwrap() else {
+        panic!("Expected a beacon frame");
+    };
+    assert_eq!(
+        with_fcs.station_info.elements,
+        without_fcs.station_info.elements
+    );
+    assert_eq!(with_fcs.station_info.ssid(), Some("Test".to_string()));
+
+    // Without the flag, the FCS is read as a truncated element.
+    assert!(parse_frame(&payload_with_fcs, false).is_err());
+    assert!(matches!(
+        parse_frame(&[0, 0, 0], true),
+        Err(Error::Incomplete(_))
+    ));
+}
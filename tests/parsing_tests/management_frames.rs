@@ -1,5 +1,6 @@
-use libwifi::frame::Frame;
-use libwifi::parse_frame;
+use libwifi::frame::components::{ReasonCode, StatusCode};
+use libwifi::frame::*;
+use libwifi::{parse_frame, Addresses};
 
 #[test]
 fn test_beacon() {
@@ -36,12 +37,12 @@ fn test_beacon() {
         47, 0,
     ];
 
-    let frame = parse_frame(&payload).expect("Payload should be valid");
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
     println!("{:?}", frame);
     assert!(matches!(frame, Frame::Beacon(_)));
 
     if let Frame::Beacon(beacon) = frame {
-        assert_eq!("My face when IP", beacon.station_info.ssid.unwrap());
+        assert_eq!("My face when IP", beacon.station_info.ssid().unwrap());
     }
 }
 
@@ -61,7 +62,7 @@ fn test_probe_request() {
         0, 0, 0, 0, 0, 191, 12, 178, 97, 128, 51, 254, 255, 134, 1, 254, 255, 134, 1,
     ];
 
-    let frame = parse_frame(&payload).expect("Payload should be valid");
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
     println!("{:?}", frame);
     assert!(matches!(frame, Frame::ProbeRequest(_)));
 }
@@ -96,35 +97,341 @@ fn test_probe_response() {
         24, 0, 80, 242, 2, 1, 1, 132, 0, 3, 164, 0, 0, 39, 164, 0, 0, 66, 67, 94, 0, 98, 50, 47, 0,
     ];
 
-    let frame = parse_frame(&payload).expect("Payload should be valid");
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
     println!("{:?}", frame);
     assert!(matches!(frame, Frame::ProbeResponse(_)));
 
     if let Frame::ProbeResponse(response) = frame {
-        assert_eq!("My face when IP", response.station_info.ssid.unwrap());
+        assert_eq!("My face when IP", response.station_info.ssid().unwrap());
     }
 }
 
 #[test]
 fn test_authentication() {
-    let _payload = [
+    let payload = [
         176, 0, // FrameControl
         58, 1, // Duration id
         248, 50, 228, 173, 71, 184, // First Address
         192, 238, 251, 75, 207, 58, // Second Address
         248, 50, 228, 173, 71, 184, // Third address
-        0, 147, 0, 0, 1, 0, 0, 0,
+        0, 147, // Sequence Control
+        0, 0, // Authentication algorithm
+        1, 0, // Authentication sequence
+        0, 0, // Status code
     ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+    assert!(matches!(frame, Frame::Authentication(_)));
+
+    if let Frame::Authentication(auth) = frame {
+        assert_eq!(auth.auth_algorithm, AuthenticationAlgorithm::OpenSystem);
+        assert_eq!(auth.auth_seq, 1);
+        assert!(auth.status_code.is_success());
+        assert!(auth.algorithm_data.is_empty());
+    }
+}
+
+#[test]
+fn test_sae_authentication() {
+    let payload = [
+        176, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third address
+        16, 147, // Sequence Control
+        3, 0, // Authentication algorithm
+        1, 0, // Authentication sequence
+        126, 0, // Status code
+        19, 0, // Finite cyclic group
+        1, 2, 3, 4, // Truncated scalar
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+
+    if let Frame::Authentication(auth) = frame {
+        assert_eq!(auth.auth_algorithm, AuthenticationAlgorithm::Sae);
+        assert_eq!(auth.status_code, StatusCode::SaeHashToElement);
+        assert_eq!(auth.algorithm_data, vec![19, 0, 1, 2, 3, 4]);
+    } else {
+        panic!("Expected an Authentication frame");
+    }
 }
 
 #[test]
 fn test_deauthentication() {
-    let _payload = [
+    let payload = [
         192, 0, // FrameControl
         58, 1, // Duration id
         248, 50, 228, 173, 71, 184, // First Address
         192, 238, 251, 75, 207, 58, // Second Address
         248, 50, 228, 173, 71, 184, // Third Address
-        224, 146, 3, 0,
+        224, 146, // Sequence Control
+        3, 0, // Reason code
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+    assert!(matches!(frame, Frame::Deauthentication(_)));
+
+    if let Frame::Deauthentication(deauth) = frame {
+        assert_eq!(deauth.reason_code, ReasonCode::LeavingNetworkDeauth);
+    }
+}
+
+#[test]
+fn test_protected_deauthentication() {
+    let payload = [
+        192, 64, // FrameControl with the Protected Frame bit
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        224, 146, // Sequence Control
+        18, 0, 0, 32, 0, 0, 0, 0, // CCMP header
+        77, 163, // Encrypted reason code
+        172, 23, 93, 14, 210, 66, 8, 149, // MIC
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+    assert!(matches!(frame, Frame::Deauthentication(_)));
+
+    if let Frame::Deauthentication(deauth) = frame {
+        assert_eq!(deauth.protected_body.as_deref(), Some(&payload[24..]));
+        assert_eq!(deauth.reason_code, ReasonCode::Reserved);
+        assert!(deauth.station_info.elements.is_empty());
+    }
+}
+
+#[test]
+fn test_disassociation() {
+    let payload = [
+        160, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        240, 146, // Sequence Control
+        200, 0, // Unknown reason code
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+    assert!(matches!(frame, Frame::Disassociation(_)));
+
+    if let Frame::Disassociation(disassoc) = frame {
+        assert_eq!(disassoc.reason_code, ReasonCode::Unknown(200));
+    }
+}
+
+#[test]
+fn test_reassociation_request() {
+    let payload = [
+        32, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        48, 0, // Sequence Control
+        49, 4, // Capability info
+        10, 0, // Listen interval
+        20, 125, 218, 170, 84, 81, // Current AP address
+        0, 4, 116, 101, 115, 116, // SSID
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+    assert!(matches!(frame, Frame::ReassociationRequest(_)));
+
+    if let Frame::ReassociationRequest(request) = frame {
+        assert_eq!(request.listen_interval, 10);
+        assert_eq!(request.current_ap_address.to_string(), "14:7d:da:aa:54:51");
+        assert_eq!(request.src().unwrap().to_string(), "c0:ee:fb:4b:cf:3a");
+        assert_eq!(request.bssid().unwrap().to_string(), "f8:32:e4:ad:47:b8");
+        assert_eq!("test", request.station_info.ssid().unwrap());
+    }
+}
+
+#[test]
+fn test_reassociation_response() {
+    let payload = [
+        48, 0, // FrameControl
+        58, 1, // Duration id
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        64, 0, // Sequence Control
+        17, 4, // Capability info
+        0, 0, // Status code
+        2, 192, // Association id
+        1, 4, 130, 132, 139, 150, // Supported rates
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+    assert!(matches!(frame, Frame::ReassociationResponse(_)));
+
+    if let Frame::ReassociationResponse(response) = frame {
+        assert_eq!(response.status_code, StatusCode::Success);
+        assert_eq!(response.association_id, 0xc002);
+        assert_eq!(response.dest().to_string(), "c0:ee:fb:4b:cf:3a");
+    }
+}
+
+#[test]
+fn test_sa_query_action() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        80, 0, // Sequence Control
+        8, // Category
+        0, // Action code
+        42, 17, // Transaction identifier
     ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+
+    if let Frame::Action(action) = frame {
+        assert_eq!(action.category, Some(ActionCategory::SaQuery));
+        assert!(matches!(
+            action.body,
+            ActionBody::SaQuery(SaQueryAction::Request {
+                transaction_id: [42, 17],
+                ..
+            })
+        ));
+    } else {
+        panic!("Expected an Action frame");
+    }
+}
+
+#[test]
+fn test_protected_action() {
+    let payload = [
+        208, 64, // FrameControl with the Protected Frame bit
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        80, 0, // Sequence Control
+        3, 0, 0, 32, 0, 0, 0, 0, // CCMP header
+        95, 18, 201, 77, // Encrypted SA Query request
+        230, 41, 7, 152, 61, 110, 203, 84, // MIC
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+
+    if let Frame::Action(action) = frame {
+        assert_eq!(action.category, None);
+        assert!(matches!(action.body, ActionBody::Protected(data) if data == payload[24..]));
+    } else {
+        panic!("Expected an Action frame");
+    }
+}
+
+#[test]
+fn test_unhandled_action_no_ack() {
+    let payload = [
+        224, 0, // FrameControl
+        0, 0, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        96, 0,  // Sequence Control
+        21, // Category
+        0, 1, // VHT compressed beamforming (truncated)
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+
+    if let Frame::ActionNoAck(action) = frame {
+        assert_eq!(action.category, Some(ActionCategory::Vht));
+        assert!(matches!(action.body, ActionBody::Unhandled(data) if data == vec![0, 1]));
+    } else {
+        panic!("Expected an ActionNoAck frame");
+    }
+}
+
+#[test]
+fn test_addba_request() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        144, 0, // Sequence Control
+        3, // Category
+        0, // Action code
+        7, // Dialog token
+        23, 16, // BlockAck parameter set
+        0, 0, // BlockAck timeout
+        0, 1, // Starting sequence control
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+
+    let Frame::Action(action) = frame else {
+        panic!("Expected an Action frame");
+    };
+    assert_eq!(action.category, Some(ActionCategory::BlockAck));
+    let ActionBody::BlockAck(BlockAckAction::AddBaRequest(request)) = action.body else {
+        panic!("Expected an ADDBA request");
+    };
+    assert_eq!(request.dialog_token, 7);
+    assert_eq!(
+        request.parameters,
+        BlockAckParameterSet {
+            amsdu_supported: true,
+            immediate_policy: true,
+            tid: 5,
+            buffer_size: 64,
+        }
+    );
+    assert_eq!(request.timeout, 0);
+    assert_eq!(request.starting_sequence_control.sequence_number, 16);
+}
+
+#[test]
+fn test_delba() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        160, 0, // Sequence Control
+        3, // Category
+        2, // Action code
+        0, 56, // DELBA parameter set
+        37, 0, // Reason code
+    ];
+
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    println!("{:?}", frame);
+
+    let Frame::Action(action) = frame else {
+        panic!("Expected an Action frame");
+    };
+    let ActionBody::BlockAck(BlockAckAction::DelBa(mut delba)) = action.body else {
+        panic!("Expected a DELBA");
+    };
+    assert!(delba.initiator());
+    assert_eq!(delba.tid(), 3);
+    assert_eq!(delba.reason_code, ReasonCode::EndTsBaDls);
+
+    delba.parameters |= 0x0005;
+    delba.set_initiator(false);
+    delba.set_tid(7);
+    assert_eq!(delba.parameters, 0x7005);
 }
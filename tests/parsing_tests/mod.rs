@@ -1,3 +1,6 @@
 mod control_frames;
 mod data_frames;
+mod elements;
 mod management_frames;
+mod pcap;
+mod radiotap;
@@ -0,0 +1,290 @@
+use std::time::Duration;
+
+use libwifi::error::Error;
+use libwifi::pcap::*;
+
+use crate::{assert_test_beacon, test_beacon};
+
+const FCS: [u8; 4] = [0x3d, 0x12, 0x8e, 0xa1];
+
+/// Build a classic pcap file, whose fields are converted with the given function.
+fn pcap_file(
+    to_bytes: fn(u32) -> [u8; 4],
+    magic: u32,
+    link_type: u32,
+    packets: &[(u32, u32, &[u8])],
+) -> Vec<u8> {
+    let mut file = to_bytes(magic).to_vec();
+    file.extend_from_slice(&to_bytes(0x0004_0002)[..]); // Versions, order doesn't matter here
+    file.extend_from_slice(&[0; 8]); // Time zone and accuracy
+    file.extend_from_slice(&to_bytes(65535)); // Snap length
+    file.extend_from_slice(&to_bytes(link_type));
+    for (seconds, fraction, data) in packets {
+        file.extend_from_slice(&to_bytes(*seconds));
+        file.extend_from_slice(&to_bytes(*fraction));
+        file.extend_from_slice(&to_bytes(data.len() as u32));
+        file.extend_from_slice(&to_bytes(data.len() as u32));
+        file.extend_from_slice(data);
+    }
+
+    file
+}
+
+/// Build a little-endian pcapng block.
+fn block(block_type: u32, body: &[u8]) -> Vec<u8> {
+    let length = (12 + body.len()) as u32;
+    let mut block = block_type.to_le_bytes().to_vec();
+    block.extend_from_slice(&length.to_le_bytes());
+    block.extend_from_slice(body);
+    block.extend_from_slice(&length.to_le_bytes());
+
+    block
+}
+
+fn pad(data: &mut Vec<u8>) {
+    data.resize(data.len().next_multiple_of(4), 0);
+}
+
+fn section_header() -> Vec<u8> {
+    let mut body = 0x1a2b_3c4d_u32.to_le_bytes().to_vec();
+    body.extend_from_slice(&[1, 0, 0, 0]); // Version 1.0
+    body.extend_from_slice(&(-1_i64).to_le_bytes()); // Unknown section length
+    block(0x0a0d_0d0a, &body)
+}
+
+fn interface_description(link_type: u16, options: &[(u16, &[u8])]) -> Vec<u8> {
+    let mut body = link_type.to_le_bytes().to_vec();
+    body.extend_from_slice(&[0, 0]);
+    body.extend_from_slice(&0_u32.to_le_bytes());
+    for (code, value) in options {
+        body.extend_from_slice(&code.to_le_bytes());
+        body.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        body.extend_from_slice(value);
+        pad(&mut body);
+    }
+    if !options.is_empty() {
+        body.extend_from_slice(&[0, 0, 0, 0]);
+    }
+    block(1, &body)
+}
+
+fn enhanced_packet(
+    interface_id: u32,
+    timestamp: u64,
+    data: &[u8],
+    comment: Option<&str>,
+) -> Vec<u8> {
+    let mut body = interface_id.to_le_bytes().to_vec();
+    body.extend_from_slice(&((timestamp >> 32) as u32).to_le_bytes());
+    body.extend_from_slice(&(timestamp as u32).to_le_bytes());
+    body.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    body.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    body.extend_from_slice(data);
+    pad(&mut body);
+    if let Some(comment) = comment {
+        body.extend_from_slice(&1_u16.to_le_bytes());
+        body.extend_from_slice(&(comment.len() as u16).to_le_bytes());
+        body.extend_from_slice(comment.as_bytes());
+        pad(&mut body);
+        body.extend_from_slice(&[0, 0, 0, 0]);
+    }
+    block(6, &body)
+}
+
+#[test]
+fn test_little_endian_pcap() {
+    let file = pcap_file(
+        u32::to_le_bytes,
+        0xa1b2_c3d4,
+        105,
+        &[
+            (1_700_000_000, 250_000, &test_beacon()),
+            (1_700_000_001, 5, &test_beacon()),
+        ],
+    );
+
+    let mut reader = PcapReader::new(file.as_slice()).expect("File should be valid");
+    assert_eq!(reader.format(), CaptureFormat::Pcap);
+    assert_eq!(reader.interfaces()[0].link_type, LinkType::Ieee80211);
+    assert_eq!(reader.interfaces()[0].fcs_length, None);
+
+    let captured = reader.next().unwrap().expect("Frame should be valid");
+    assert_eq!(
+        captured.timestamp,
+        Some(Duration::new(1_700_000_000, 250_000_000))
+    );
+    assert!(captured.radiotap.is_none());
+    assert_test_beacon(&captured.frame);
+
+    let captured = reader.next().unwrap().expect("Frame should be valid");
+    assert_eq!(
+        captured.timestamp,
+        Some(Duration::new(1_700_000_001, 5_000))
+    );
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn test_big_endian_nanosecond_pcap_with_fcs() {
+    let mut packet = test_beacon();
+    packet.extend_from_slice(&FCS);
+    // The FCS length is given as number of 16 bit words in the upper bits of the link type.
+    let link_type = 0x2000_0000 | 0x0400_0000 | 105;
+    let file = pcap_file(
+        u32::to_be_bytes,
+        0xa1b2_3c4d,
+        link_type,
+        &[(10, 42, &packet)],
+    );
+
+    let mut reader = PcapReader::new(file.as_slice()).expect("File should be valid");
+    assert_eq!(reader.interfaces()[0].fcs_length, Some(4));
+    assert_eq!(
+        reader.interfaces()[0].timestamp_resolution,
+        TimestampResolution::NANOSECONDS
+    );
+
+    let captured = reader.next().unwrap().expect("Frame should be valid");
+    assert_eq!(captured.timestamp, Some(Duration::new(10, 42)));
+    assert_test_beacon(&captured.frame);
+}
+
+#[test]
+fn test_radiotap_pcap() {
+    let mut packet = vec![
+        0, 0, 9, 0, // Version, padding and length
+        0x02, 0x00, 0x00, 0x00, // Present: flags
+        0x10, // Flags: FCS
+    ];
+    packet.extend(test_beacon());
+    packet.extend_from_slice(&FCS);
+    let file = pcap_file(u32::to_le_bytes, 0xa1b2_c3d4, 127, &[(0, 0, &packet)]);
+
+    let captured = PcapReader::new(file.as_slice())
+        .expect("File should be valid")
+        .next()
+        .unwrap()
+        .expect("Frame should be valid");
+    assert!(captured
+        .radiotap
+        .expect("Radiotap should be parsed")
+        .has_fcs());
+    assert_test_beacon(&captured.frame);
+}
+
+#[test]
+fn test_ppi_and_avs_pcap() {
+    let mut ppi = vec![
+        0, 0, 32, 0, // Version, flags and length
+        105, 0, 0, 0, // Link type
+        2, 0, 20, 0, // 802.11-Common field
+        0, 0, 0, 0, 0, 0, 0, 0, // TSFT
+        0x01, 0x00, // Flags: FCS present
+        0x02, 0x00, 0x6c, 0x09, 0xa0, 0x00, // Rate and channel
+        0, 0, 0xd6, 0xa1, // FHSS, signal and noise
+    ];
+    ppi.extend(test_beacon());
+    ppi.extend_from_slice(&FCS);
+    let file = pcap_file(u32::to_le_bytes, 0xa1b2_c3d4, 192, &[(0, 0, &ppi)]);
+    let captured = PcapReader::new(file.as_slice())
+        .unwrap()
+        .next()
+        .unwrap()
+        .expect("Frame should be valid");
+    assert_test_beacon(&captured.frame);
+
+    let mut avs = vec![0x80, 0x21, 0x10, 0x01, 0, 0, 0, 64];
+    avs.resize(64, 0);
+    avs.extend(test_beacon());
+    let file = pcap_file(u32::to_le_bytes, 0xa1b2_c3d4, 163, &[(0, 0, &avs)]);
+    let captured = PcapReader::new(file.as_slice())
+        .unwrap()
+        .next()
+        .unwrap()
+        .expect("Frame should be valid");
+    assert_test_beacon(&captured.frame);
+}
+
+#[test]
+fn test_pcapng_with_multiple_interfaces() {
+    let mut radiotap_packet = vec![0, 0, 8, 0, 0, 0, 0, 0];
+    radiotap_packet.extend(test_beacon());
+    let mut fcs_packet = test_beacon();
+    fcs_packet.extend_from_slice(&FCS);
+
+    let mut file = section_header();
+    // Raw 802.11 with nanosecond timestamps and a 32 bit FCS
+    file.extend(interface_description(105, &[(9, &[9]), (13, &[32])]));
+    // Radiotap with the default microsecond timestamps
+    file.extend(interface_description(127, &[]));
+    // A name resolution block, which is skipped
+    file.extend(block(4, &[0, 0, 0, 0]));
+    file.extend(enhanced_packet(
+        0,
+        1_500_000_000,
+        &fcs_packet,
+        Some("first"),
+    ));
+    file.extend(enhanced_packet(1, 2_500_000, &radiotap_packet, None));
+    let mut simple_packet = (fcs_packet.len() as u32).to_le_bytes().to_vec();
+    simple_packet.extend_from_slice(&fcs_packet);
+    pad(&mut simple_packet);
+    file.extend(block(3, &simple_packet));
+
+    let mut reader = PcapReader::new(file.as_slice()).expect("File should be valid");
+    assert_eq!(reader.format(), CaptureFormat::PcapNg);
+
+    let packet = reader
+        .next_packet()
+        .unwrap()
+        .expect("Packet should be read");
+    assert_eq!(packet.interface_id, 0);
+    assert_eq!(packet.comments, vec!["first".to_string()]);
+    assert_eq!(packet.timestamp, Some(Duration::new(1, 500_000_000)));
+    let captured = packet.parse().expect("Frame should be valid");
+    assert_test_beacon(&captured.frame);
+
+    let captured = reader.next().unwrap().expect("Frame should be valid");
+    assert_eq!(captured.interface_id, 1);
+    assert_eq!(captured.timestamp, Some(Duration::new(2, 500_000_000)));
+    assert!(captured.radiotap.is_some());
+    assert_test_beacon(&captured.frame);
+
+    let captured = reader.next().unwrap().expect("Frame should be valid");
+    assert_eq!(captured.timestamp, None);
+    assert_test_beacon(&captured.frame);
+
+    assert!(reader.next().is_none());
+    assert_eq!(reader.interfaces().len(), 2);
+    assert_eq!(reader.interfaces()[0].fcs_length, Some(4));
+}
+
+#[test]
+fn test_invalid_captures() {
+    assert!(matches!(
+        PcapReader::new([0_u8, 1, 2, 3].as_slice()),
+        Err(Error::InvalidCapture(_))
+    ));
+    assert!(matches!(
+        PcapReader::new([0xd4_u8, 0xc3].as_slice()),
+        Err(Error::Io(_))
+    ));
+
+    // A packet of an unknown interface ends the iteration.
+    let mut file = section_header();
+    file.extend(enhanced_packet(3, 0, &test_beacon(), None));
+    let mut reader = PcapReader::new(file.as_slice()).unwrap();
+    assert!(matches!(reader.next(), Some(Err(Error::InvalidCapture(_)))));
+    assert!(reader.next().is_none());
+
+    // Packets that can't be parsed don't end the iteration.
+    let file = pcap_file(
+        u32::to_le_bytes,
+        0xa1b2_c3d4,
+        105,
+        &[(0, 0, &[0x80, 0x00]), (0, 0, &test_beacon())],
+    );
+    let mut reader = PcapReader::new(file.as_slice()).unwrap();
+    assert!(reader.next().unwrap().is_err());
+    assert!(reader.next().unwrap().is_ok());
+}
@@ -0,0 +1,212 @@
+use libwifi::error::Error;
+use libwifi::frame::Frame;
+use libwifi::radiotap::*;
+use libwifi::{parse_radiotap, parse_radiotap_frame};
+
+use crate::beacon_with_elements;
+
+#[test]
+fn test_radiotap_with_extended_presence_and_fcs() {
+    let mut payload = vec![
+        0, 0, 38, 0, // Version, padding and length
+        0x2f, 0x40, 0x00, 0xa0, // Present: TSFT, flags, rate, channel, signal, RX flags
+        0x20, 0x08, 0x00, 0xa0, // Present: signal, antenna
+        0x20, 0x08, 0x00, 0x00, // Present: signal, antenna
+        0x78, 0x56, 0x34, 0x12, 0, 0, 0, 0,    // TSFT
+        0x10, // Flags: FCS
+        0x02, // Rate: 1 Mbps
+        0x85, 0x09, 0xa0, 0x00, // Channel: 2437 MHz, CCK, 2 GHz
+        0xd6, // Antenna signal: -42 dBm
+        0,    // Padding
+        0, 0, // RX flags
+        0xd5, 0, // Antenna signal -43 dBm, antenna 0
+        0xd4, 1, // Antenna signal -44 dBm, antenna 1
+    ];
+    payload.extend(beacon_with_elements(&[0, 4, 84, 101, 115, 116]));
+    payload.extend_from_slice(&[0x3d, 0x12, 0x8e, 0xa1]); // FCS
+
+    let (radiotap, frame) = parse_radiotap_frame(&payload).expect("Payload should be valid");
+    assert_eq!(radiotap.length, 38);
+    assert_eq!(radiotap.present.len(), 3);
+    assert_eq!(radiotap.tsft, Some(0x12345678));
+    assert!(radiotap.has_fcs());
+    assert_eq!(radiotap.rate_kbps(), Some(1000));
+
+    let channel = radiotap.channel.expect("Channel should be parsed");
+    assert_eq!(channel.frequency, 2437);
+    assert_eq!(channel.number(), Some(6));
+    assert_eq!(
+        channel.flags,
+        ChannelFlags::CCK | ChannelFlags::SPECTRUM_2GHZ
+    );
+
+    assert_eq!(radiotap.antenna_signal, Some(-42));
+    assert_eq!(radiotap.antenna, Some(0));
+    assert_eq!(radiotap.rx_flags, Some(RxFlags::empty()));
+    assert_eq!(
+        radiotap.antennas,
+        vec![
+            AntennaInfo {
+                antenna: None,
+                signal: Some(-42),
+                noise: None,
+            },
+            AntennaInfo {
+                antenna: Some(0),
+                signal: Some(-43),
+                noise: None,
+            },
+            AntennaInfo {
+                antenna: Some(1),
+                signal: Some(-44),
+                noise: None,
+            },
+        ]
+    );
+
+    match frame {
+        Frame::Beacon(beacon) => {
+            assert_eq!(beacon.station_info.ssid(), Some("Test".to_string()));
+            assert_eq!(beacon.station_info.elements.len(), 1);
+        }
+        _ => panic!("Expected a beacon frame"),
+    }
+}
+
+#[test]
+fn test_radiotap_mcs_ampdu_and_vht() {
+    let payload = [
+        0, 0, 32, 0, // Version, padding and length
+        0x02, 0x00, 0x38, 0x00, // Present: flags, MCS, A-MPDU status, VHT
+        0x00, // Flags
+        0x07, 0x05, 0x07, // MCS: 40 MHz, short GI, index 7
+        0x42, 0, 0, 0, 0x0c, 0x00, 0, 0, // A-MPDU status: last subframe
+        0x44, 0x00, 0x04, 0x04, // VHT: short GI, 80 MHz
+        0x92, 0, 0, 0, // VHT: MCS 9, 2 spatial streams
+        0x01, 0, 0, 0, // VHT: LDPC, group id and partial AID
+    ];
+
+    let (radiotap, remaining) = parse_radiotap(&payload).expect("Payload should be valid");
+    assert!(remaining.is_empty());
+    assert!(!radiotap.has_fcs());
+
+    let mcs = radiotap.mcs.expect("MCS should be parsed");
+    assert_eq!(mcs.mcs_index(), Some(7));
+    assert_eq!(mcs.bandwidth(), Some(McsBandwidth::Bandwidth40));
+    assert_eq!(mcs.short_gi(), Some(true));
+    assert_eq!(mcs.ldpc(), None);
+
+    let ampdu_status = radiotap
+        .ampdu_status
+        .expect("A-MPDU status should be parsed");
+    assert_eq!(ampdu_status.reference, 0x42);
+    assert_eq!(ampdu_status.is_last(), Some(true));
+
+    let vht = radiotap.vht.expect("VHT should be parsed");
+    assert_eq!(vht.bandwidth_mhz(), Some(80));
+    assert_eq!(vht.short_gi(), Some(true));
+    assert_eq!(
+        vht.users().collect::<Vec<_>>(),
+        vec![VhtUser {
+            mcs: 9,
+            nss: 2,
+            ldpc: true
+        }]
+    );
+}
+
+#[test]
+fn test_radiotap_timestamp_and_he() {
+    let payload = [
+        0, 0, 40, 0, // Version, padding and length
+        0x02, 0x00, 0xc0, 0x00, // Present: flags, timestamp, HE
+        0x00, // Flags
+        0, 0, 0, 0, 0, 0, 0, // Padding
+        0xe8, 0x03, 0, 0, 0, 0, 0, 0, // Timestamp: 1000
+        0x10, 0x00, 0x01, 0x02, // Accuracy 16, microseconds, accuracy known
+        0xa4, 0x40, 0x02, 0x00, // HE data1 and data2
+        0x05, 0x2b, 0x00, 0x00, // HE data3 and data4
+        0x12, 0x00, 0x02, 0x00, // HE data5 and data6
+    ];
+
+    let (radiotap, _) = parse_radiotap(&payload).expect("Payload should be valid");
+    let timestamp = radiotap.timestamp.expect("Timestamp should be parsed");
+    assert_eq!(timestamp.timestamp, 1000);
+    assert_eq!(timestamp.unit(), TimestampUnit::Microseconds);
+    assert_eq!(timestamp.accuracy(), Some(16));
+
+    let he = radiotap.he.expect("HE should be parsed");
+    assert_eq!(he.ppdu_format(), HePpduFormat::SingleUser);
+    assert_eq!(he.bss_color(), Some(5));
+    assert_eq!(he.mcs(), Some(11));
+    assert_eq!(he.ldpc(), Some(true));
+    assert_eq!(he.stbc(), None);
+    assert_eq!(he.bandwidth_ru_allocation(), Some(2));
+    assert_eq!(he.guard_interval(), Some(HeGuardInterval::Gi1_6));
+    assert_eq!(he.nsts(), 2);
+}
+
+#[test]
+fn test_radiotap_vendor_namespace() {
+    let payload = [
+        0, 0, 29, 0, // Version, padding and length
+        0x02, 0x00, 0x00, 0xc0, // Present: flags, vendor namespace
+        0x01, 0x00, 0x00, 0xa0, // Vendor present, radiotap namespace
+        0x04, 0x00, 0x00, 0x00, // Present: rate
+        0x10, // Flags: FCS
+        0,    // Padding
+        0x00, 0x11, 0x22, 0x03, 0x04, 0x00, // OUI, sub namespace and skip length
+        0xde, 0xad, 0xbe, 0xef, // Vendor data
+        0x0c, // Rate: 6 Mbps
+    ];
+
+    let (radiotap, _) = parse_radiotap(&payload).expect("Payload should be valid");
+    assert!(radiotap.has_fcs());
+    assert_eq!(radiotap.rate, Some(12));
+    assert_eq!(
+        radiotap.vendor_namespaces,
+        vec![VendorNamespace {
+            oui: [0x00, 0x11, 0x22],
+            sub_namespace: 3,
+            present: vec![0xa000_0001],
+            data: vec![0xde, 0xad, 0xbe, 0xef],
+        }]
+    );
+}
+
+#[test]
+fn test_radiotap_unknown_field_stops_parsing() {
+    let payload = [
+        0, 0, 16, 0, // Version, padding and length
+        0x02, 0x00, 0x00, 0x10, // Present: flags, TLVs
+        0x10, // Flags: FCS
+        0, 0, 0, // Padding
+        0x01, 0x02, 0x03, 0x04, // TLV data
+        0xaa, // Frame data
+    ];
+
+    let (radiotap, remaining) = parse_radiotap(&payload).expect("Payload should be valid");
+    assert!(radiotap.has_fcs());
+    assert_eq!(remaining, &[0xaa]);
+}
+
+#[test]
+fn test_invalid_radiotap_headers() {
+    // Unsupported version
+    assert!(matches!(
+        parse_radiotap(&[1, 0, 8, 0, 0, 0, 0, 0]),
+        Err(Error::ParseFailure(..))
+    ));
+
+    // The header is longer than the data.
+    assert!(matches!(
+        parse_radiotap(&[0, 0, 12, 0, 0, 0, 0, 0]),
+        Err(Error::Incomplete(_))
+    ));
+
+    // The TSFT field exceeds the header length.
+    assert!(matches!(
+        parse_radiotap(&[0, 0, 12, 0, 1, 0, 0, 0, 0, 0, 0, 0]),
+        Err(Error::Incomplete(_))
+    ));
+}
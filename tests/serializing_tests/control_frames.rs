@@ -0,0 +1,138 @@
+use crate::*;
+use libwifi::frame::components::{FrameControl, SequenceControl};
+use libwifi::frame::*;
+use libwifi::serialize_frame;
+
+#[test]
+fn round_trip_rts() {
+    let payload = [
+        180, 0, // FrameControl
+        158, 0, // Duration
+        116, 66, 127, 77, 29, 45, // First Address
+        20, 125, 218, 170, 84, 81, // Second Address
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_cts() {
+    let payload = [
+        196, 0, // FrameControl
+        246, 14, // Duration
+        224, 62, 68, 8, 195, 239, // First Address
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_ack() {
+    let payload = [
+        212, 0, // FrameControl
+        0, 0, // Duration
+        104, 217, 60, 214, 195, 239, // First Address
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_compressed_block_ack_request() {
+    let payload = [
+        132, 0, // FrameControl
+        58, 1, // Duration
+        192, 238, 251, 75, 207, 58, // First Address
+        24, 29, 234, 198, 62, 190, // Second Address
+        4, 0x50, // BlockAckRequest Control with TID 5
+        160, 15, // Starting sequence number of the single TID
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_multi_tid_block_ack_request() {
+    let payload = [
+        132, 0, // FrameControl
+        58, 1, // Duration
+        192, 238, 251, 75, 207, 58, // First Address
+        24, 29, 234, 198, 62, 190, // Second Address
+        7, 0x10, // BlockAckRequest Control, Multi-TID with two TIDs
+        0, 0x00, 16, 0, // Per-TID info (TID 0) and starting sequence control
+        0, 0x60, 32, 1, // Per-TID info (TID 6) and starting sequence control
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_compressed_block_ack() {
+    let payload = [
+        148, 0, // FrameControl
+        0, 0, // Duration
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        5, 0, // BlockAck Control
+        144, 4, // BlockAck starting sequence control
+        1, 0, 0, 0, 0, 0, 0, 0, // BlockAck Bitmap
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_multi_tid_block_ack() {
+    let payload = [
+        148, 0, // FrameControl
+        0, 0, // Duration
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        6, 0x10, // BlockAck Control, Multi-TID with two TIDs
+        0, 0x30, 144, 4, 255, 0, 0, 0, 0, 0, 0, 0, // TID 3
+        0, 0x70, 16, 0, 3, 0, 0, 0, 0, 0, 0, 128, // TID 7
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_basic_block_ack() {
+    let mut payload = vec![
+        148, 0, // FrameControl
+        0, 0, // Duration
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        0, 0x20, // BlockAck Control with TID 2
+        144, 4, // BlockAck starting sequence control
+    ];
+    // The 128 byte bitmap
+    payload.extend((0..128).map(|byte| byte as u8));
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+/// A single TID BlockAck can't carry multiple TIDs.
+fn block_ack_invalid_tid_count() {
+    let frame = Frame::BlockAck(BlockAck {
+        frame_control: FrameControl {
+            protocol_version: 0,
+            frame_type: libwifi::FrameType::Control,
+            frame_subtype: libwifi::FrameSubType::BlockAck,
+            flags: 0x00,
+        },
+        duration: [0x00, 0x00],
+        source: TEST_MAC_1,
+        destination: TEST_MAC_2,
+        policy: false,
+        mode: BlockAckMode::CompressedBlockAck,
+        acks: BlockAckInfo::Compressed(vec![
+            (0, SequenceControl::create_fresh(1), 1),
+            (1, SequenceControl::create_fresh(2), 1),
+        ]),
+    });
+
+    let mut buffer = [0_u8; 64];
+    assert!(serialize_frame(buffer.as_mut_slice(), &frame).is_err());
+}
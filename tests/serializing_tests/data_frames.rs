@@ -0,0 +1,133 @@
+use crate::*;
+use libwifi::frame::components::{build_flags, DataHeader, FrameControl, SequenceControl};
+use libwifi::frame::*;
+use libwifi::{parse_frame, serialize_frame, Addresses};
+
+#[test]
+fn round_trip_data() {
+    let payload = [
+        8, 98, // FrameControl
+        0, 0, // Duration id
+        51, 51, 255, 75, 207, 58, // First address
+        248, 50, 228, 173, 71, 184, // Second address
+        192, 238, 251, 75, 207, 58, // Third address
+        80, 2, // SequencControl
+        // The rest is data
+        90, 7, 0, 96, 0, 0, 0, 0, 239, 46, 109, 235, 61, 58, 89, 37, 181, 238, 23, 98, 108, 29, 99,
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_null_data() {
+    let payload = [
+        72, 17, // FrameControl
+        60, 0, // Duration id
+        156, 128, 223, 131, 16, 180, // First address
+        252, 25, 16, 16, 128, 171, // Second address
+        156, 128, 223, 131, 16, 180, // Third address
+        128, 43, // SequencControl
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_qos_data() {
+    let payload = [
+        136, 66, // Frame Control
+        44, 0, // Duration Id
+        192, 238, 251, 75, 207, 58, // Address 1
+        248, 50, 228, 173, 71, 184, // Address 2
+        248, 50, 228, 173, 71, 184, // Address 3
+        64, 119, // SequencControl
+        5, 0, // QoS
+        // The rest is data
+        163, 23, 0, 32, 2, 0, 0, 0, 210, 141, 170, 200, 6, 91, 65, 22, 251, 155, 224, 22, 110, 76,
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_qos_null() {
+    let payload = [
+        200, 1, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        80, 106, // Sequence Control
+        0, 0, // QoS Header
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+/// A QoS data frame in a wireless distribution system (to_ds and from_ds set).
+fn serialize_four_address_qos_data() {
+    let frame = Frame::QosData(QosData {
+        header: DataHeader {
+            frame_control: FrameControl {
+                protocol_version: 0,
+                frame_type: libwifi::FrameType::Data,
+                frame_subtype: libwifi::FrameSubType::QosData,
+                flags: build_flags(true, true, false, false, false, false, false, false),
+            },
+            duration: [0x2c, 0x00],
+            address_1: TEST_MAC_1,
+            address_2: TEST_MAC_2,
+            address_3: TEST_MAC_3,
+            sequence_control: SequenceControl::create_fresh(42),
+            address_4: Some(TEST_MAC_4),
+            qos: Some([0x06, 0x00]),
+        },
+        data: vec![0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00],
+    });
+
+    let mut buffer = [0_u8; 64];
+    let bytes_written =
+        serialize_frame(buffer.as_mut_slice(), &frame).expect("Couldn't serialize frame!");
+    // Header (24) + Address 4 (6) + QoS (2) + Data (8)
+    assert_eq!(bytes_written, 40);
+    assert_eq!(&buffer[1..2], &[0b0000_0011]);
+    assert!(compare_byte_slice(&buffer[24..30], &TEST_MAC_4.0));
+
+    let parsed = parse_frame(&buffer[..bytes_written], false).expect("Payload should be valid");
+    let Frame::QosData(parsed) = parsed else {
+        panic!("Expected a QosData frame");
+    };
+    assert_eq!(parsed.src(), Some(&TEST_MAC_4));
+    assert_eq!(parsed.header.qos, Some([0x06, 0x00]));
+    assert_eq!(
+        parsed.data,
+        vec![0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00]
+    );
+}
+
+#[test]
+/// The forth address must be present, if both DS flags are set.
+fn serialize_missing_address_4() {
+    let frame = Frame::NullData(NullData {
+        header: DataHeader {
+            frame_control: FrameControl {
+                protocol_version: 0,
+                frame_type: libwifi::FrameType::Data,
+                frame_subtype: libwifi::FrameSubType::NullData,
+                flags: build_flags(true, true, false, false, false, false, false, false),
+            },
+            duration: [0x00, 0x00],
+            address_1: TEST_MAC_1,
+            address_2: TEST_MAC_2,
+            address_3: TEST_MAC_3,
+            sequence_control: SequenceControl::create_fresh(1),
+            address_4: None,
+            qos: None,
+        },
+    });
+
+    let mut buffer = [0_u8; 64];
+    assert!(serialize_frame(buffer.as_mut_slice(), &frame).is_err());
+}
@@ -0,0 +1,504 @@
+use libwifi::frame::components::{
+    BasicMultiLink, Country, CountryTriplet, DsParameterSet, ErpInformation, MacAddress,
+    ManagementInfoId, MultiLink, OperatingTriplet, OweTransitionMode, PerStaProfile,
+    RsnInformation, StationInfo, SubbandTriplet, SupportedRate, Tim, VendorElement, VendorId,
+    VendorRegistry,
+};
+use libwifi::frame::Frame;
+use libwifi::{parse_frame, serialize_frame};
+
+use crate::{assert_round_trip, beacon_with_elements};
+
+#[test]
+fn round_trip_wpa3_rsn() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+        3, 1, 6, // DS parameter set
+        50, 4, 12, 18, 24, 96, // Extended supported rates
+        48, 54, // RSN header
+        1, 0, // Version
+        0, 15, 172, 4, // Group cipher: CCMP-128
+        2, 0, 0, 15, 172, 4, 0, 15, 172, 8, // Pairwise ciphers: CCMP-128, GCMP-128
+        3, 0, 0, 15, 172, 2, 0, 15, 172, 8, 0, 15, 172, 9, // AKM suites: PSK, SAE, FT-SAE
+        192, 0, // RSN capabilities: MFP required and capable
+        1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, // PMKIDs
+        0, 15, 172, 6, // Group management cipher: BIP-CMAC-128
+        11, 5, 1, 0, 10, 0, 0, // BSS load
+        221, 7, 0, 80, 242, 2, 0, 1, 0, // Vendor specific
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_rsn_with_group_management_cipher_only() {
+    let payload = beacon_with_elements(&[
+        48, 26, // RSN header
+        1, 0, // Version
+        0, 15, 172, 4, // Group cipher: CCMP-128
+        1, 0, 0, 15, 172, 4, // Pairwise ciphers: CCMP-128
+        1, 0, 0, 15, 172, 24, // AKM suites: SAE-EXT-KEY
+        192, 0, // RSN capabilities
+        0, 0, // No PMKIDs
+        0, 15, 172, 6, // Group management cipher: BIP-CMAC-128
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_wpa_and_wmm() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+        3, 1, 6, // DS parameter set
+        221, 14, 0, 80, 242, 4, 16, 74, 0, 1, 16, 16, 68, 0, 1, 2, // WPS
+        221, 24, // WPA header
+        0, 80, 242, 1, // Microsoft OUI, WPA
+        1, 0, // Version
+        0, 80, 242, 2, // Group cipher: TKIP
+        1, 0, 0, 80, 242, 4, // Pairwise ciphers: CCMP
+        1, 0, 0, 80, 242, 2, // AKM suites: PSK
+        0, 0, // Capabilities
+        221, 24, // WMM header
+        0, 80, 242, 2, // Microsoft OUI, WMM
+        1, 1, 132, 0, // Parameter subtype, version, QoS info, reserved
+        3, 164, 0, 0, 39, 164, 0, 0, 66, 67, 94, 0, 98, 50, 47, 0, // AC parameters
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_unknown_elements() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+        3, 1, 6, // DS parameter set
+        47, 1, 0, // ERP (legacy id)
+        45, 26, 189, 25, 23, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 44, 1, 1, 0, 0, 0, 0, 4, 0, 0, 0,
+        0, 0, // HT capabilities
+        127, 8, 4, 0, 8, 0, 0, 0, 0, 64, // Extended capabilities
+        254, 3, 1, 2, 3, // Unassigned id
+        221, 7, 0, 80, 242, 2, 0, 1, 0, // WMM information
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_ht_elements() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        1, 8, 130, 132, 139, 150, 36, 48, 72, 108, // Supported rates
+        3, 1, 9, // DS parameter set
+        42, 1, 4, // ERP
+        50, 4, 12, 18, 24, 96, // Extended supported rates
+        45, 26, 189, 25, 23, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 44, 1, 1, 0, 0, 0, 0, 4, 0, 0, 0,
+        0, 0, // HT capabilities
+        61, 22, 9, 13, 22, 1, 0, 1, // HT operation
+        255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // Basic MCS set
+        74, 14, 20, 0, 10, 0, 44, 1, 200, 0, 20, 0, 5, 0, 25, 0, // OBSS scan parameters
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_vht_elements() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        1, 8, 140, 18, 152, 36, 176, 72, 96, 108, // Supported rates
+        191, 12, 178, 121, 139, 51, 170, 255, 0, 0, 170, 255, 0, 32, // VHT capabilities
+        192, 5, 1, 155, 0, 252, 255, // VHT operation
+        221, 7, 0, 80, 242, 2, 0, 1, 0, // WMM information
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_extension_elements() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        1, 4, 140, 18, 152, 36, // Supported rates
+        3, 1, 37, // DS parameter set
+        48, 20, 1, 0, 0, 15, 172, 4, 1, 0, 0, 15, 172, 4, 1, 0, 0, 15, 172, 8, 192, 0, // RSN
+        255, 32, 35, 9, 0, 8, 18, 0, 16, 12, 32, 14, 64, 9, 0, 128, 12, 0, 0, 0, 250, 255, 250,
+        255, 250, 255, 250, 255, 57, 28, 199, 113, 28, 7, // HE capabilities
+        255, 12, 36, 244, 63, 2, 21, 252, 255, 37, 15, 39, 47, 6, // HE operation
+        255, 14, 38, 0, 3, 164, 8, 39, 164, 8, 66, 67, 8, 98, 50, 8, // MU EDCA parameter set
+        255, 21, 108, 3, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 68, 68, 68, 68, 68, 68, 68, 68,
+        68, // EHT capabilities
+        255, 11, 106, 3, 68, 68, 68, 68, 4, 47, 31, 2, 0, // EHT operation
+        221, 7, 0, 80, 242, 2, 0, 1, 0, // WMM information
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_multi_link_elements() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+        3, 1, 6, // DS parameter set
+        255, 41, 107, // Multi-Link header
+        48, 1, // Control: Basic, link ID, change count and MLD capabilities present
+        11, 2, 17, 34, 51, 68, 85, 0, 3, 1, 0, // Common info
+        0, 25, // Per-STA profile header
+        113, 9, // STA control: Link 1, complete profile
+        12, 248, 50, 228, 173, 71, 185, 100, 0, 0, 1, 2, // STA info
+        17, 4, // Capability info
+        3, 1, 36, // DS parameter set
+        255, 4, 56, 1, 221, 0, // Non-Inheritance: Vendor specific
+        255, 21, 108, 3, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 68, 68, 68, 68, 68, 68, 68, 68,
+        68, // EHT capabilities
+        221, 7, 0, 80, 242, 2, 0, 1, 0, // WMM information
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_probe_request_multi_link() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        255, 17, 107, // Multi-Link header
+        17, 0, // Control: Probe request, AP MLD ID present
+        2, 0, // Common info
+        0, 2, 17, 0, // Per-STA profile: Link 1, complete profile requested
+        0, 6, 2, 0, 3, 1, 36, 0, // Per-STA profile: Link 2, partial profile
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_elements_out_of_order() {
+    let payload = beacon_with_elements(&[
+        3, 1, 6, // DS parameter set
+        221, 7, 0, 80, 242, 2, 0, 1, 0, // WMM information
+        0, 4, 84, 101, 115, 116, // SSID
+        48, 8, 1, 0, 0, 15, 172, 4, 2, 0, // Malformed RSN
+        0, 0, // Hidden SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_ds_tim_country_and_erp() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+        3, 1, 11, // DS parameter set
+        5, 5, 0, 3, 3, 34, 128, // TIM
+        7, 13, 68, 69, 32, 1, 13, 20, 201, 17, 0, 36, 4, 23, 0, // Country
+        42, 1, 3, // ERP
+        50, 4, 12, 18, 24, 96, // Extended supported rates
+    ]);
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn typed_ds_tim_country_and_erp_setters() {
+    let mut station_info = StationInfo::default();
+    station_info.set_ssid("Test");
+    station_info
+        .set_erp_information(&ErpInformation::BARKER_PREAMBLE_MODE)
+        .unwrap();
+    station_info
+        .set_country(&Country {
+            country_code: *b"US",
+            environment: b'O',
+            triplets: vec![CountryTriplet::Subband(SubbandTriplet {
+                first_channel: 1,
+                number_of_channels: 11,
+                max_transmit_power: 30,
+            })],
+        })
+        .unwrap();
+    station_info.set_tim(&Tim::new(0, 2, false, &[1])).unwrap();
+    station_info
+        .set_ds_parameter_set(&DsParameterSet { current_channel: 6 })
+        .unwrap();
+
+    assert_eq!(
+        station_info.elements,
+        vec![
+            (ManagementInfoId::SSID, b"Test".to_vec()),
+            (ManagementInfoId::DsParameterSet, vec![6]),
+            (ManagementInfoId::TIM, vec![0, 2, 0, 2]),
+            (ManagementInfoId::Country, vec![85, 83, 79, 1, 11, 30]),
+            (ManagementInfoId::ErpInfo, vec![4]),
+        ]
+    );
+    // An even number of triplets requires a padding byte.
+    let mut country = station_info.country().unwrap();
+    country
+        .triplets
+        .push(CountryTriplet::Operating(OperatingTriplet::default()));
+    station_info.set_country(&country).unwrap();
+    assert_eq!(
+        station_info.element(ManagementInfoId::Country),
+        Some(&[85, 83, 79, 1, 11, 30, 201, 0, 0, 0][..])
+    );
+    assert_eq!(station_info.country(), Some(country));
+}
+
+#[test]
+fn round_trip_fragmented_elements() {
+    let mut elements = vec![0, 4, 84, 101, 115, 116]; // SSID
+    elements.extend([221, 255]); // Vendor specific, first fragment
+    elements.extend([7; 255]);
+    elements.extend([242, 255]); // Fragment
+    elements.extend([8; 255]);
+    elements.extend([242, 12]); // Fragment
+    elements.extend([9; 12]);
+    elements.extend([221, 255]); // Vendor specific with exactly 255 bytes
+    elements.extend([1; 255]);
+    elements.extend([242, 0]); // Empty fragment
+    elements.extend([50, 4, 12, 18, 24, 96]); // Extended supported rates
+
+    assert_round_trip(&beacon_with_elements(&elements));
+}
+
+#[test]
+fn long_multi_link_elements_are_fragmented() {
+    let multi_link = MultiLink::Basic(BasicMultiLink {
+        mld_mac_address: MacAddress([2, 0, 0, 0, 0, 1]),
+        link_id: None,
+        bss_parameters_change_count: None,
+        medium_synchronization_delay: None,
+        eml_capabilities: None,
+        mld_capabilities: None,
+        ap_mld_id: None,
+        extended_mld_capabilities: None,
+        per_sta_profiles: vec![PerStaProfile {
+            link_id: 1,
+            complete_profile: true,
+            sta_mac_address: Some(MacAddress([2, 0, 0, 0, 0, 2])),
+            sta_profile: vec![17; 400],
+            ..Default::default()
+        }],
+    });
+
+    let mut frame = parse_frame(&beacon_with_elements(&[0, 4, 84, 101, 115, 116]), false).unwrap();
+    let Frame::Beacon(beacon) = &mut frame else {
+        panic!("Expected a beacon frame");
+    };
+    beacon.station_info.set_multi_link(&multi_link).unwrap();
+
+    // The per-STA profile has 409 bytes and is split into a Fragment subelement.
+    let data = beacon
+        .station_info
+        .element(ManagementInfoId::InfoIdExtension)
+        .unwrap();
+    assert_eq!(data.len(), 423);
+    assert_eq!(data[10..12], [0, 255]);
+    assert_eq!(data[267..269], [254, 154]);
+
+    let mut buffer = [0_u8; 2304];
+    let length = serialize_frame(buffer.as_mut_slice(), &frame).unwrap();
+    // The element itself is split into a Fragment element after the SSID.
+    let elements = &buffer[36..length];
+    assert_eq!(elements[6..8], [255, 255]);
+    assert_eq!(elements[263..265], [242, 168]);
+    assert_eq!(elements.len(), 6 + 4 + 423);
+
+    let Frame::Beacon(parsed) = parse_frame(&buffer[..length], false).unwrap() else {
+        panic!("Expected a beacon frame");
+    };
+    assert_eq!(parsed.station_info.multi_link(), Some(multi_link));
+}
+
+#[test]
+fn round_trip_vendor_elements() {
+    let payload = beacon_with_elements(&[
+        0, 4, 84, 101, 115, 116, // SSID
+        221, 17, 0, 80, 242, 4, 16, 74, 0, 1, 16, 16, 17, 0, 4, 84, 101, 115, 116, // WPS
+        221, 9, 80, 111, 154, 9, 2, 2, 0, 37, 0, // P2P
+        221, 15, 80, 111, 154, 28, 2, 0, 0, 0, 0, 1, 4, 79, 87, 69, 33, // OWE transition mode
+        221, 9, 0, 16, 24, 2, 0, 0, 28, 0, 0, // Broadcom
+        3, 1, 6, // DS parameter set
+    ]);
+    let frame = parse_frame(&payload, false).unwrap();
+    let Frame::Beacon(beacon) = &frame else {
+        panic!("Expected a beacon frame");
+    };
+
+    // Setting the decoded elements again must not change any bytes.
+    let registry = VendorRegistry::default();
+    let mut station_info = beacon.station_info.clone();
+    assert_eq!(beacon.station_info.vendor_elements(&registry).count(), 4);
+    for element in beacon.station_info.vendor_elements(&registry) {
+        station_info
+            .set_vendor_element(&registry, &element)
+            .unwrap();
+    }
+    assert_eq!(station_info, beacon.station_info);
+}
+
+#[test]
+fn custom_vendor_encoder() {
+    let vendor_id = VendorId::new([0x02, 0x12, 0x34], 7);
+    let mut registry = VendorRegistry::default();
+    registry.register(
+        vendor_id,
+        move |body| Ok(VendorElement::custom(vendor_id, body.to_vec())),
+        |element| {
+            element
+                .downcast_ref::<Vec<u8>>()
+                .cloned()
+                .ok_or_else(|| libwifi::error::Error::SerializeFailure("Wrong type".to_string()))
+        },
+    );
+
+    let mut station_info = StationInfo::default();
+    station_info.set_ssid("Test");
+    station_info
+        .set_vendor_element(
+            &registry,
+            &VendorElement::custom(vendor_id, vec![1_u8, 2, 3]),
+        )
+        .unwrap();
+    station_info
+        .set_vendor_element(
+            &registry,
+            &VendorElement::OweTransitionMode(OweTransitionMode {
+                bssid: MacAddress([2, 0, 0, 0, 0, 1]),
+                ssid: b"OWE".to_vec(),
+                operating_class_and_channel: None,
+            }),
+        )
+        .unwrap();
+    // The first element with the same vendor ID is replaced.
+    station_info
+        .set_vendor_element(&registry, &VendorElement::custom(vendor_id, vec![4_u8]))
+        .unwrap();
+
+    assert_eq!(
+        station_info.elements,
+        vec![
+            (ManagementInfoId::SSID, b"Test".to_vec()),
+            (ManagementInfoId::VendorSpecific, vec![2, 18, 52, 7, 4]),
+            (
+                ManagementInfoId::VendorSpecific,
+                vec![80, 111, 154, 28, 2, 0, 0, 0, 0, 1, 3, 79, 87, 69]
+            ),
+        ]
+    );
+
+    // Elements without an encoder and elements of the wrong type can't be set.
+    assert!(station_info
+        .set_vendor_element(
+            &VendorRegistry::default(),
+            &VendorElement::custom(vendor_id, vec![4_u8])
+        )
+        .is_err());
+    assert!(station_info
+        .set_vendor_element(&registry, &VendorElement::custom(vendor_id, 4_u8))
+        .is_err());
+}
+
+#[test]
+fn round_trip_raw_ssids() {
+    for ssid in [&b""[..], &[0; 7], &[84, 101, 255, 115, 116], &[0, 84, 0]] {
+        let mut elements = vec![0, ssid.len() as u8];
+        elements.extend_from_slice(ssid);
+        elements.extend_from_slice(&[1, 4, 130, 132, 139, 150]);
+
+        assert_round_trip(&beacon_with_elements(&elements));
+    }
+}
+
+#[test]
+fn supported_rates_are_split() {
+    let rates: Vec<SupportedRate> = [130, 132, 139, 150, 12, 18, 152, 36, 176, 72, 96, 108, 255]
+        .into_iter()
+        .map(SupportedRate)
+        .collect();
+    let mut station_info = StationInfo {
+        elements: vec![
+            (ManagementInfoId::SSID, b"Test".to_vec()),
+            (ManagementInfoId::DsParameterSet, vec![6]),
+            (ManagementInfoId::ErpInfo, vec![0]),
+            (ManagementInfoId::RobustSecurityNetwork, vec![1, 0]),
+        ],
+    };
+
+    station_info.set_supported_rates(&rates);
+    assert_eq!(
+        station_info.elements[1],
+        (
+            ManagementInfoId::SupportedRates,
+            vec![130, 132, 139, 150, 12, 18, 152, 36]
+        )
+    );
+    assert_eq!(
+        station_info.elements[4],
+        (
+            ManagementInfoId::ExtSupportedRates,
+            vec![176, 72, 96, 108, 255]
+        )
+    );
+    assert_eq!(station_info.supported_rates(), rates);
+
+    // The extended supported rates are removed, if they aren't needed anymore.
+    station_info.set_supported_rates(&rates[..4]);
+    assert_eq!(station_info.elements.len(), 5);
+    assert_eq!(station_info.supported_rates(), &rates[..4]);
+
+    // Empty elements are invalid, so both elements are removed without rates.
+    station_info.set_supported_rates(&rates);
+    station_info.set_supported_rates(&[]);
+    assert_eq!(station_info.elements.len(), 4);
+    assert!(station_info.supported_rates().is_empty());
+}
+
+#[test]
+fn typed_setters_keep_element_positions() {
+    let mut station_info = StationInfo {
+        elements: vec![
+            (ManagementInfoId::DsParameterSet, vec![6]),
+            (ManagementInfoId::SSID, b"Old".to_vec()),
+            (
+                ManagementInfoId::VendorSpecific,
+                vec![0, 80, 242, 2, 0, 1, 0],
+            ),
+        ],
+    };
+
+    // Existing elements are replaced in place.
+    station_info.set_ssid("New");
+    // New elements are inserted in front of the first element that follows them in the spec.
+    station_info
+        .set_rsn_information(&RsnInformation::default())
+        .unwrap();
+    station_info.insert_element(ManagementInfoId::SupportedRates, vec![130, 132]);
+
+    let ids: Vec<_> = station_info.elements.iter().map(|(id, _)| *id).collect();
+    assert_eq!(
+        ids,
+        vec![
+            ManagementInfoId::SupportedRates,
+            ManagementInfoId::DsParameterSet,
+            ManagementInfoId::SSID,
+            ManagementInfoId::RobustSecurityNetwork,
+            ManagementInfoId::VendorSpecific,
+        ]
+    );
+    assert_eq!(station_info.ssid(), Some("New".to_string()));
+    assert_eq!(
+        station_info.rsn_information(),
+        Some(RsnInformation::default())
+    );
+    assert!(station_info.wmm().is_some());
+
+    station_info.set_ssid_bytes(&[0; 3]);
+    assert_eq!(station_info.elements[2].1, vec![0; 3]);
+}
@@ -1,7 +1,7 @@
 use crate::*;
 use libwifi::frame::components::{
-    CapabilityInfo, FrameControl, ManagementHeader, ManagementInfoId, SequenceControl, StationInfo,
-    SupportedRate,
+    AkmSuite, CapabilityInfo, CipherSuite, FrameControl, ManagementHeader, ManagementInfoId,
+    RsnCapabilities, RsnInformation, SequenceControl, StationInfo, SupportedRate,
 };
 use libwifi::frame::{Beacon, Frame};
 use libwifi::serialize_frame;
@@ -29,6 +29,56 @@ fn serialize_beacon() -> Result<(), libwifi::error::Error> {
 
     type CI = CapabilityInfo;
 
+    // Elements with a typed setter are inserted at their position in the element order.
+    let mut station_info = StationInfo {
+        elements: vec![
+            (ManagementInfoId::DsParameterSet, vec![0x09]),
+            (ManagementInfoId::TIM, vec![0x00, 0x01, 0x00, 0x00]),
+            (ManagementInfoId::TpcReport, vec![0x10, 0x00]),
+            (ManagementInfoId::ErpInfo, vec![0x00]),
+            (
+                ManagementInfoId::ObssLoadElement,
+                vec![0x01, 0x00, 0x0a, 0x00, 0x00],
+            ),
+            (
+                ManagementInfoId::RmEnabledCapability,
+                vec![0x33, 0x00, 0x00, 0x00, 0x00],
+            ),
+            (
+                ManagementInfoId::HtCapability,
+                vec![
+                    0xef, 0x19, 0x17, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                ],
+            ),
+        ],
+    };
+    station_info.set_ssid("big MAC");
+    // The last 4 rates are put into the Extended Supported Rates element.
+    station_info.set_supported_rates(&[
+        SupportedRate::new(1000, true)?,
+        SupportedRate::new(2000, true)?,
+        SupportedRate::new(5500, true)?,
+        SupportedRate::new(11000, true)?,
+        18000.try_into()?,
+        24000.try_into()?,
+        36000.try_into()?,
+        54000.try_into()?,
+        6000.try_into()?,
+        9000.try_into()?,
+        12000.try_into()?,
+        48000.try_into()?,
+    ]);
+    station_info.set_rsn_information(&RsnInformation {
+        version: 1,
+        group_cipher: CipherSuite::Ccmp128,
+        pairwise_ciphers: vec![CipherSuite::Ccmp128],
+        akm_suites: vec![AkmSuite::Psk],
+        capabilities: RsnCapabilities::PTKSA_REPLAY_COUNTER,
+        pmkids: vec![],
+        group_management_cipher: None,
+    })?;
+
     let beacon = Frame::Beacon(Beacon {
         header: ManagementHeader {
             frame_control: FrameControl {
@@ -53,52 +103,7 @@ fn serialize_beacon() -> Result<(), libwifi::error::Error> {
             | CI::SHORT_PREAMBLE
             | CI::SHORT_TIME_SLOT
             | CI::MEASURE_RADIO,
-        station_info: StationInfo {
-            ssid: Some("big MAC".to_owned()),
-            supported_rates: vec![
-                1000.try_into()?,
-                2000.try_into()?,
-                5500.try_into()?,
-                11000.try_into()?,
-                18000.try_into()?,
-                24000.try_into()?,
-                36000.try_into()?,
-                54000.try_into()?,
-            ],
-            data: vec![
-                (ManagementInfoId::DsParameterSet, vec![0x09]),
-                (ManagementInfoId::TIM, vec![0x00, 0x01, 0x00, 0x00]),
-                (ManagementInfoId::TpcReport, vec![0x10, 0x00]),
-                (ManagementInfoId::ErpInfo, vec![0x00]),
-                (
-                    ManagementInfoId::ExtSupportedRates,
-                    vec![0x0c, 0x12, 0x18, 0x60],
-                ),
-                (
-                    ManagementInfoId::RobustSecurityNetwork,
-                    vec![
-                        0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x04,
-                        0x01, 0x00, 0x00, 0x0f, 0xac, 0x02, 0x0c, 0x00,
-                    ],
-                ),
-                (
-                    ManagementInfoId::ObssLoadElement,
-                    vec![0x01, 0x00, 0x0a, 0x00, 0x00],
-                ),
-                (
-                    ManagementInfoId::RmEnabledCapability,
-                    vec![0x33, 0x00, 0x00, 0x00, 0x00],
-                ),
-                (
-                    ManagementInfoId::HtCapability,
-                    vec![
-                        0xef, 0x19, 0x17, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                        0x00, 0x00,
-                    ],
-                ),
-            ],
-        },
+        station_info,
     });
     let mut buffer = [0_u8; 2304];
     let bytes_written =
@@ -112,3 +117,361 @@ fn serialize_beacon() -> Result<(), libwifi::error::Error> {
 
     Ok(())
 }
+
+#[test]
+fn round_trip_probe_response() {
+    let payload = [
+        80, 0, // FrameControl
+        58, 1, // Duration id
+        192, 238, 251, 75, 207, 58, // First address
+        248, 50, 228, 173, 71, 184, // Second address
+        248, 50, 228, 173, 71, 184, // Third address
+        144, 1, // SequenceControl
+        129, 106, 187, 25, 166, 0, 0, 0, // Timestamp
+        100, 0, //  beacon interval
+        17, 4, // capability info
+        0, 15, 77, 121, 32, 102, 97, 99, 101, 32, 119, 104, 101, 110, 32, 73, 80, // SSID
+        1, 8, 130, 132, 139, 150, 36, 48, 72, 108, // Supported rates
+        3, 1, 9, // DS parameter set
+        48, 20, 1, 0, 0, 15, 172, 4, 1, 0, 0, 15, 172, 4, 1, 0, 0, 15, 172, 2, 12, 0, // RSN
+        50, 4, 12, 18, 24, 96, // Extended supported rates
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_probe_request() {
+    let payload = [
+        64, 0, // FrameControl
+        0, 0, // Duration id
+        255, 255, 255, 255, 255, 255, // First address
+        192, 238, 251, 75, 207, 58, // Second address
+        255, 255, 255, 255, 255, 255, // Thrid address
+        48, 89, // Sequence Control
+        0, 4, 116, 101, 115, 116, // SSID
+        1, 4, 2, 4, 11, 22, // Supported rates
+        50, 8, 12, 18, 24, 36, 48, 72, 96, 108, // Extended supported rates
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_association_request() {
+    let payload = [
+        0, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First address
+        192, 238, 251, 75, 207, 58, // Second address
+        248, 50, 228, 173, 71, 184, // Third address
+        16, 0, // Sequence Control
+        49, 4, // Capability info
+        10, 0, // Listen interval
+        0, 4, 116, 101, 115, 116, // SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+        50, 4, 12, 18, 24, 96, // Extended supported rates
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_association_response() {
+    let payload = [
+        16, 0, // FrameControl
+        58, 1, // Duration id
+        192, 238, 251, 75, 207, 58, // First address
+        248, 50, 228, 173, 71, 184, // Second address
+        248, 50, 228, 173, 71, 184, // Third address
+        32, 0, // Sequence Control
+        17, 4, // Capability info
+        0, 0, // Status code
+        1, 192, // Association id
+        1, 4, 130, 132, 139, 150, // Supported rates
+        50, 4, 12, 18, 24, 96, // Extended supported rates
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_shared_key_authentication() {
+    let payload = [
+        176, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third address
+        0, 147, // Sequence Control
+        1, 0, // Authentication algorithm
+        2, 0, // Authentication sequence
+        0, 0, // Status code
+        16, 8, 1, 2, 3, 4, 5, 6, 7, 8, // Challenge text
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_sae_authentication() {
+    let payload = [
+        176, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third address
+        16, 147, // Sequence Control
+        3, 0, // Authentication algorithm
+        2, 0, // Authentication sequence
+        0, 0, // Status code
+        1, 0, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, // Send-confirm and confirm
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_deauthentication() {
+    let payload = [
+        192, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        224, 146, // Sequence Control
+        7, 0, // Reason code
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_disassociation() {
+    let payload = [
+        160, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        240, 146, // Sequence Control
+        200, 0, // Unknown reason code
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_protected_disassociation() {
+    let payload = [
+        160, 64, // FrameControl with the Protected Frame bit
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        240, 146, // Sequence Control
+        19, 0, 0, 32, 0, 0, 0, 0, // CCMP header
+        12, 201, // Encrypted reason code
+        98, 4, 177, 60, 35, 219, 140, 7, // MIC
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_reassociation_request() {
+    let payload = [
+        32, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        48, 0, // Sequence Control
+        49, 4, // Capability info
+        10, 0, // Listen interval
+        20, 125, 218, 170, 84, 81, // Current AP address
+        0, 4, 116, 101, 115, 116, // SSID
+        1, 4, 130, 132, 139, 150, // Supported rates
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_reassociation_response() {
+    let payload = [
+        48, 0, // FrameControl
+        58, 1, // Duration id
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        64, 0, // Sequence Control
+        17, 4, // Capability info
+        0, 0, // Status code
+        2, 192, // Association id
+        1, 4, 130, 132, 139, 150, // Supported rates
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_sa_query_response() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        96, 0, // Sequence Control
+        8, // Category
+        1, // Action code
+        42, 17, // Transaction identifier
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_sa_query_request_with_oci() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        96, 0, // Sequence Control
+        8, // Category
+        0, // Action code
+        42, 17, // Transaction identifier
+        255, 4, 54, 81, 6, 0, // Operating Channel Information
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_vendor_specific_action() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        112, 0,   // Sequence Control
+        127, // Category
+        0, 16, 24, // OUI
+        1, 2, 3, 4, // Vendor specific content
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_unhandled_action() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        128, 0, // Sequence Control
+        4, // Public category
+        10, 1, 2, 3, // Action code and body
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_protected_action_no_ack() {
+    let payload = [
+        224, 64, // FrameControl with the Protected Frame bit
+        0, 0, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        96, 0, // Sequence Control
+        4, 0, 0, 32, 0, 0, 0, 0, // CCMP header
+        126, 33, 152, 4, 211, 67, // Encrypted category and body
+        17, 250, 93, 2, 142, 8, 71, 190, // MIC
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_addba_request() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        144, 0, // Sequence Control
+        3, // Category
+        0, // Action code
+        7, // Dialog token
+        23, 16, // BlockAck parameter set
+        136, 19, // BlockAck timeout
+        0, 1, // Starting sequence control
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_addba_response() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        192, 238, 251, 75, 207, 58, // First Address
+        248, 50, 228, 173, 71, 184, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        176, 0, // Sequence Control
+        3, // Category
+        1, // Action code
+        7, // Dialog token
+        0, 0, // Status code
+        22, 16, // BlockAck parameter set
+        0, 0, // BlockAck timeout
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_delba() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        160, 0, // Sequence Control
+        3, // Category
+        2, // Action code
+        0, 56, // DELBA parameter set
+        37, 0, // Reason code
+    ];
+
+    assert_round_trip(&payload);
+}
+
+#[test]
+fn round_trip_delba_with_reserved_bits() {
+    let payload = [
+        208, 0, // FrameControl
+        58, 1, // Duration id
+        248, 50, 228, 173, 71, 184, // First Address
+        192, 238, 251, 75, 207, 58, // Second Address
+        248, 50, 228, 173, 71, 184, // Third Address
+        160, 0, // Sequence Control
+        3, // Category
+        2, // Action code
+        5, 56, // DELBA parameter set with reserved bits
+        37, 0, // Reason code
+    ];
+
+    assert_round_trip(&payload);
+}
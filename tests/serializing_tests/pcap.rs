@@ -0,0 +1,217 @@
+use std::time::Duration;
+
+use libwifi::error::Error;
+use libwifi::frame::Frame;
+use libwifi::parse_frame;
+use libwifi::pcap::*;
+use libwifi::radiotap::{Radiotap, RadiotapFlags};
+
+use crate::{assert_test_beacon, compare_byte_slice, test_beacon};
+
+fn beacon() -> Frame {
+    parse_frame(&test_beacon(), false).expect("Beacon should be valid")
+}
+
+#[test]
+fn test_pcap_header() {
+    let mut interface = Interface::new(LinkType::Radiotap);
+    interface.fcs_length = Some(4);
+    let writer = PcapWriter::new(Vec::new(), CaptureFormat::Pcap, interface)
+        .expect("Header should be written");
+
+    let expected = [
+        0xd4, 0xc3, 0xb2, 0xa1, // Magic number
+        2, 0, 4, 0, // Version 2.4
+        0, 0, 0, 0, // Time zone offset
+        0, 0, 0, 0, // Timestamp accuracy
+        0xff, 0xff, 0, 0, // Snap length
+        127, 0, 0, 0x24, // Link type and FCS length of 2 words
+    ];
+    assert!(compare_byte_slice(&writer.into_inner(), &expected));
+}
+
+#[test]
+fn test_pcap_round_trip() {
+    for resolution in [
+        TimestampResolution::MICROSECONDS,
+        TimestampResolution::NANOSECONDS,
+    ] {
+        let mut interface = Interface::new(LinkType::Ieee80211);
+        interface.timestamp_resolution = resolution;
+        let mut writer = PcapWriter::new(Vec::new(), CaptureFormat::Pcap, interface)
+            .expect("Header should be written");
+        let timestamp = Duration::new(1_700_000_000, 123_456_000);
+        writer
+            .write_frame(timestamp, &beacon(), None, Some("Ignored"))
+            .expect("Frame should be written");
+
+        let file = writer.into_inner();
+        let mut reader = PcapReader::new(file.as_slice()).expect("File should be valid");
+        assert_eq!(reader.format(), CaptureFormat::Pcap);
+        assert_eq!(reader.interfaces(), &[interface]);
+
+        let captured = reader.next().unwrap().expect("Frame should be valid");
+        assert_eq!(captured.timestamp, Some(timestamp));
+        assert_test_beacon(&captured.frame);
+        assert!(reader.next().is_none());
+    }
+}
+
+#[test]
+fn test_pcapng_round_trip() {
+    let mut interface = Interface::new(LinkType::Radiotap);
+    interface.timestamp_resolution = TimestampResolution::NANOSECONDS;
+    let mut writer = PcapWriter::new(Vec::new(), CaptureFormat::PcapNg, interface)
+        .expect("Header should be written");
+
+    let radiotap = Radiotap {
+        flags: Some(RadiotapFlags::SHORT_PREAMBLE),
+        rate: Some(12),
+        ..Default::default()
+    };
+    writer
+        .write_frame(
+            Duration::new(5, 1),
+            &beacon(),
+            Some(&radiotap),
+            Some("First"),
+        )
+        .expect("Frame should be written");
+    writer
+        .write_frame(Duration::new(6, 0), &beacon(), None, None)
+        .expect("Frame should be written");
+
+    let file = writer.into_inner();
+    assert_eq!(file.len() % 4, 0);
+    let mut reader = PcapReader::new(file.as_slice()).expect("File should be valid");
+    assert_eq!(reader.format(), CaptureFormat::PcapNg);
+
+    let packet = reader
+        .next_packet()
+        .expect("Packet should be valid")
+        .expect("Packet should exist");
+    assert_eq!(packet.interface, interface);
+    assert_eq!(packet.timestamp, Some(Duration::new(5, 1)));
+    assert_eq!(packet.comments, vec!["First".to_string()]);
+    let captured = packet.parse().expect("Frame should be valid");
+    let parsed_radiotap = captured.radiotap.expect("Radiotap should be parsed");
+    assert_eq!(parsed_radiotap.flags, radiotap.flags);
+    assert_eq!(parsed_radiotap.rate, radiotap.rate);
+    assert_test_beacon(&captured.frame);
+
+    let captured = reader.next().unwrap().expect("Frame should be valid");
+    assert_eq!(captured.timestamp, Some(Duration::new(6, 0)));
+    assert!(captured.radiotap.is_some());
+    assert_test_beacon(&captured.frame);
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn test_pcapng_fcs_length() {
+    let mut interface = Interface::new(LinkType::Ieee80211);
+    interface.fcs_length = Some(4);
+    let mut writer = PcapWriter::new(Vec::new(), CaptureFormat::PcapNg, interface)
+        .expect("Header should be written");
+
+    // Serialized frames don't have an FCS.
+    let result = writer.write_frame(Duration::ZERO, &beacon(), None, None);
+    assert!(matches!(result, Err(Error::SerializeFailure(_))));
+
+    let mut packet = test_beacon();
+    packet.extend_from_slice(&[0x3d, 0x12, 0x8e, 0xa1]);
+    writer
+        .write_packet(Duration::ZERO, &packet, None)
+        .expect("Packet should be written");
+
+    let file = writer.into_inner();
+    let expected = [
+        1, 0, 0, 0, // Interface description block
+        32, 0, 0, 0, // Block length
+        105, 0, 0, 0, // Link type and reserved
+        0xff, 0xff, 0, 0, // Snap length
+        13, 0, 1, 0, 32, 0, 0, 0, // FCS length in bits
+        0, 0, 0, 0, // End of options
+        32, 0, 0, 0, // Block length
+    ];
+    assert!(compare_byte_slice(&file[28..60], &expected));
+
+    let mut reader = PcapReader::new(file.as_slice()).expect("File should be valid");
+    let captured = reader.next().unwrap().expect("Frame should be valid");
+    assert_test_beacon(&captured.frame);
+    assert_eq!(reader.interfaces()[0].fcs_length, Some(4));
+}
+
+#[test]
+fn test_large_timestamp_resolution() {
+    let resolution = TimestampResolution::Binary(127);
+    assert_eq!(resolution.to_ticks(Duration::new(3, 500)), u64::MAX);
+    assert_eq!(
+        TimestampResolution::NANOSECONDS.to_ticks(Duration::new(3, 500)),
+        3_000_000_500
+    );
+}
+
+#[test]
+fn test_snap_length_truncation() {
+    let mut interface = Interface::new(LinkType::Ieee80211);
+    interface.snap_length = 10;
+    let data: Vec<u8> = (0..30).collect();
+
+    for format in [CaptureFormat::Pcap, CaptureFormat::PcapNg] {
+        let mut writer =
+            PcapWriter::new(Vec::new(), format, interface).expect("Header should be written");
+        writer
+            .write_packet(Duration::from_secs(1), &data, None)
+            .expect("Packet should be written");
+
+        let file = writer.into_inner();
+        let packet = PcapReader::new(file.as_slice())
+            .expect("File should be valid")
+            .next_packet()
+            .expect("Packet should be valid")
+            .expect("Packet should exist");
+        assert_eq!(packet.data, &data[..10]);
+        assert_eq!(packet.original_length, 30);
+    }
+}
+
+#[test]
+fn test_unsupported_writes() {
+    let mut interface = Interface::new(LinkType::Ieee80211);
+    interface.timestamp_resolution = TimestampResolution::Binary(20);
+    let result = PcapWriter::new(Vec::new(), CaptureFormat::Pcap, interface);
+    assert!(matches!(result, Err(Error::SerializeFailure(_))));
+
+    // Pcapng files support any resolution.
+    let mut writer = PcapWriter::new(Vec::new(), CaptureFormat::PcapNg, interface)
+        .expect("Header should be written");
+    let result = writer.write_frame(Duration::ZERO, &beacon(), Some(&Radiotap::default()), None);
+    assert!(matches!(result, Err(Error::UnhandledProtocol(_))));
+
+    let comment = "a".repeat(usize::from(u16::MAX) + 1);
+    let result = writer.write_packet(Duration::ZERO, &[0; 10], Some(&comment));
+    assert!(matches!(result, Err(Error::SerializeFailure(_))));
+
+    let mut writer = PcapWriter::new(
+        Vec::new(),
+        CaptureFormat::Pcap,
+        Interface::new(LinkType::Ppi),
+    )
+    .expect("Header should be written");
+    let result = writer.write_frame(Duration::ZERO, &beacon(), None, None);
+    assert!(matches!(result, Err(Error::UnhandledProtocol(_))));
+
+    // The radiotap flags announce an FCS, which isn't computed.
+    let mut writer = PcapWriter::new(
+        Vec::new(),
+        CaptureFormat::Pcap,
+        Interface::new(LinkType::Radiotap),
+    )
+    .expect("Header should be written");
+    let radiotap = Radiotap {
+        flags: Some(RadiotapFlags::FCS),
+        ..Default::default()
+    };
+    let result = writer.write_frame(Duration::ZERO, &beacon(), Some(&radiotap), None);
+    assert!(matches!(result, Err(Error::SerializeFailure(_))));
+}
@@ -0,0 +1,138 @@
+use libwifi::frame::Frame;
+use libwifi::radiotap::*;
+use libwifi::{
+    parse_frame, parse_radiotap, parse_radiotap_frame, serialize_radiotap, serialize_radiotap_frame,
+};
+
+use crate::{beacon_with_elements, compare_byte_slice};
+
+#[test]
+fn test_injection_header() {
+    let radiotap = Radiotap {
+        flags: Some(RadiotapFlags::SHORT_PREAMBLE),
+        rate: Some(2),
+        tx_flags: Some(TxFlags::NO_ACK | TxFlags::NO_SEQNO),
+        data_retries: Some(3),
+        ..Default::default()
+    };
+
+    let mut buffer = [0_u8; 64];
+    let length = serialize_radiotap(&mut buffer, &radiotap).expect("Couldn't serialize header!");
+    let expected = [
+        0, 0, 13, 0, // Version, padding and length
+        0x06, 0x80, 0x02, 0x00, // Present: flags, rate, TX flags, data retries
+        0x02, // Flags: short preamble
+        0x02, // Rate: 1 Mbps
+        0x18, 0x00, // TX flags: no ACK, no sequence number
+        0x03, // Data retries
+    ];
+    assert!(compare_byte_slice(&buffer[..length], &expected));
+}
+
+#[test]
+fn test_mcs_and_vht_alignment() {
+    let radiotap = Radiotap {
+        tx_flags: Some(TxFlags::NO_ACK),
+        mcs: Some(Mcs::new(7, McsBandwidth::Bandwidth40, true)),
+        vht: Some(Vht::new(9, 2, VhtBandwidth::Bandwidth80, false)),
+        ..Default::default()
+    };
+
+    let mut buffer = [0_u8; 64];
+    let length = serialize_radiotap(&mut buffer, &radiotap).expect("Couldn't serialize header!");
+    let expected = [
+        0, 0, 26, 0, // Version, padding and length
+        0x00, 0x80, 0x28, 0x00, // Present: TX flags, MCS, VHT
+        0x08, 0x00, // TX flags: no ACK
+        0x07, 0x05, 0x07, // MCS: 40 MHz, short GI, index 7
+        0x00, // Padding
+        0x44, 0x00, 0x00, 0x04, // VHT: 80 MHz, long GI
+        0x92, 0, 0, 0, // VHT: MCS 9, 2 spatial streams
+        0, 0, 0, 0, // VHT: coding, group id and partial AID
+    ];
+    assert!(compare_byte_slice(&buffer[..length], &expected));
+
+    let (parsed, _) = parse_radiotap(&buffer[..length]).expect("Header should be valid");
+    let mcs = parsed.mcs.expect("MCS should be parsed");
+    assert_eq!(mcs.mcs_index(), Some(7));
+    assert_eq!(mcs.bandwidth(), Some(McsBandwidth::Bandwidth40));
+    assert_eq!(mcs.short_gi(), Some(true));
+    let vht = parsed.vht.expect("VHT should be parsed");
+    assert_eq!(vht.bandwidth_mhz(), Some(80));
+    assert_eq!(vht.short_gi(), Some(false));
+}
+
+#[test]
+fn test_radiotap_round_trip() {
+    let radiotap = Radiotap {
+        version: 0,
+        length: 72,
+        present: vec![0x00f0_c06f],
+        tsft: Some(0x0102_0304_0506_0708),
+        flags: Some(RadiotapFlags::FCS),
+        rate: Some(108),
+        channel: Some(Channel {
+            frequency: 5180,
+            flags: ChannelFlags::OFDM | ChannelFlags::SPECTRUM_5GHZ,
+        }),
+        antenna_signal: Some(-60),
+        antenna_noise: Some(-95),
+        tx_flags: Some(TxFlags::NO_ACK),
+        rx_flags: Some(RxFlags::BAD_PLCP),
+        ampdu_status: Some(AmpduStatus {
+            reference: 7,
+            flags: AmpduFlags::LAST_KNOWN,
+            delimiter_crc: 0,
+        }),
+        timestamp: Some(Timestamp {
+            timestamp: 1234,
+            accuracy: 0,
+            unit_position: 0x12,
+            flags: 0,
+        }),
+        he: Some(He {
+            data: [0x40a4, 0x0002, 0x2b05, 0, 0x0012, 0x0002],
+        }),
+        vht: Some(Vht::new(5, 1, VhtBandwidth::Bandwidth20, true)),
+        antennas: vec![AntennaInfo {
+            antenna: None,
+            signal: Some(-60),
+            noise: Some(-95),
+        }],
+        ..Default::default()
+    };
+
+    let mut buffer = [0_u8; 128];
+    let length = serialize_radiotap(&mut buffer, &radiotap).expect("Couldn't serialize header!");
+    let (parsed, remaining) = parse_radiotap(&buffer[..length]).expect("Header should be valid");
+    assert!(remaining.is_empty());
+    assert_eq!(parsed, radiotap);
+}
+
+#[test]
+fn test_radiotap_frame_for_injection() {
+    let payload = beacon_with_elements(&[0, 4, 84, 101, 115, 116]);
+    let frame = parse_frame(&payload, false).expect("Payload should be valid");
+    let radiotap = Radiotap {
+        rate: Some(12),
+        tx_flags: Some(TxFlags::NO_ACK | TxFlags::NO_SEQNO),
+        ..Default::default()
+    };
+
+    let mut buffer = [0_u8; 256];
+    let length = serialize_radiotap_frame(&mut buffer, &radiotap, &frame)
+        .expect("Couldn't serialize frame!");
+    assert_eq!(length, 12 + payload.len());
+    assert!(compare_byte_slice(&buffer[12..length], &payload));
+
+    let (parsed_radiotap, parsed_frame) =
+        parse_radiotap_frame(&buffer[..length]).expect("Payload should be valid");
+    assert_eq!(
+        parsed_radiotap.tx_flags,
+        Some(TxFlags::NO_ACK | TxFlags::NO_SEQNO)
+    );
+    let (Frame::Beacon(parsed), Frame::Beacon(original)) = (parsed_frame, frame) else {
+        panic!("Expected beacon frames");
+    };
+    assert_eq!(parsed.station_info.elements, original.station_info.elements);
+}